@@ -5,11 +5,14 @@ use std::fmt;
 use itertools::Itertools;
 use rustc_hash::FxHashMap as HashMap;
 
-use fp::prime::{BinomialIterator, BitflagIterator, ValidPrime};
+use fp::matrix::{AugmentedMatrix, QuasiInverse, Subspace};
+use fp::prime::{minus_one_to_the_n, BinomialIterator, BitflagIterator, ValidPrime};
 use fp::vector::{FpVector, SliceMut};
 use once::OnceVec;
 
+use crate::algebra::algebra_trait::unicode_superscript;
 use crate::algebra::combinatorics::{self, MAX_XI_TAU};
+use crate::algebra::milnor_algebra::MilnorProfile;
 use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra, UnstableAlgebra};
 
 #[cfg(doc)]
@@ -152,11 +155,38 @@ unsafe fn shift_vec<T>(v: &mut Vec<T>, offset: isize) {
     std::mem::forget(w);
 }
 
+/// The basis of the subalgebra $A(n)$ in a single degree. See [`AdemAlgebra::new_with_profile`].
+struct SubalgebraBasis {
+    /// Each basis element is the product of a list of letters $\beta$ and $P^i$ (or $Sq^i$) that
+    /// lie in the subalgebra, which are given by their degrees and indices in the full Steenrod
+    /// algebra.
+    factors: Vec<Vec<(i32, usize)>>,
+    /// The inverse of `factors`.
+    factors_to_index: HashMap<Vec<(i32, usize)>, usize>,
+    /// The basis elements in the admissible basis of the full Steenrod algebra.
+    elements: Vec<FpVector>,
+    /// admissible monomial index -> index in the basis, if the monomial is a basis element
+    admissible_to_index: Vec<Option<usize>>,
+    /// Expresses elements of the full Steenrod algebra that lie in the subalgebra in terms of
+    /// `elements`.
+    quasi_inverse: QuasiInverse,
+    /// The generators of the subalgebra in this degree.
+    generators: Vec<usize>,
+    /// basis element -> decomposition as in [`GeneratedAlgebra::decompose_basis_element`]. This
+    /// is empty for generators.
+    decompositions: Vec<Vec<(u32, (i32, usize), (i32, usize))>>,
+    /// The relations between products of a generator and a basis element in this degree.
+    relations: Vec<Vec<(u32, (i32, usize), (i32, usize))>>,
+}
+
 /// An [`Algebra`] implementing the Steenrod algebra, using the Adem basis.
+///
+/// This can also be the subalgebra $A(n)$, see [`AdemAlgebra::new_with_profile`].
 pub struct AdemAlgebra {
     p: ValidPrime,
     generic: bool,
     unstable_enabled: bool,
+    profile: MilnorProfile,
 
     even_basis_table: OnceVec<Vec<AdemBasisElement>>,
     /// degree -> index -> AdemBasisElement
@@ -166,11 +196,20 @@ pub struct AdemAlgebra {
     /// degree -> first square -> admissible sequence idx -> result
     multiplication_table: OnceVec<Vec<Vec<FpVector>>>,
     excess_table: OnceVec<Vec<usize>>,
+    /// degree -> basis of the subalgebra. This is only used if the profile is non-trivial.
+    subalgebra: OnceVec<SubalgebraBasis>,
+    /// degree -> index -> antipode of the basis element. See [`AdemAlgebra::compute_antipodes`].
+    antipode_table: OnceVec<Vec<FpVector>>,
 }
 
 impl fmt::Display for AdemAlgebra {
+    /// The profile is omitted if it is trivial, and is otherwise displayed as `A(n)`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "AdemAlgebra(p={})", self.prime())
+        write!(f, "AdemAlgebra(p={}", self.prime())?;
+        if let Some(n) = self.subalgebra_n() {
+            write!(f, ", profile=A({n})")?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -179,8 +218,12 @@ impl Algebra for AdemAlgebra {
         "adem"
     }
 
+    /// The profile $A(n)$ is encoded as $2(n + 1)$, so that save files of different subalgebras
+    /// cannot be mixed up.
     fn magic(&self) -> u32 {
-        (*self.prime() << 16) + self.unstable_enabled as u32
+        (*self.prime() << 16)
+            + self.unstable_enabled as u32
+            + self.subalgebra_n().map_or(0, |n| 2 * (n as u32 + 1))
     }
 
     fn prime(&self) -> ValidPrime {
@@ -229,7 +272,10 @@ impl Algebra for AdemAlgebra {
         self.compute_basis(max_degree);
         products
             .into_iter()
-            .map(|(name, b)| (name, b.degree, self.basis_element_to_index(&b)))
+            .filter_map(|(name, b)| {
+                let idx = self.try_basis_element_to_index(&b)?;
+                Some((name, b.degree, self.try_subalgebra_index(b.degree, idx)?))
+            })
             .collect()
     }
 
@@ -247,16 +293,34 @@ impl Algebra for AdemAlgebra {
         if self.unstable_enabled {
             self.generate_excess_table(max_degree);
         }
+
+        if !self.profile.is_trivial() {
+            self.generate_subalgebra(max_degree);
+        }
+    }
+
+    fn computed_through(&self) -> i32 {
+        if self.profile.is_trivial() {
+            self.multiplication_table.len() as i32 - 1
+        } else {
+            self.subalgebra.len() as i32 - 1
+        }
     }
 
     fn dimension(&self, degree: i32) -> usize {
         if degree < 0 {
             0
-        } else {
+        } else if self.profile.is_trivial() {
             self.basis_table[degree as usize].len()
+        } else {
+            self.subalgebra[degree as usize].elements.len()
         }
     }
 
+    fn basis(&self, degree: i32) -> std::ops::Range<usize> {
+        0..self.dimension(degree)
+    }
+
     fn multiply_basis_elements(
         &self,
         result: SliceMut,
@@ -266,22 +330,83 @@ impl Algebra for AdemAlgebra {
         s_degree: i32,
         s_index: usize,
     ) {
-        self.multiply_inner(
-            result,
-            coeff,
-            r_degree,
-            r_index,
-            s_degree,
-            s_index,
-            i32::MAX,
-        );
+        if self.profile.is_trivial() {
+            self.multiply_inner(
+                result,
+                coeff,
+                r_degree,
+                r_index,
+                s_degree,
+                s_index,
+                i32::MAX,
+            );
+        } else {
+            self.multiply_subalgebra_basis_elements(
+                result, coeff, r_degree, r_index, s_degree, s_index,
+            );
+        }
     }
 
     fn basis_element_to_string(&self, degree: i32, idx: usize) -> String {
-        format!("{}", self.basis_element_from_index(degree, idx))
+        if self.profile.is_trivial() {
+            format!("{}", self.basis_element_from_index(degree, idx))
+        } else {
+            self.subalgebra_element_to_string(degree, idx, |d, i| {
+                format!("{}", self.basis_element_from_index(d, i))
+            })
+        }
+    }
+
+    fn basis_element_to_string_unicode(&self, degree: i32, idx: usize) -> String {
+        // As for the Milnor basis, the unit is written as 1 rather than as an empty product
+        if degree == 0 {
+            return "1".to_string();
+        }
+        if self.profile.is_trivial() {
+            self.admissible_to_string_unicode(degree, idx)
+        } else {
+            self.subalgebra_element_to_string(degree, idx, |d, i| {
+                self.admissible_to_string_unicode(d, i)
+            })
+        }
     }
 
-    fn basis_element_from_string(&self, mut elt: &str) -> Option<(i32, usize)> {
+    fn basis_element_from_string(&self, elt: &str) -> Option<(i32, usize)> {
+        if self.profile.is_trivial() {
+            return self.admissible_from_string(elt);
+        }
+        // The name of a basis element is the product of the names of its factors, each of which
+        // is a letter. Each admissible monomial in the name stands for its sequence of letters.
+        let mut degree = 0;
+        let mut letters = Vec::new();
+        for factor in elt.split_whitespace() {
+            let (d, i) = self.admissible_from_string(factor)?;
+            let mut factor_letters = self.decompose_admissible(d, i);
+            factor_letters.reverse();
+            degree += d;
+            letters.extend(factor_letters);
+        }
+        self.compute_basis(degree);
+        let idx = *self.subalgebra[degree as usize]
+            .factors_to_index
+            .get(&letters)?;
+        Some((degree, idx))
+    }
+}
+
+impl AdemAlgebra {
+    fn admissible_to_string_unicode(&self, degree: i32, idx: usize) -> String {
+        let elt = self.basis_element_from_index(degree, idx);
+        let p_or_sq = if elt.p_or_sq { "P" } else { "Sq" };
+        elt.iter_filtered()
+            .map(|e| match e {
+                PorBockstein::P(exp) => format!("{p_or_sq}{}", unicode_superscript(exp)),
+                PorBockstein::Bockstein(_) => "β".to_string(),
+            })
+            .join(" ")
+    }
+
+    fn admissible_from_string(&self, mut elt: &str) -> Option<(i32, usize)> {
         use crate::steenrod_parser::{digits, p_or_sq};
         use nom::sequence::preceded;
 
@@ -313,12 +438,12 @@ impl Algebra for AdemAlgebra {
         self.compute_basis(degree);
         Some((
             degree,
-            self.basis_element_to_index(&AdemBasisElement {
+            self.try_basis_element_to_index(&AdemBasisElement {
                 ps,
                 bocksteins,
                 degree,
                 p_or_sq: self.generic,
-            }),
+            })?,
         ))
     }
 }
@@ -337,6 +462,9 @@ impl GeneratedAlgebra for AdemAlgebra {
     }
 
     fn generators(&self, degree: i32) -> Vec<usize> {
+        if !self.profile.is_trivial() {
+            return self.subalgebra[degree as usize].generators.clone();
+        }
         let p = *self.prime();
         if degree == 0 {
             return vec![];
@@ -384,7 +512,9 @@ impl GeneratedAlgebra for AdemAlgebra {
         degree: i32,
         idx: usize,
     ) -> Vec<(u32, (i32, usize), (i32, usize))> {
-        if self.generic {
+        if !self.profile.is_trivial() {
+            self.subalgebra[degree as usize].decompositions[idx].clone()
+        } else if self.generic {
             self.decompose_basis_element_generic(degree, idx)
         } else {
             self.decompose_basis_element_2(degree, idx)
@@ -393,7 +523,13 @@ impl GeneratedAlgebra for AdemAlgebra {
 
     /// We return Adem relations $b^2 = 0$, $P^i P^j = \cdots$ for $i < pj$, and $P^i b P^j = \cdots$ for $i < pj + 1$. It suffices to check these because
     /// they generate all relations.
+    ///
+    /// For the subalgebra $A(n)$, we instead return all relations between products of a generator
+    /// with a basis element of positive degree. These are not minimal.
     fn generating_relations(&self, degree: i32) -> Vec<Vec<(u32, (i32, usize), (i32, usize))>> {
+        if !self.profile.is_trivial() {
+            return self.subalgebra[degree as usize].relations.clone();
+        }
         if self.generic && degree == 2 {
             // beta^2 = 0 is an edge case
             return vec![vec![(1, (1, 0), (1, 0))]];
@@ -409,29 +545,22 @@ impl GeneratedAlgebra for AdemAlgebra {
             let first_sq = self.beps_pn(0, x);
             let second_sq = self.beps_pn(b, y);
             relation.push((*p - 1, first_sq, second_sq));
-            for e1 in 0..=b {
-                let e2 = b - e1;
-                // e1 and e2 determine where a bockstein shows up.
-                // e1 determines if a bockstein shows up in front
-                // e2 determines if a bockstein shows up in middle
-                // So our output term looks like b^{e1} P^{x+y-j} b^{e2} P^{j}
-                for j in 0..=x / *p {
-                    let c = combinatorics::adem_relation_coefficient(p, x, y, j, e1, e2);
-                    if c == 0 {
-                        continue;
-                    }
-                    let idx = self.basis_element_to_index(&AdemBasisElement {
-                        degree,
-                        ps: if j == 0 {
-                            vec![x + y]
-                        } else {
-                            vec![x + y - j, j]
-                        },
-                        bocksteins: e1 | (e2 << 1),
-                        p_or_sq: *self.prime() != 2,
-                    });
-                    relation.push((c, (degree, idx), (0, 0)));
-                }
+            // e1 and e2 determine where a bockstein shows up.
+            // e1 determines if a bockstein shows up in front
+            // e2 determines if a bockstein shows up in middle
+            // So our output term looks like b^{e1} P^{x+y-j} b^{e2} P^{j}
+            for (j, e1, e2, c) in combinatorics::adem_relation_coefficients(p, x, y, b) {
+                let idx = self.basis_element_to_index(&AdemBasisElement {
+                    degree,
+                    ps: if j == 0 {
+                        vec![x + y]
+                    } else {
+                        vec![x + y - j, j]
+                    },
+                    bocksteins: e1 | (e2 << 1),
+                    p_or_sq: *self.prime() != 2,
+                });
+                relation.push((c, (degree, idx), (0, 0)));
             }
             result.push(relation);
         }
@@ -476,6 +605,39 @@ impl UnstableAlgebra for AdemAlgebra {
 impl AdemAlgebra {
     /// Constructs a new [`AdemAlgebra`].
     pub fn new(p: ValidPrime, unstable_enabled: bool) -> Self {
+        Self::new_with_profile(p, MilnorProfile::default(), unstable_enabled)
+    }
+
+    /// Constructs the subalgebra of the Steenrod algebra with profile `profile`, which must either
+    /// be trivial or the profile of some $A(n)$ (see [`MilnorProfile::is_an`]).
+    ///
+    /// The subalgebra $A(n)$ is generated by $\beta$ and $P^{p^i}$ (or $Sq^{2^i}$) for $i \leq n$.
+    /// It is not spanned by admissible monomials, e.g. $Sq^2 Sq^1 Sq^2 = Sq^5 + Sq^4 Sq^1$ lies in
+    /// $A(1)$ but $Sq^5$ does not. Its basis consists of the admissible monomials all of whose
+    /// letters lie in $A(n)$, completed by products of generators with basis elements of lower
+    /// degree. For example, the basis of $A(1)$ in degree 5 is `Sq2 Sq3`.
+    ///
+    /// Note that [`AdemAlgebra::basis_element_from_index`] and
+    /// [`AdemAlgebra::basis_element_to_index`] always refer to the admissible basis of the full
+    /// Steenrod algebra. Use [`AdemAlgebra::try_subalgebra_basis_element`] to look up basis
+    /// elements of the subalgebra.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the profile is neither trivial nor that of some $A(n)$, or if the profile is
+    /// non-trivial and `unstable_enabled` is `true`.
+    pub fn new_with_profile(p: ValidPrime, profile: MilnorProfile, unstable_enabled: bool) -> Self {
+        let generic = *p != 2;
+        if !profile.is_trivial() {
+            assert!(
+                !profile.p_part.is_empty() && profile.is_an(generic),
+                "The Adem algebra only supports the profiles of A(n), found {profile:?}"
+            );
+            assert!(
+                !unstable_enabled,
+                "The unstable Adem algebra does not support profiles"
+            );
+        }
         let even_basis_table = OnceVec::new();
         let basis_table = OnceVec::new();
         let basis_element_to_index_map = OnceVec::new();
@@ -483,13 +645,16 @@ impl AdemAlgebra {
         let excess_table = OnceVec::new();
         Self {
             p,
-            generic: *p != 2,
+            generic,
             unstable_enabled,
+            profile,
             even_basis_table,
             basis_table,
             basis_element_to_index_map,
             multiplication_table,
             excess_table,
+            subalgebra: OnceVec::new(),
+            antipode_table: OnceVec::new(),
         }
     }
 
@@ -497,6 +662,10 @@ impl AdemAlgebra {
         self.generic
     }
 
+    pub fn profile(&self) -> &MilnorProfile {
+        &self.profile
+    }
+
     pub fn q(&self) -> i32 {
         if self.generic {
             2 * (*self.prime() as i32 - 1)
@@ -652,12 +821,85 @@ impl AdemAlgebra {
             });
     }
 
+    /// The number of admissible monomials in degree `degree`. This is the dimension of the full
+    /// Steenrod algebra, regardless of the profile.
+    fn admissible_dimension(&self, degree: i32) -> usize {
+        if degree < 0 {
+            0
+        } else {
+            self.basis_table[degree as usize].len()
+        }
+    }
+
+    /// The admissible monomial of index `idx` in degree `degree`. This indexes the admissible basis
+    /// of the full Steenrod algebra, regardless of the profile.
+    ///
+    /// If the profile is non-trivial, `idx` is *not* an index of the [`Algebra`] basis, which is
+    /// the basis of the subalgebra. Use [`AdemAlgebra::try_subalgebra_basis_element`] or
+    /// [`AdemAlgebra::to_admissibles`] for basis elements of the subalgebra.
     pub fn basis_element_from_index(&self, degree: i32, idx: usize) -> &AdemBasisElement {
         &self.basis_table[degree as usize][idx]
     }
 
+    /// The basis element of index `idx` in degree `degree` of the [`Algebra`] basis, if it is an
+    /// admissible monomial. This is always the case if the profile is trivial, but basis elements
+    /// of $A(n)$ such as `Sq2 Sq3` are not. This returns `None` for those, as well as when the
+    /// basis in this degree has not been computed or `idx` is out of range.
+    pub fn try_subalgebra_basis_element(
+        &self,
+        degree: i32,
+        idx: usize,
+    ) -> Option<&AdemBasisElement> {
+        let degree_idx = usize::try_from(degree).ok()?;
+        if self.profile.is_trivial() {
+            return self.basis_table.get(degree_idx)?.get(idx);
+        }
+        let (i, _) = self
+            .subalgebra
+            .get(degree_idx)?
+            .elements
+            .get(idx)?
+            .iter_nonzero()
+            .next()?;
+        if self.try_subalgebra_index(degree, i) == Some(idx) {
+            Some(self.basis_element_from_index(degree, i))
+        } else {
+            None
+        }
+    }
+
+    /// Whether `elt` is an admissible monomial, i.e. $P^{i_j} \beta^{\varepsilon} P^{i_{j + 1}}$
+    /// always satisfies $i_j \geq p i_{j + 1} + \varepsilon$ (or $i_j \geq 2 i_{j + 1}$ at the
+    /// prime 2), and no $P^0$ appears.
+    pub fn is_admissible(&self, elt: &AdemBasisElement) -> bool {
+        let p = *self.prime();
+        if elt.ps.contains(&0) {
+            return false;
+        }
+        elt.ps
+            .iter()
+            .tuple_windows()
+            .enumerate()
+            .all(|(i, (&x, &y))| {
+                if self.generic {
+                    x >= p * y + ((elt.bocksteins >> (i + 1)) & 1)
+                } else {
+                    x >= 2 * y
+                }
+            })
+    }
+
+    /// Find the index of `elt` in the basis. This returns `None` if `elt` is not admissible, or if
+    /// its degree has not been computed.
+    ///
+    /// As with [`AdemAlgebra::basis_element_from_index`], this indexes the admissible basis of the
+    /// full Steenrod algebra even if the profile is non-trivial.
     pub fn try_basis_element_to_index(&self, elt: &AdemBasisElement) -> Option<usize> {
-        self.basis_element_to_index_map[elt.degree as usize]
+        if elt.degree < 0 || !self.is_admissible(elt) {
+            return None;
+        }
+        self.basis_element_to_index_map
+            .get(elt.degree as usize)?
             .get(elt)
             .copied()
     }
@@ -701,11 +943,11 @@ impl AdemAlgebra {
 
             table.push(Vec::with_capacity(0));
             for x in 1..=n {
-                let dimension = self.dimension(n - x);
+                let dimension = self.admissible_dimension(n - x);
                 table.push(Vec::with_capacity(dimension));
             }
             for x in (1..=n).rev() {
-                for idx in 0..self.dimension(n - x) {
+                for idx in 0..self.admissible_dimension(n - x) {
                     let res = self.generate_multiplication_table_2_step(&table, n, x, idx);
                     table[x as usize].push(res);
                 }
@@ -721,7 +963,7 @@ impl AdemAlgebra {
         x: i32,
         idx: usize,
     ) -> FpVector {
-        let output_dimension = self.dimension(n);
+        let output_dimension = self.admissible_dimension(n);
         let mut result = FpVector::new(self.prime(), output_dimension);
         let cur_basis_elt = self.basis_element_from_index(n - x, idx);
         let x = x as u32;
@@ -781,18 +1023,18 @@ impl AdemAlgebra {
             for i in 0..=n / q {
                 for b in 0..=1 {
                     // This corresponds to x = 2i + b
-                    let dimension = self.dimension(n - q * i - b);
+                    let dimension = self.admissible_dimension(n - q * i - b);
                     table.push(Vec::with_capacity(dimension));
                 }
             }
             for i in (0..=n / q).rev() {
-                for idx in 0..self.dimension(n - q * i - 1) {
+                for idx in 0..self.admissible_dimension(n - q * i - 1) {
                     let res =
                         self.generate_multiplication_table_generic_step(&table, n, 2 * i + 1, idx);
                     table[1 + 2 * i as usize].push(res);
                 }
                 if i != 0 {
-                    for idx in 0..self.dimension(n - q * i) {
+                    for idx in 0..self.admissible_dimension(n - q * i) {
                         let res =
                             self.generate_multiplication_table_generic_step(&table, n, 2 * i, idx);
                         table[2 * i as usize].push(res);
@@ -822,7 +1064,7 @@ impl AdemAlgebra {
 
         let x: u32 = x as u32;
 
-        let output_dimension = self.dimension(n);
+        let output_dimension = self.admissible_dimension(n);
         let mut result = FpVector::new(self.prime(), output_dimension);
 
         // If x is just \beta, this is super easy.
@@ -955,7 +1197,7 @@ impl AdemAlgebra {
         if coeff == 0 {
             return;
         }
-        assert!(r_index < self.dimension(r_degree));
+        assert!(r_index < self.admissible_dimension(r_degree));
         assert!(s_index < self.dimension_unstable(s_degree, excess));
 
         if s_degree == 0 {
@@ -1220,14 +1462,15 @@ impl AdemAlgebra {
             ps: vec![second_sq],
             p_or_sq: *self.prime() != 2,
         });
-        let mut out_vec = FpVector::new(fp::prime::TWO, self.dimension(degree));
-        self.multiply_basis_elements(
+        let mut out_vec = FpVector::new(fp::prime::TWO, self.admissible_dimension(degree));
+        self.multiply_inner(
             out_vec.as_slice_mut(),
             1,
             first_degree,
             first_idx,
             second_degree,
             second_idx,
+            i32::MAX,
         );
         out_vec.set_entry(idx, 0);
         let mut result = vec![(1, (first_degree, first_idx), (second_degree, second_idx))];
@@ -1303,14 +1546,15 @@ impl AdemAlgebra {
             ps: vec![second_sq],
             p_or_sq: *self.prime() != 2,
         });
-        let mut out_vec = FpVector::new(p, self.dimension(degree));
-        self.multiply_basis_elements(
+        let mut out_vec = FpVector::new(p, self.admissible_dimension(degree));
+        self.multiply_inner(
             out_vec.as_slice_mut(),
             1,
             first_degree,
             first_idx,
             second_degree,
             second_idx,
+            i32::MAX,
         );
         let mut result = vec![];
         let c = out_vec.entry(idx);
@@ -1350,6 +1594,275 @@ impl AdemAlgebra {
 }
 
 impl AdemAlgebra {
+    /// The $n$ such that this is the subalgebra $A(n)$, or `None` if this is the full Steenrod
+    /// algebra.
+    fn subalgebra_n(&self) -> Option<usize> {
+        if self.profile.is_trivial() {
+            None
+        } else {
+            Some(self.profile.p_part.len() - 1)
+        }
+    }
+
+    /// The index of the admissible monomial `idx` in the basis of the subalgebra, if it is a basis
+    /// element.
+    fn try_subalgebra_index(&self, degree: i32, idx: usize) -> Option<usize> {
+        if self.profile.is_trivial() {
+            Some(idx)
+        } else {
+            self.subalgebra[degree as usize].admissible_to_index[idx]
+        }
+    }
+
+    /// Like [`AdemAlgebra::try_subalgebra_index`], but for admissible monomials that are known to
+    /// be basis elements, e.g. the factors of a basis element.
+    fn subalgebra_index(&self, degree: i32, idx: usize) -> usize {
+        self.try_subalgebra_index(degree, idx)
+            .expect("Admissible monomial is not a basis element")
+    }
+
+    /// Expresses a basis element in terms of admissible monomials.
+    ///
+    /// This is the identity if the profile is trivial. Otherwise, it gives the basis element of
+    /// the subalgebra in the admissible basis of the full Steenrod algebra.
+    pub fn to_admissibles(&self, degree: i32, idx: usize) -> FpVector {
+        if self.profile.is_trivial() {
+            let mut result = FpVector::new(self.prime(), self.dimension(degree));
+            result.set_entry(idx, 1);
+            result
+        } else {
+            self.subalgebra[degree as usize].elements[idx].clone()
+        }
+    }
+
+    fn multiply_subalgebra_basis_elements(
+        &self,
+        result: SliceMut,
+        coeff: u32,
+        r_degree: i32,
+        r_index: usize,
+        s_degree: i32,
+        s_index: usize,
+    ) {
+        let p = self.prime();
+        let degree = r_degree + s_degree;
+        let mut product = FpVector::new(p, self.admissible_dimension(degree));
+        let r = &self.subalgebra[r_degree as usize].elements[r_index];
+        let s = &self.subalgebra[s_degree as usize].elements[s_index];
+        for (i, c) in r.iter_nonzero() {
+            for (j, d) in s.iter_nonzero() {
+                self.multiply_inner(
+                    product.as_slice_mut(),
+                    c * d % *p,
+                    r_degree,
+                    i,
+                    s_degree,
+                    j,
+                    i32::MAX,
+                );
+            }
+        }
+        self.subalgebra[degree as usize]
+            .quasi_inverse
+            .apply(result, coeff, product.as_slice());
+    }
+
+    /// The name of a basis element of $A(n)$, which is the product of the names of its factors
+    /// as given by `to_string`.
+    fn subalgebra_element_to_string(
+        &self,
+        degree: i32,
+        idx: usize,
+        to_string: impl Fn(i32, usize) -> String,
+    ) -> String {
+        self.subalgebra[degree as usize].factors[idx]
+            .iter()
+            .map(|&(d, i)| to_string(d, i))
+            .join(" ")
+    }
+
+    /// The algebra generators of $A(n)$ of degree at most `max_degree`, as admissible monomials.
+    fn subalgebra_generators(&self, max_degree: i32) -> Vec<(i32, usize)> {
+        let p = *self.prime();
+        let mut result = Vec::new();
+        if self.generic && max_degree >= 1 {
+            result.push(self.beps_pn(1, 0));
+        }
+        for i in 0..=self.subalgebra_n().unwrap() as u32 {
+            let x = p.pow(i);
+            if x as i32 * self.q() > max_degree {
+                break;
+            }
+            result.push(self.beps_pn(0, x));
+        }
+        result
+    }
+
+    /// Computes the quasi-inverse and kernel of the map that sends the `i`th basis vector to
+    /// `vectors[i]`.
+    fn quasi_inverse_and_kernel(
+        p: ValidPrime,
+        dimension: usize,
+        vectors: &[FpVector],
+    ) -> (QuasiInverse, Subspace) {
+        let mut matrix = AugmentedMatrix::<2>::new(p, vectors.len(), [dimension, vectors.len()]);
+        for (row, v) in vectors.iter().enumerate() {
+            matrix.row_segment_mut(row, 0, 0).assign(v.as_slice());
+            matrix.row_segment_mut(row, 1, 1).set_entry(row, 1);
+        }
+        matrix.row_reduce();
+        (matrix.compute_quasi_inverse(), matrix.compute_kernel())
+    }
+
+    /// Computes the basis of $A(n)$ through `max_degree`. In each degree, we compute the span of
+    /// the products of generators with basis elements of lower degree. The admissible monomials
+    /// whose letters lie in $A(n)$ come first in the basis, and the rest of the basis consists of
+    /// such products.
+    fn generate_subalgebra(&self, max_degree: i32) {
+        let p = self.prime();
+        let generators = self.subalgebra_generators(max_degree);
+        self.subalgebra.extend(max_degree as usize, |n| {
+            let degree = n as i32;
+            let dimension = self.admissible_dimension(degree);
+
+            // Products of a generator with a basis element of positive degree
+            let products: Vec<(i32, usize, usize)> = generators
+                .iter()
+                .filter(|&&(gen_degree, _)| gen_degree < degree)
+                .flat_map(|&(gen_degree, gen_idx)| {
+                    (0..self.subalgebra[(degree - gen_degree) as usize]
+                        .elements
+                        .len())
+                        .map(move |idx| (gen_degree, gen_idx, idx))
+                })
+                .collect();
+            let product_vectors: Vec<FpVector> = products
+                .iter()
+                .map(|&(gen_degree, gen_idx, idx)| {
+                    let mut result = FpVector::new(p, dimension);
+                    let elt = &self.subalgebra[(degree - gen_degree) as usize].elements[idx];
+                    for (i, c) in elt.iter_nonzero() {
+                        self.multiply_inner(
+                            result.as_slice_mut(),
+                            c,
+                            gen_degree,
+                            gen_idx,
+                            degree - gen_degree,
+                            i,
+                            i32::MAX,
+                        );
+                    }
+                    result
+                })
+                .collect();
+            let (product_qi, kernel) =
+                Self::quasi_inverse_and_kernel(p, dimension, &product_vectors);
+
+            // The part of A(n) in this degree is spanned by the products and generators
+            let mut subalgebra = Subspace::new(p, dimension + 1, dimension);
+            let mut unit = FpVector::new(p, dimension);
+            for v in &product_vectors {
+                subalgebra.add_vector(v.as_slice());
+            }
+            for &(gen_degree, gen_idx) in &generators {
+                if gen_degree == degree {
+                    unit.set_entry(gen_idx, 1);
+                    subalgebra.add_vector(unit.as_slice());
+                    unit.set_to_zero();
+                }
+            }
+
+            let mut factors = Vec::new();
+            let mut elements = Vec::new();
+            let mut admissible_to_index = vec![None; dimension];
+            let mut span = Subspace::new(p, dimension + 1, dimension);
+            for (idx, entry) in admissible_to_index.iter_mut().enumerate() {
+                // An admissible monomial is a basis element if all of its letters lie in A(n).
+                // This ensures that the coproduct of every factor of a basis element is known.
+                let mut letters = self.decompose_admissible(degree, idx);
+                letters.reverse();
+                unit.set_entry(idx, 1);
+                let is_basis_element = match letters[..] {
+                    [_] => subalgebra.contains(unit.as_slice()),
+                    _ => letters
+                        .iter()
+                        .all(|&(d, i)| self.try_subalgebra_index(d, i).is_some()),
+                };
+                if is_basis_element {
+                    *entry = Some(elements.len());
+                    factors.push(letters);
+                    span.add_vector(unit.as_slice());
+                    elements.push(unit.clone());
+                }
+                unit.set_to_zero();
+            }
+            for (&(gen_degree, gen_idx, idx), v) in products.iter().zip(&product_vectors) {
+                if span.dimension() == subalgebra.dimension() {
+                    break;
+                }
+                if span.add_vector(v.as_slice()) > elements.len() {
+                    let mut word = vec![(gen_degree, gen_idx)];
+                    word.extend_from_slice(
+                        &self.subalgebra[(degree - gen_degree) as usize].factors[idx],
+                    );
+                    factors.push(word);
+                    elements.push(v.clone());
+                }
+            }
+
+            let (quasi_inverse, _) = Self::quasi_inverse_and_kernel(p, dimension, &elements);
+
+            let generators: Vec<usize> = generators
+                .iter()
+                .filter(|&&(gen_degree, _)| gen_degree == degree)
+                .map(|&(_, gen_idx)| admissible_to_index[gen_idx].unwrap())
+                .collect();
+
+            let to_products = |v: &FpVector| {
+                v.iter_nonzero()
+                    .map(|(row, c)| {
+                        let (gen_degree, gen_idx, idx) = products[row];
+                        (
+                            c,
+                            (gen_degree, self.subalgebra_index(gen_degree, gen_idx)),
+                            (degree - gen_degree, idx),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            };
+            let decompositions = elements
+                .iter()
+                .enumerate()
+                .map(|(idx, elt)| {
+                    if degree == 0 || generators.contains(&idx) {
+                        return vec![];
+                    }
+                    let mut result = FpVector::new(p, products.len());
+                    product_qi.apply(result.as_slice_mut(), 1, elt.as_slice());
+                    to_products(&result)
+                })
+                .collect();
+            let relations = kernel.iter().map(|v| to_products(&v.to_owned())).collect();
+
+            let factors_to_index = factors
+                .iter()
+                .enumerate()
+                .map(|(idx, word)| (word.clone(), idx))
+                .collect();
+
+            SubalgebraBasis {
+                factors,
+                factors_to_index,
+                elements,
+                admissible_to_index,
+                quasi_inverse,
+                generators,
+                decompositions,
+                relations,
+            }
+        });
+    }
+
     fn generate_excess_table(&self, max_degree: i32) {
         let p = self.prime();
         self.excess_table.extend(max_degree as usize, |n| {
@@ -1362,7 +1875,7 @@ impl AdemAlgebra {
                 }
                 cur_excess = excess;
             }
-            let dim = self.dimension(n as i32);
+            let dim = self.admissible_dimension(n as i32);
             for _ in cur_excess..n as u32 {
                 new_entry.push(dim);
             }
@@ -1373,6 +1886,107 @@ impl AdemAlgebra {
 
 impl Bialgebra for AdemAlgebra {
     fn decompose(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize)> {
+        if self.profile.is_trivial() {
+            return self.decompose_admissible(op_deg, op_idx);
+        }
+        // The factors act from right to left
+        self.subalgebra[op_deg as usize].factors[op_idx]
+            .iter()
+            .rev()
+            .map(|&(degree, idx)| (degree, self.subalgebra_index(degree, idx)))
+            .collect()
+    }
+
+    /// The antipodes are memoized, see [`AdemAlgebra::compute_antipodes`].
+    fn antipode(&self, mut result: SliceMut, coeff: u32, op_deg: i32, op_idx: usize) {
+        self.compute_antipodes(op_deg);
+        result.add(
+            self.antipode_table[op_deg as usize][op_idx].as_slice(),
+            coeff,
+        );
+    }
+
+    fn coproduct(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize, i32, usize)> {
+        if self.profile.is_trivial() {
+            return self.coproduct_admissible(op_deg, op_idx);
+        }
+        let [(degree, idx)] = self.subalgebra[op_deg as usize].factors[op_idx][..] else {
+            panic!("Coproduct of Adem basis element that is not returned by decompose");
+        };
+        self.coproduct_admissible(degree, idx)
+            .into_iter()
+            .map(|(l_deg, l_idx, r_deg, r_idx)| {
+                (
+                    l_deg,
+                    self.subalgebra_index(l_deg, l_idx),
+                    r_deg,
+                    self.subalgebra_index(r_deg, r_idx),
+                )
+            })
+            .collect()
+    }
+}
+
+impl AdemAlgebra {
+    /// Compute the antipodes (also known as the conjugations) of all basis elements up to degree
+    /// `max_degree`. The antipode of a letter $x$ (see [`Bialgebra::decompose`]) is computed
+    /// recursively from the coproduct via the relation $\sum x' \chi(x'') = 0$ for $|x| > 0$,
+    /// and we extend it to products of letters using $\chi(ab) = (-1)^{|a||b|} \chi(b) \chi(a)$.
+    /// The basis must have been computed up to `max_degree`.
+    pub fn compute_antipodes(&self, max_degree: i32) {
+        let p = self.prime();
+        self.antipode_table.extend(max_degree as usize, |degree| {
+            let degree = degree as i32;
+            let dim = self.dimension(degree);
+            if degree == 0 {
+                let mut unit = FpVector::new(p, dim);
+                unit.set_entry(0, 1);
+                return vec![unit];
+            }
+            let chi = |d: i32, i: usize| &self.antipode_table[d as usize][i];
+            (0..dim)
+                .map(|idx| {
+                    let letters = self.decompose(degree, idx);
+                    if letters.len() == 1 {
+                        let mut result = FpVector::new(p, dim);
+                        for (l_deg, l_idx, r_deg, r_idx) in self.coproduct(degree, idx) {
+                            if l_deg == 0 {
+                                continue;
+                            }
+                            self.multiply_basis_element_by_element(
+                                result.as_slice_mut(),
+                                *p - 1,
+                                l_deg,
+                                l_idx,
+                                r_deg,
+                                chi(r_deg, r_idx).as_slice(),
+                            );
+                        }
+                        return result;
+                    }
+                    // The first letter acts first, so its antipode comes first
+                    let (mut result_deg, first) = letters[0];
+                    let mut result = chi(result_deg, first).clone();
+                    for &(d, i) in &letters[1..] {
+                        let mut product = FpVector::new(p, self.dimension(result_deg + d));
+                        self.multiply_element_by_element(
+                            product.as_slice_mut(),
+                            minus_one_to_the_n(*p, d * result_deg),
+                            result_deg,
+                            result.as_slice(),
+                            d,
+                            chi(d, i).as_slice(),
+                        );
+                        result = product;
+                        result_deg += d;
+                    }
+                    result
+                })
+                .collect()
+        });
+    }
+
+    fn decompose_admissible(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize)> {
         let elt = &self.basis_table[op_deg as usize][op_idx];
         if self.generic {
             let mut result: Vec<(i32, usize)> = Vec::with_capacity(elt.ps.len() * 2 + 1);
@@ -1398,7 +2012,7 @@ impl Bialgebra for AdemAlgebra {
         }
     }
 
-    fn coproduct(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize, i32, usize)> {
+    fn coproduct_admissible(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize, i32, usize)> {
         if self.generic {
             if op_deg == 1 {
                 vec![(1, 0, 0, 0), (0, 0, 1, 0)]
@@ -1430,11 +2044,360 @@ mod tests {
     use rstest::rstest;
     use std::fmt::Write as _; // Needed for write! macro for String
 
+    #[test]
+    fn test_adem_string_unicode() {
+        let check = |algebra: &AdemAlgebra, elt: &str, expected: &str| {
+            let (degree, idx) = algebra.basis_element_from_string(elt).unwrap();
+            assert_eq!(
+                algebra.basis_element_to_string_unicode(degree, idx),
+                expected
+            );
+        };
+
+        let algebra = AdemAlgebra::new(fp::prime::TWO, false);
+        algebra.compute_basis(20);
+        check(&algebra, "Sq2", "Sq²");
+        check(&algebra, "Sq4 Sq2", "Sq⁴ Sq²");
+        check(&algebra, "Sq10 Sq5", "Sq¹⁰ Sq⁵");
+        assert_eq!(algebra.basis_element_to_string_unicode(0, 0), "1");
+        let milnor = crate::MilnorAlgebra::new(fp::prime::TWO, false);
+        milnor.compute_basis(0);
+        assert_eq!(milnor.basis_element_to_string_unicode(0, 0), "1");
+
+        let algebra = AdemAlgebra::new(ValidPrime::new(3), false);
+        algebra.compute_basis(40);
+        check(&algebra, "P3", "P³");
+        check(&algebra, "b P1", "β P¹");
+        check(&algebra, "P4 b P1", "P⁴ β P¹");
+        assert_eq!(algebra.basis_element_to_string_unicode(0, 0), "1");
+
+        let algebra = AdemAlgebra::new_with_profile(fp::prime::TWO, an_profile(1), false);
+        algebra.compute_basis(6);
+        assert_eq!(algebra.basis_element_to_string_unicode(0, 0), "1");
+        assert_eq!(algebra.basis_element_to_string_unicode(5, 0), "Sq² Sq³");
+    }
+
+    #[rstest(p, case(2), case(3))]
+    #[trace]
+    fn test_computed_through(p: u32) {
+        let algebra = AdemAlgebra::new(ValidPrime::new(p), false);
+        assert_eq!(algebra.computed_through(), -1);
+
+        algebra.compute_basis(20);
+        assert_eq!(algebra.computed_through(), 20);
+        let dim = algebra.dimension(20);
+
+        algebra.compute_basis(20);
+        algebra.compute_basis(10);
+        assert_eq!(algebra.computed_through(), 20);
+        assert_eq!(algebra.dimension(20), dim);
+    }
+
     #[rstest(p, max_degree, case(2, 32), case(3, 120))]
     #[trace]
-    fn test_adem_decompose(p: u32, max_degree: i32) {
+    fn test_basis_is_admissible(p: u32, max_degree: i32) {
+        let algebra = AdemAlgebra::new(ValidPrime::new(p), false);
+        algebra.compute_basis(max_degree);
+        for degree in 0..=max_degree {
+            for idx in 0..algebra.dimension(degree) {
+                let elt = algebra.basis_element_from_index(degree, idx);
+                assert!(algebra.is_admissible(elt), "{elt:?}");
+                assert_eq!(algebra.try_basis_element_to_index(elt), Some(idx));
+            }
+        }
+    }
+
+    /// The profile of $A(n)$.
+    fn an_profile(n: usize) -> MilnorProfile {
+        MilnorProfile {
+            truncated: true,
+            q_part: (1 << (n + 2)) - 1,
+            p_part: (0..=n).map(|i| (n + 1 - i) as _).collect(),
+        }
+    }
+
+    /// The Adem algebra with profile $A(n)$, or the full Steenrod algebra if `n` is `None`.
+    fn algebra_with_n(p: ValidPrime, n: Option<usize>) -> AdemAlgebra {
+        match n {
+            Some(n) => AdemAlgebra::new_with_profile(p, an_profile(n), false),
+            None => AdemAlgebra::new(p, false),
+        }
+    }
+
+    #[rstest(
+        p,
+        n,
+        max_degree,
+        case(2, 0, 5),
+        case(2, 1, 10),
+        case(2, 2, 30),
+        case(2, 3, 80),
+        case(3, 0, 20),
+        case(3, 1, 80)
+    )]
+    #[trace]
+    fn test_subalgebra_dimension(p: u32, n: usize, max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let adem = AdemAlgebra::new_with_profile(p, an_profile(n), false);
+        let milnor = crate::MilnorAlgebra::new_with_profile(p, an_profile(n), false);
+        adem.compute_basis(max_degree);
+        milnor.compute_basis(max_degree);
+        for t in 0..=max_degree {
+            assert_eq!(adem.dimension(t), milnor.dimension(t), "t = {t}");
+        }
+    }
+
+    #[test]
+    fn test_subalgebra_indices() {
+        let algebra = AdemAlgebra::new_with_profile(fp::prime::TWO, an_profile(1), false);
+        algebra.compute_basis(6);
+
+        let sq = |ps: Vec<u32>| AdemBasisElement {
+            degree: ps.iter().sum::<u32>() as i32,
+            bocksteins: 0,
+            ps,
+            p_or_sq: false,
+        };
+
+        // The admissible basis is that of the full Steenrod algebra, where Sq3 Sq1 comes after Sq4
+        assert_eq!(algebra.basis_element_from_index(4, 1), &sq(vec![3, 1]));
+        assert_eq!(algebra.basis_element_to_index(&sq(vec![3, 1])), 1);
+        assert_eq!(algebra.basis_element_to_index(&sq(vec![5])), 0);
+
+        assert_eq!(
+            algebra.try_subalgebra_basis_element(4, 0),
+            Some(&sq(vec![3, 1]))
+        );
+        // Sq2 Sq3 = Sq5 + Sq4 Sq1
+        assert_eq!(algebra.try_subalgebra_basis_element(5, 0), None);
+        assert_eq!(algebra.try_subalgebra_basis_element(4, 1), None);
+        assert_eq!(algebra.try_subalgebra_basis_element(7, 0), None);
+
+        let algebra = AdemAlgebra::new(fp::prime::TWO, false);
+        algebra.compute_basis(4);
+        assert_eq!(
+            algebra.try_subalgebra_basis_element(4, 0),
+            Some(&sq(vec![4]))
+        );
+        assert_eq!(algebra.try_subalgebra_basis_element(4, 2), None);
+    }
+
+    #[rstest(p, n, max_degree, case(2, 1, 10), case(2, 2, 30), case(3, 1, 80))]
+    #[trace]
+    fn test_subalgebra_string(p: u32, n: usize, max_degree: i32) {
+        let algebra = AdemAlgebra::new_with_profile(ValidPrime::new(p), an_profile(n), false);
+        check_string(&algebra, max_degree);
+    }
+
+    #[test]
+    fn test_a1_basis() {
+        let p = fp::prime::TWO;
+        let algebra = AdemAlgebra::new_with_profile(p, an_profile(1), false);
+        algebra.compute_basis(7);
+
+        let names = |t| {
+            (0..algebra.dimension(t))
+                .map(|i| algebra.basis_element_to_string(t, i))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(3), ["Sq3", "Sq2 Sq1"]);
+        assert_eq!(names(4), ["Sq3 Sq1"]);
+        // Sq5 is not in A(1), and Sq2 Sq3 = Sq5 + Sq4 Sq1
+        assert_eq!(names(5), ["Sq2 Sq3"]);
+        assert_eq!(algebra.dimension(6), 1);
+        assert_eq!(algebra.dimension(7), 0);
+        assert_eq!(algebra.basis_element_from_string("Sq5"), None);
+        assert_eq!(algebra.basis_element_from_string("Sq2 Sq3"), Some((5, 0)));
+        assert_eq!(algebra.to_admissibles(5, 0).iter_nonzero().count(), 2);
+
+        let sq = |n: &str| algebra.basis_element_from_string(n).unwrap();
+        let mut sq2sq1 = FpVector::new(p, algebra.dimension(3));
+        algebra.multiply_basis_elements(sq2sq1.as_slice_mut(), 1, 2, sq("Sq2").1, 1, sq("Sq1").1);
+        let mut result = FpVector::new(p, algebra.dimension(5));
+        algebra.multiply_element_by_basis_element(
+            result.as_slice_mut(),
+            1,
+            3,
+            sq2sq1.as_slice(),
+            2,
+            sq("Sq2").1,
+        );
+        assert_eq!(algebra.element_to_string(5, result.as_slice()), "Sq2 Sq3");
+    }
+
+    #[test]
+    fn test_magic() {
+        let magic = |n| AdemAlgebra::new_with_profile(fp::prime::TWO, an_profile(n), false).magic();
+        assert_eq!(AdemAlgebra::new(fp::prime::TWO, false).magic(), 2 << 16);
+        assert_eq!(magic(0), (2 << 16) + 2);
+        assert_ne!(magic(1), magic(2));
+    }
+
+    #[rstest(p, n, max_degree, case(2, 1, 10), case(2, 2, 30), case(3, 1, 80))]
+    #[trace]
+    fn test_subalgebra_bialgebra_decompose(p: u32, n: usize, max_degree: i32) {
         let p = ValidPrime::new(p);
-        let algebra = AdemAlgebra::new(p, false);
+        let algebra = AdemAlgebra::new_with_profile(p, an_profile(n), false);
+        algebra.compute_basis(max_degree);
+        for t in 0..=max_degree {
+            for idx in 0..algebra.dimension(t) {
+                let factors = algebra.decompose(t, idx);
+                assert_eq!(factors.iter().map(|&(d, _)| d).sum::<i32>(), t);
+
+                // The factors act from right to left, so their product is the element
+                let mut elt = FpVector::new(p, 1);
+                elt.set_entry(0, 1);
+                let mut degree = 0;
+                for &(d, i) in &factors {
+                    let mut result = FpVector::new(p, algebra.dimension(degree + d));
+                    algebra.multiply_basis_element_by_element(
+                        result.as_slice_mut(),
+                        1,
+                        d,
+                        i,
+                        degree,
+                        elt.as_slice(),
+                    );
+                    elt = result;
+                    degree += d;
+                }
+                let mut expected = FpVector::new(p, algebra.dimension(t));
+                expected.set_entry(idx, 1);
+                assert_eq!(elt, expected, "{}", algebra.basis_element_to_string(t, idx));
+            }
+        }
+    }
+
+    #[rstest(
+        p,
+        n,
+        max_degree,
+        case(2, None, 30),
+        case(3, None, 60),
+        case(2, Some(2), 30),
+        case(3, Some(1), 60)
+    )]
+    #[trace]
+    fn test_antipode(p: u32, n: Option<usize>, max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = algebra_with_n(p, n);
+        algebra.compute_basis(max_degree);
+
+        let chi = |degree: i32, idx: usize| -> FpVector {
+            let mut result = FpVector::new(p, algebra.dimension(degree));
+            algebra.antipode(result.as_slice_mut(), 1, degree, idx);
+            result
+        };
+
+        for degree in 1..=max_degree {
+            let dim = algebra.dimension(degree);
+            for idx in 0..dim {
+                let name = algebra.basis_element_to_string(degree, idx);
+
+                // The defining relation \sum x' \chi(x'') = 0 for letters
+                if algebra.decompose(degree, idx).len() == 1 {
+                    let mut sum = FpVector::new(p, dim);
+                    for (l_deg, l_idx, r_deg, r_idx) in algebra.coproduct(degree, idx) {
+                        algebra.multiply_basis_element_by_element(
+                            sum.as_slice_mut(),
+                            1,
+                            l_deg,
+                            l_idx,
+                            r_deg,
+                            chi(r_deg, r_idx).as_slice(),
+                        );
+                    }
+                    assert!(sum.is_zero(), "Antipode relation fails for {name}");
+                }
+
+                // \chi^2 = id
+                let mut chi2 = FpVector::new(p, dim);
+                for (i, c) in chi(degree, idx).iter_nonzero() {
+                    chi2.add(&chi(degree, i), c);
+                }
+                let mut expected = FpVector::new(p, dim);
+                expected.set_entry(idx, 1);
+                assert_eq!(chi2, expected, "\\chi^2 is not the identity on {name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            AdemAlgebra::new(ValidPrime::new(2), false).to_string(),
+            "AdemAlgebra(p=2)"
+        );
+        assert_eq!(
+            AdemAlgebra::new(ValidPrime::new(5), false).to_string(),
+            "AdemAlgebra(p=5)"
+        );
+        assert_eq!(
+            AdemAlgebra::new_with_profile(ValidPrime::new(2), an_profile(1), false).to_string(),
+            "AdemAlgebra(p=2, profile=A(1))"
+        );
+    }
+
+    #[test]
+    fn test_try_basis_element_to_index() {
+        let algebra = AdemAlgebra::new(fp::prime::TWO, false);
+        algebra.compute_basis(10);
+
+        let sq = |ps: Vec<u32>| AdemBasisElement {
+            degree: ps.iter().sum::<u32>() as i32,
+            bocksteins: 0,
+            ps,
+            p_or_sq: false,
+        };
+
+        assert!(algebra
+            .try_basis_element_to_index(&sq(vec![2, 1]))
+            .is_some());
+        assert_eq!(algebra.try_basis_element_to_index(&sq(vec![1, 1])), None);
+        assert_eq!(algebra.try_basis_element_to_index(&sq(vec![2, 2])), None);
+        assert_eq!(algebra.try_basis_element_to_index(&sq(vec![0])), None);
+        // Not yet computed
+        assert_eq!(algebra.try_basis_element_to_index(&sq(vec![8, 4])), None);
+
+        let algebra = AdemAlgebra::new(ValidPrime::new(3), false);
+        algebra.compute_basis(20);
+        // P^1 b P^1 is inadmissible since 1 < 3 * 1 + 1
+        let elt = AdemBasisElement {
+            degree: 9,
+            bocksteins: 0b010,
+            ps: vec![1, 1],
+            p_or_sq: true,
+        };
+        assert_eq!(algebra.try_basis_element_to_index(&elt), None);
+        // P^4 b P^1 is admissible
+        let elt = AdemBasisElement {
+            degree: 21,
+            bocksteins: 0b010,
+            ps: vec![4, 1],
+            p_or_sq: true,
+        };
+        assert!(!algebra.is_admissible(&AdemBasisElement {
+            ps: vec![3, 1],
+            ..elt.clone()
+        }));
+        assert!(algebra.is_admissible(&elt));
+    }
+
+    #[rstest(p, max_degree, case(2, 32), case(3, 120))]
+    #[trace]
+    fn test_adem_decompose(p: u32, max_degree: i32) {
+        check_decompose(&AdemAlgebra::new(ValidPrime::new(p), false), max_degree);
+    }
+
+    #[rstest(p, n, max_degree, case(2, 2, 30), case(3, 1, 80))]
+    #[trace]
+    fn test_subalgebra_decompose(p: u32, n: usize, max_degree: i32) {
+        let algebra = AdemAlgebra::new_with_profile(ValidPrime::new(p), an_profile(n), false);
+        check_decompose(&algebra, max_degree);
+    }
+
+    fn check_decompose(algebra: &AdemAlgebra, max_degree: i32) {
+        let p = algebra.prime();
         algebra.compute_basis(max_degree);
         for i in 1..=max_degree {
             let dim = algebra.dimension(i);
@@ -1484,8 +2447,18 @@ mod tests {
     #[rstest(p, max_degree, case(2, 32), case(3, 120))]
     #[trace]
     fn test_adem_relations(p: u32, max_degree: i32) {
-        let p = ValidPrime::new(p);
-        let algebra = AdemAlgebra::new(p, false);
+        check_relations(&AdemAlgebra::new(ValidPrime::new(p), false), max_degree);
+    }
+
+    #[rstest(p, n, max_degree, case(2, 2, 30), case(3, 1, 80))]
+    #[trace]
+    fn test_subalgebra_relations(p: u32, n: usize, max_degree: i32) {
+        let algebra = AdemAlgebra::new_with_profile(ValidPrime::new(p), an_profile(n), false);
+        check_relations(&algebra, max_degree);
+    }
+
+    fn check_relations(algebra: &AdemAlgebra, max_degree: i32) {
+        let p = algebra.prime();
         algebra.compute_basis(max_degree);
         let mut output_vec = FpVector::new(p, 0);
         for i in 1..=max_degree {
@@ -1537,8 +2510,10 @@ mod tests {
     #[case(2, 32)]
     #[case(3, 106)]
     fn test_adem_string(#[case] p: u32, #[case] max_degree: i32) {
-        let p = ValidPrime::new(p);
-        let algebra = AdemAlgebra::new(p, false);
+        check_string(&AdemAlgebra::new(ValidPrime::new(p), false), max_degree);
+    }
+
+    fn check_string(algebra: &AdemAlgebra, max_degree: i32) {
         algebra.compute_basis(max_degree);
         for t in 0..max_degree {
             for i in 0..algebra.dimension(t) {
@@ -6,6 +6,17 @@ use std::fmt::Write as _; // Needed for write! macro for String
 #[cfg(doc)]
 use fp::vector::FpVector;
 
+/// A filtration one element of $\mathrm{Ext}(k, k)$, given by an indecomposable element of the
+/// algebra. See [`Algebra::filtration_one_product_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductInfo {
+    pub name: String,
+    pub op_degree: i32,
+    pub op_index: usize,
+    /// The stem of the product, namely `op_degree - 1`.
+    pub stem: i32,
+}
+
 /// A graded algebra over $\mathbb{F}_p$.
 ///
 /// Each degree is finite dimensional, and equipped with a distinguished ordered basis. Basis
@@ -49,9 +60,27 @@ pub trait Algebra: std::fmt::Display + Send + Sync + 'static {
     /// same argument.
     fn compute_basis(&self, degree: i32);
 
+    /// Returns the largest degree through which [`Algebra::compute_basis`] has been run. Calling
+    /// `compute_basis` with a degree at most this value is a no-op. Algebras that need no
+    /// computation return `i32::MAX`.
+    fn computed_through(&self) -> i32;
+
     /// Returns the dimension of the algebra in degree `degree`.
     fn dimension(&self, degree: i32) -> usize;
 
+    /// Returns the range of indices of basis elements in degree `degree`.
+    fn basis(&self, degree: i32) -> std::ops::Range<usize> {
+        0..self.dimension(degree)
+    }
+
+    /// Iterates over the indices of basis elements in degree `degree`.
+    fn iter_basis(&self, degree: i32) -> impl Iterator<Item = usize>
+    where
+        Self: Sized,
+    {
+        self.basis(degree)
+    }
+
     /// Computes the product `r * s` of two basis elements, and adds the
     /// result to `result`.
     ///
@@ -155,9 +184,30 @@ pub trait Algebra: std::fmt::Display + Send + Sync + 'static {
         vec![]
     }
 
+    /// The products of [`Algebra::default_filtration_one_products`], together with their stems.
+    fn filtration_one_product_info(&self) -> Vec<ProductInfo> {
+        self.default_filtration_one_products()
+            .into_iter()
+            .map(|(name, op_degree, op_index)| ProductInfo {
+                name,
+                op_degree,
+                op_index,
+                stem: op_degree - 1,
+            })
+            .collect()
+    }
+
     /// Converts a basis element into a string for display.
     fn basis_element_to_string(&self, degree: i32, idx: usize) -> String;
 
+    /// Converts a basis element into a string for display in a terminal, using Unicode
+    /// superscripts and subscripts where appropriate, e.g. `Sq²` instead of `Sq2`. This is for
+    /// display only and need not be parseable by [`Algebra::basis_element_from_string`]. The
+    /// default implementation is [`Algebra::basis_element_to_string`].
+    fn basis_element_to_string_unicode(&self, degree: i32, idx: usize) -> String {
+        self.basis_element_to_string(degree, idx)
+    }
+
     /// Converts a string to a basis element. This must be a one-sided inverse inverse to
     /// both basis_element_to_string and generator_to_string (if [`GeneratedAlgebra`] is
     /// implemented).
@@ -190,6 +240,24 @@ pub trait Algebra: std::fmt::Display + Send + Sync + 'static {
     }
 }
 
+/// Write `n` in Unicode superscript digits.
+pub(crate) fn unicode_superscript(n: u32) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    n.to_string()
+        .bytes()
+        .map(|c| DIGITS[(c - b'0') as usize])
+        .collect()
+}
+
+/// Write `n` in Unicode subscript digits.
+pub(crate) fn unicode_subscript(n: u32) -> String {
+    const DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+    n.to_string()
+        .bytes()
+        .map(|c| DIGITS[(c - b'0') as usize])
+        .collect()
+}
+
 pub trait UnstableAlgebra: Algebra {
     fn dimension_unstable(&self, degree: i32, excess: i32) -> usize;
 
@@ -539,7 +607,9 @@ macro_rules! dispatch_algebra {
                 fn magic(&self) -> u32;
                 fn prime(&self) -> ValidPrime;
                 fn compute_basis(&self, degree: i32);
+                fn computed_through(&self) -> i32;
                 fn dimension(&self, degree: i32) -> usize;
+                fn basis(&self, degree: i32) -> std::ops::Range<usize>;
                 fn multiply_basis_elements(
                     &self,
                     result: SliceMut,
@@ -583,6 +653,7 @@ macro_rules! dispatch_algebra {
                 fn default_filtration_one_products(&self) -> Vec<(String, i32, usize)>;
 
                 fn basis_element_to_string(&self, degree: i32, idx: usize) -> String;
+                fn basis_element_to_string_unicode(&self, degree: i32, idx: usize) -> String;
                 fn basis_element_from_string(&self, elt: &str) -> Option<(i32, usize)>;
 
                 fn element_to_string(&self, degree: i32, element: Slice) -> String;
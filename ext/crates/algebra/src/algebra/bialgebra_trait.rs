@@ -1,4 +1,7 @@
 use crate::algebra::Algebra;
+use crate::module::Module;
+use fp::prime::minus_one_to_the_n;
+use fp::vector::{FpVector, Slice, SliceMut};
 
 /// An [`Algebra`] equipped with a coproduct operation that makes it into a
 /// bialgebra.
@@ -24,4 +27,132 @@ pub trait Bialgebra: Algebra {
     /// an element naturally decomposes into a product of Steenrod squares, each of which has an
     /// easy coproduct formula.
     fn decompose(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize)>;
+
+    /// Add `coeff` times the antipode (also known as the conjugation) $\chi(x)$ of the basis
+    /// element `x = (op_deg, op_idx)` to `result`.
+    ///
+    /// The default implementation assumes the algebra is connected. It computes the antipode of
+    /// each factor returned by [`Bialgebra::decompose()`] recursively from the coproduct via the
+    /// relation $\sum x' \chi(x'') = 0$ for $|x| > 0$, and then uses
+    /// $\chi(ab) = (-1)^{|a||b|} \chi(b) \chi(a)$. Nothing is memoized, so implementors that
+    /// compute many antipodes should override this.
+    fn antipode(&self, mut result: SliceMut, coeff: u32, op_deg: i32, op_idx: usize) {
+        let p = self.prime();
+        if op_deg == 0 {
+            result.add_basis_element(op_idx, coeff);
+            return;
+        }
+        let chi = |degree: i32, idx: usize| {
+            let mut v = FpVector::new(p, self.dimension(degree));
+            self.antipode(v.as_slice_mut(), 1, degree, idx);
+            v
+        };
+
+        let factors = self.decompose(op_deg, op_idx);
+        if let [_] = factors[..] {
+            for (l_deg, l_idx, r_deg, r_idx) in self.coproduct(op_deg, op_idx) {
+                if l_deg == 0 {
+                    continue;
+                }
+                self.multiply_basis_element_by_element(
+                    result.copy(),
+                    (*p - 1) * coeff % *p,
+                    l_deg,
+                    l_idx,
+                    r_deg,
+                    chi(r_deg, r_idx).as_slice(),
+                );
+            }
+            return;
+        }
+
+        // The first factor acts first, so its antipode comes first
+        let (mut degree, first) = factors[0];
+        let mut product = chi(degree, first);
+        for &(d, i) in &factors[1..] {
+            let mut next = FpVector::new(p, self.dimension(degree + d));
+            self.multiply_element_by_element(
+                next.as_slice_mut(),
+                minus_one_to_the_n(*p, d * degree),
+                degree,
+                product.as_slice(),
+                d,
+                chi(d, i).as_slice(),
+            );
+            product = next;
+            degree += d;
+        }
+        result.add(product.as_slice(), coeff);
+    }
+
+    /// Act on the tensor product $x \otimes y$, where $x$ is a basis element of `left` and $y$ is
+    /// an element of `right`, via the coproduct. For each term $a \otimes b$ of
+    /// $\Delta(\mathrm{op})$ with nonzero action, `f` is called with the degree of $ax$, the vector
+    /// `coeff` $\cdot ax$ and the vector $(-1)^{|b||x|} by$. The action of `op` on $x \otimes y$ is
+    /// the sum of the resulting tensors.
+    ///
+    /// Since $ax$ does not depend on $y$, acting on all of $y$ at once computes each $ax$ only
+    /// once, as opposed to once for every basis element of `right` that appears in $y$.
+    ///
+    /// As with [`Bialgebra::coproduct()`], `op` must have been returned by
+    /// [`Bialgebra::decompose()`].
+    #[allow(clippy::too_many_arguments)]
+    fn act_diagonally<M, N>(
+        &self,
+        left: &M,
+        right: &N,
+        coeff: u32,
+        (op_deg, op_idx): (i32, usize),
+        (left_deg, left_idx): (i32, usize),
+        (right_deg, right_input): (i32, Slice),
+        mut f: impl FnMut(i32, Slice, Slice),
+    ) where
+        Self: Sized,
+        M: Module<Algebra = Self>,
+        N: Module<Algebra = Self>,
+    {
+        let p = self.prime();
+        let mut left_result = FpVector::new(p, 0);
+        let mut right_result = FpVector::new(p, 0);
+
+        for (op_deg_l, op_idx_l, op_deg_r, op_idx_r) in self.coproduct(op_deg, op_idx) {
+            let left_dim = Module::dimension(left, left_deg + op_deg_l);
+            let right_dim = Module::dimension(right, right_deg + op_deg_r);
+            if left_dim == 0 || right_dim == 0 {
+                continue;
+            }
+
+            left_result.set_scratch_vector_size(left_dim);
+            left.act_on_basis(
+                left_result.as_slice_mut(),
+                coeff,
+                op_deg_l,
+                op_idx_l,
+                left_deg,
+                left_idx,
+            );
+            if left_result.is_zero() {
+                continue;
+            }
+
+            right_result.set_scratch_vector_size(right_dim);
+            right.act(
+                right_result.as_slice_mut(),
+                minus_one_to_the_n(*p, op_deg_r * left_deg),
+                op_deg_r,
+                op_idx_r,
+                right_deg,
+                right_input,
+            );
+            if right_result.is_zero() {
+                continue;
+            }
+
+            f(
+                left_deg + op_deg_l,
+                left_result.as_slice(),
+                right_result.as_slice(),
+            );
+        }
+    }
 }
@@ -1,3 +1,6 @@
+use std::sync::OnceLock;
+
+use fp::matrix::Matrix;
 use fp::vector::FpVector;
 use once::OnceVec;
 
@@ -38,6 +41,39 @@ const TAU_DEGREES: [[i32; MAX_XI_TAU]; NUM_PRIMES] = {
     res
 };
 
+/// If p is the nth prime, then `XI_LENGTHS[n]` is the number of entries of `XI_DEGREES[n]` that
+/// do not come from an overflowing computation.
+const XI_LENGTHS: [usize; NUM_PRIMES] = {
+    let mut res = [0; NUM_PRIMES];
+    const_for! { p_idx in 0 .. NUM_PRIMES {
+        let p = PRIMES[p_idx];
+        let mut n = 0;
+        while n < MAX_XI_TAU && p.checked_pow(n as u32 + 1).is_some() {
+            n += 1;
+        }
+        res[p_idx] = n;
+    }}
+    res
+};
+
+/// If p is the nth prime, then `TAU_LENGTHS[n]` is the number of entries of `TAU_DEGREES[n]` that
+/// do not come from an overflowing computation.
+const TAU_LENGTHS: [usize; NUM_PRIMES] = {
+    let mut res = [0; NUM_PRIMES];
+    const_for! { p_idx in 0 .. NUM_PRIMES {
+        let p = PRIMES[p_idx];
+        let mut n = 0;
+        while n < MAX_XI_TAU {
+            match p.checked_pow(n as u32) {
+                Some(x) if x <= 1 << 30 => n += 1,
+                _ => break,
+            }
+        }
+        res[p_idx] = n;
+    }}
+    res
+};
+
 pub fn adem_relation_coefficient(p: ValidPrime, x: u32, y: u32, j: u32, e1: u32, e2: u32) -> u32 {
     let pi32 = *p as i32;
     let x = x as i32;
@@ -53,6 +89,94 @@ pub fn adem_relation_coefficient(p: ValidPrime, x: u32, y: u32, j: u32, e1: u32,
     c % *p
 }
 
+/// If p is the nth prime, then `ADEM_RELATION_TERMS[n][x][y][b]` is the list of non-zero terms of
+/// the Adem relation for $P^x \beta^b P^y$. This is filled in lazily by
+/// [`adem_relation_coefficients`].
+type AdemRelationTerms = OnceVec<OnceVec<[Vec<(u32, u32, u32, u32)>; 2]>>;
+static ADEM_RELATION_TERMS: OnceLock<[AdemRelationTerms; NUM_PRIMES]> = OnceLock::new();
+
+/// Iterate over the non-zero terms of the Adem relation for $P^x \beta^b P^y$. Each term is
+/// returned as `(j, e1, e2, c)`, where `c` is the coefficient of $\beta^{e1} P^{x + y - j}
+/// \beta^{e2} P^j$ as computed by [`adem_relation_coefficient`]. The terms are cached, so the
+/// coefficients are only computed once.
+pub fn adem_relation_coefficients(
+    p: ValidPrime,
+    x: u32,
+    y: u32,
+    b: u32,
+) -> impl Iterator<Item = (u32, u32, u32, u32)> {
+    let table = &ADEM_RELATION_TERMS.get_or_init(|| std::array::from_fn(|_| OnceVec::new()))
+        [PRIME_TO_INDEX_MAP[*p as usize]];
+    table.extend(x as usize, |_| OnceVec::new());
+    table[x as usize].extend(y as usize, |y| {
+        [0, 1].map(|b| {
+            (0..=b)
+                .flat_map(|e1| {
+                    let e2 = b - e1;
+                    (0..=x / *p).filter_map(move |j| {
+                        let c = adem_relation_coefficient(p, x, y as u32, j, e1, e2);
+                        (c != 0).then_some((j, e1, e2, c))
+                    })
+                })
+                .collect()
+        })
+    });
+    table[x as usize][y as usize][b as usize].iter().copied()
+}
+
+/// Check [`adem_relation_coefficient`] against the product formula of the Milnor basis. For every
+/// inadmissible $P^x \beta^b P^y$ with $x + y \leq 2p$, we compute both sides of the Adem
+/// relation
+/// $$ P^x \beta^b P^y = \sum_{j, e_1, e_2} c_{j, e_1, e_2} \beta^{e_1} P^{x + y - j} \beta^{e_2} P^j $$
+/// in the Milnor basis, and return whether they agree. At $p = 2$, $P^x$ is $\mathrm{Sq}^x$ and
+/// there are no Bocksteins.
+///
+/// This is meant for validating the coefficients at primes that are not covered by the tests.
+pub fn verify_adem_coefficients(p: ValidPrime) -> bool {
+    use crate::algebra::milnor_algebra::{MilnorAlgebra, MilnorBasisElement};
+    use crate::algebra::Algebra;
+
+    let generic = *p != 2;
+    let q = if generic { 2 * *p as i32 - 2 } else { 1 };
+    let algebra = MilnorAlgebra::new(p, false);
+    algebra.compute_basis(2 * *p as i32 * q + 1);
+
+    // β^b P^x
+    let element = |b: u32, x: u32| MilnorBasisElement {
+        q_part: b,
+        p_part: if x == 0 { vec![] } else { vec![x] },
+        degree: x as i32 * q + b as i32,
+    };
+
+    let mut result = FpVector::new(p, 0);
+    for y in 1..=2 * *p {
+        for x in 1..=2 * *p - y {
+            for b in 0..=u32::from(generic) {
+                if x >= *p * y + b {
+                    continue;
+                }
+                let lhs_1 = element(0, x);
+                let lhs_2 = element(b, y);
+                result.set_scratch_vector_size(algebra.dimension(lhs_1.degree + lhs_2.degree));
+                algebra.multiply(result.as_slice_mut(), *p - 1, &lhs_1, &lhs_2);
+
+                for (j, e1, e2, c) in adem_relation_coefficients(p, x, y, b) {
+                    algebra.multiply(
+                        result.as_slice_mut(),
+                        c,
+                        &element(e1, x + y - j),
+                        &element(e2, j),
+                    );
+                }
+                if !result.is_zero() {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
 pub fn inadmissible_pairs(p: ValidPrime, generic: bool, degree: i32) -> Vec<(u32, u32, u32)> {
     let p = *p;
     let degree = degree as u32;
@@ -82,12 +206,39 @@ pub fn inadmissible_pairs(p: ValidPrime, generic: bool, degree: i32) -> Vec<(u32
     inadmissible_pairs
 }
 
+/// The degrees of $τ_0, τ_1, \ldots$ at the prime `p`. This is truncated so that all degrees fit
+/// in an `i32`, and has at most [`MAX_XI_TAU`] entries.
 pub fn tau_degrees(p: ValidPrime) -> &'static [i32] {
-    &TAU_DEGREES[PRIME_TO_INDEX_MAP[*p as usize]]
+    let idx = PRIME_TO_INDEX_MAP[*p as usize];
+    &TAU_DEGREES[idx][..TAU_LENGTHS[idx]]
 }
 
+/// The degrees of $ξ_1, ξ_2, \ldots$ at the prime `p`, divided by q. This is truncated so that all
+/// degrees fit in an `i32`, and has at most [`MAX_XI_TAU`] entries.
 pub fn xi_degrees(p: ValidPrime) -> &'static [i32] {
-    &XI_DEGREES[PRIME_TO_INDEX_MAP[*p as usize]]
+    let idx = PRIME_TO_INDEX_MAP[*p as usize];
+    &XI_DEGREES[idx][..XI_LENGTHS[idx]]
+}
+
+/// The largest `i` such that the degrees of $ξ_1, \ldots, ξ_i$ are all at most `max_degree`.
+/// Here `max_degree` is measured in the same units as [`xi_degrees`], i.e. divided by q.
+pub fn max_xi_index(p: ValidPrime, max_degree: i32) -> usize {
+    xi_degrees(p)
+        .iter()
+        .take_while(|&&d| d <= max_degree)
+        .count()
+}
+
+/// The `n` by `n` matrix whose `(i, j)`th entry is $\binom{i}{j}$ mod `p`. This is lower
+/// triangular with ones on the diagonal, hence invertible.
+pub fn binomial_matrix(p: ValidPrime, n: usize) -> Matrix {
+    let mut result = Matrix::new(p, n, n);
+    for i in 0..n {
+        for j in 0..=i {
+            result[i].set_entry(j, u32::binomial(p, i as u32, j as u32));
+        }
+    }
+    result
 }
 
 pub struct TruncatedPolynomialMonomialBasis {
@@ -317,6 +468,140 @@ impl<'a> Iterator for PartitionIterator<'a> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_verify_adem_coefficients() {
+        for p in [2, 3, 5, 7] {
+            assert!(verify_adem_coefficients(ValidPrime::new(p)), "p = {p}");
+        }
+    }
+
+    #[test]
+    fn test_adem_relation_coefficient_odd() {
+        let p = ValidPrime::new(3);
+        // P^1 P^1 = 2 P^2
+        assert_eq!(adem_relation_coefficient(p, 1, 1, 0, 0, 0), 2);
+        // P^1 β P^1 = β P^2 + P^2 β
+        assert_eq!(adem_relation_coefficient(p, 1, 1, 0, 1, 0), 1);
+        assert_eq!(adem_relation_coefficient(p, 1, 1, 0, 0, 1), 1);
+
+        let p = ValidPrime::new(5);
+        // P^1 P^1 = 2 P^2
+        assert_eq!(adem_relation_coefficient(p, 1, 1, 0, 0, 0), 2);
+        // P^1 β P^1 = β P^2 + P^2 β
+        assert_eq!(adem_relation_coefficient(p, 1, 1, 0, 1, 0), 1);
+        assert_eq!(adem_relation_coefficient(p, 1, 1, 0, 0, 1), 1);
+    }
+
+    #[test]
+    fn test_adem_relation_coefficients() {
+        for p in [2, 3, 5] {
+            let p = ValidPrime::new(p);
+            for x in 0..20 {
+                for y in 0..20 {
+                    for b in 0..=u32::from(*p != 2) {
+                        let mut expected = vec![];
+                        for e1 in 0..=b {
+                            let e2 = b - e1;
+                            for j in 0..=x / *p {
+                                let c = adem_relation_coefficient(p, x, y, j, e1, e2);
+                                if c != 0 {
+                                    expected.push((j, e1, e2, c));
+                                }
+                            }
+                        }
+                        assert_eq!(
+                            adem_relation_coefficients(p, x, y, b).collect::<Vec<_>>(),
+                            expected,
+                            "p = {p}, x = {x}, b = {b}, y = {y}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_xi_index() {
+        assert_eq!(max_xi_index(fp::prime::TWO, 0), 0);
+        assert_eq!(max_xi_index(fp::prime::TWO, 1), 1);
+        assert_eq!(max_xi_index(fp::prime::TWO, 1022), 9);
+        assert_eq!(max_xi_index(fp::prime::TWO, 1023), 10);
+        assert_eq!(max_xi_index(fp::prime::TWO, i32::MAX), MAX_XI_TAU);
+
+        for p in PRIMES {
+            let p = ValidPrime::new(p);
+            let q = if *p == 2 { 1 } else { 2 * *p as i32 - 2 };
+            // Make sure we never use an entry coming from an overflow.
+            for i in 0..max_xi_index(p, i32::MAX) {
+                let p_to_the_i = p.checked_pow(i as u32 + 1).unwrap();
+                assert_eq!(xi_degrees(p)[i], ((p_to_the_i - 1) / (*p - 1)) as i32);
+            }
+            if *p != 2 {
+                let n = std::cmp::min(max_xi_index(p, i32::MAX / q) + 1, MAX_XI_TAU);
+                for i in 0..n {
+                    let p_to_the_i = p.checked_pow(i as u32).unwrap();
+                    assert_eq!(tau_degrees(p)[i], (2 * p_to_the_i - 1) as i32);
+                }
+            }
+            for d in 0..100 {
+                let n = max_xi_index(p, d);
+                assert!(xi_degrees(p)[..n].iter().all(|&x| x <= d));
+                assert!(n == MAX_XI_TAU || xi_degrees(p)[n] > d);
+            }
+        }
+        // 19^8 > u32::MAX
+        assert_eq!(max_xi_index(ValidPrime::new(19), i32::MAX), 7);
+    }
+
+    #[test]
+    fn test_degrees_truncated() {
+        // 17^8 > u32::MAX
+        let p = ValidPrime::new(17);
+        assert_eq!(xi_degrees(p).len(), 7);
+        assert_eq!(tau_degrees(p).len(), 8);
+        assert_eq!(*tau_degrees(p).last().unwrap(), 2 * 17_i32.pow(7) - 1);
+
+        assert_eq!(xi_degrees(fp::prime::TWO).len(), MAX_XI_TAU);
+        for p in PRIMES {
+            let p = ValidPrime::new(p);
+            assert!(xi_degrees(p).iter().all(|&d| d > 0));
+            assert!(tau_degrees(p).iter().all(|&d| d > 0));
+        }
+    }
+
+    #[test]
+    fn test_binomial_matrix() {
+        for p in [2, 3, 5, 7] {
+            let p = ValidPrime::new(p);
+            let n = 30;
+            let m = binomial_matrix(p, n);
+            assert_eq!((m.rows(), m.columns()), (n, n));
+            for i in 0..n {
+                assert_eq!(m[i].entry(i), 1);
+                for j in i + 1..n {
+                    assert_eq!(m[i].entry(j), 0);
+                }
+                for j in 0..=i {
+                    assert_eq!(
+                        m[i].entry(j),
+                        u32::binomial(p, i as u32, j as u32),
+                        "p = {p}, i = {i}, j = {j}"
+                    );
+                }
+            }
+            // Pascal's rule
+            for i in 1..n {
+                for j in 1..n {
+                    assert_eq!(
+                        m[i].entry(j),
+                        (m[i - 1].entry(j - 1) + m[i - 1].entry(j)) % *p
+                    );
+                }
+            }
+        }
+        assert_eq!(binomial_matrix(fp::prime::TWO, 0).rows(), 0);
+    }
+
     #[test]
     fn test_trunc_poly_partitions() {
         let p = ValidPrime::new(3);
@@ -33,6 +33,10 @@ impl Algebra for Field {
 
     fn compute_basis(&self, _degree: i32) {}
 
+    fn computed_through(&self) -> i32 {
+        i32::MAX
+    }
+
     fn dimension(&self, degree: i32) -> usize {
         usize::from(degree == 0)
     }
@@ -1,7 +1,10 @@
 use itertools::Itertools;
 use rustc_hash::FxHashMap as HashMap;
 use std::cell::Cell;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
+use crate::algebra::algebra_trait::unicode_subscript;
 use crate::algebra::combinatorics;
 use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra, UnstableAlgebra};
 use fp::prime::{factor_pk, integer_power, Binomial, BitflagIterator, ValidPrime};
@@ -30,7 +33,7 @@ fn q_part_default() -> u32 {
 }
 
 #[cfg_attr(feature = "json", derive(Deserialize, Serialize))]
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MilnorProfile {
     /// If `true`, unspecified p_part entries will be 0. Otherwise they will be infinity.
     pub truncated: bool,
@@ -57,10 +60,21 @@ impl MilnorProfile {
 
     /// Checks whether the profile function is valid
     pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Checks whether the profile function defines a sub-Hopf algebra, and returns a description
+    /// of the first violated inequality otherwise.
+    pub fn validate(&self) -> Result<(), String> {
         for (i, &hi) in self.p_part.iter().enumerate() {
             for (j, &hj) in self.p_part.iter().enumerate().skip(i + 1) {
                 if hi > (j - i) as PPartEntry + hj && self.p_part[j - i] > hj {
-                    return false;
+                    return Err(format!(
+                        "p_part[{i}] = {hi} > {} + p_part[{j}] and p_part[{}] = {} > p_part[{j}] = {hj}",
+                        j - i,
+                        j - i,
+                        self.p_part[j - i]
+                    ));
                 }
             }
         }
@@ -68,7 +82,10 @@ impl MilnorProfile {
             let len = self.p_part.len();
             for (i, &hi) in self.p_part.iter().enumerate() {
                 if hi > (len - i) as PPartEntry {
-                    return false;
+                    return Err(format!(
+                        "p_part[{i}] = {hi} > {} but the profile is truncated after {len} entries",
+                        len - i
+                    ));
                 }
             }
         }
@@ -76,12 +93,15 @@ impl MilnorProfile {
             for i in BitflagIterator::set_bit_iterator(!self.q_part as u64) {
                 for j in 0..i {
                     if (self.q_part >> j) & 1 == 1 && self.get_p_part(i - j - 1) > j as PPartEntry {
-                        return false;
+                        return Err(format!(
+                            "Q_{j} is included and p_part[{}] > {j}, so Q_{i} must be included",
+                            i - j - 1
+                        ));
                     }
                 }
             }
         }
-        true
+        Ok(())
     }
 
     /// Whether the profile is that of A(n). This is relevant since A(n) is generated by P(p^n) and
@@ -277,17 +297,45 @@ pub struct MilnorAlgebra {
 
     excess_table: OnceVec<Vec<usize>>,
 
+    /// A directory to cache [`Self::ppart_table`] in. See [`Self::set_basis_cache_dir`].
+    basis_cache_dir: Option<PathBuf>,
+    /// Whether newly computed P parts are written to the basis cache. See
+    /// [`Self::set_save_basis_cache`].
+    save_basis_cache: bool,
+    /// The number of rows of [`Self::ppart_table`] that were computed rather than read from the
+    /// basis cache.
+    ppart_rows_computed: AtomicUsize,
+
     /// degree -> MilnorBasisElement -> index
     basis_element_to_index_map: OnceVec<MilnorHashMap<usize>>,
 
+    /// degree -> index -> antipode of the basis element. See [`Self::compute_antipodes`].
+    antipode_table: OnceVec<Vec<FpVector>>,
+
     #[cfg(feature = "cache-multiplication")]
     /// source_deg -> target_deg -> source_op -> target_op
     multiplication_table: OnceVec<OnceVec<Vec<Vec<FpVector>>>>,
 }
 
 impl std::fmt::Display for MilnorAlgebra {
+    /// The profile is omitted if it is trivial, and is displayed as `A(n)` when possible.
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "MilnorAlgebra(p={})", self.prime())
+        write!(f, "MilnorAlgebra(p={}", self.prime())?;
+        let profile = &self.profile;
+        if !profile.is_trivial() {
+            if !profile.p_part.is_empty() && profile.is_an(self.generic()) {
+                write!(f, ", profile=A({})", profile.p_part.len() - 1)?;
+            } else {
+                write!(f, ", profile={:?}", profile.p_part)?;
+                if profile.truncated {
+                    write!(f, " truncated")?;
+                }
+                if self.generic() && profile.q_part != !0 {
+                    write!(f, ", q_part={:#b}", profile.q_part)?;
+                }
+            }
+        }
+        write!(f, ")")
     }
 }
 
@@ -297,7 +345,9 @@ impl MilnorAlgebra {
     }
 
     pub fn new_with_profile(p: ValidPrime, profile: MilnorProfile, unstable_enabled: bool) -> Self {
-        assert!(profile.is_valid());
+        if let Err(e) = profile.validate() {
+            panic!("Invalid profile with p_part {:?}: {e}", profile.p_part);
+        }
         Self {
             p,
             #[cfg(feature = "odd-primes")]
@@ -307,12 +357,30 @@ impl MilnorAlgebra {
             ppart_table: OnceVec::new(),
             basis_table: OnceVec::new(),
             excess_table: OnceVec::new(),
+            basis_cache_dir: None,
+            save_basis_cache: true,
+            ppart_rows_computed: AtomicUsize::new(0),
             basis_element_to_index_map: OnceVec::new(),
+            antipode_table: OnceVec::new(),
             #[cfg(feature = "cache-multiplication")]
             multiplication_table: OnceVec::new(),
         }
     }
 
+    /// The sub-Hopf algebra generated by the $P^i$, i.e. the subalgebra without any Bocksteins.
+    /// Its basis consists of the $P(R)$. At the prime 2 this is the whole Steenrod algebra.
+    pub fn new_restricted(p: ValidPrime, unstable_enabled: bool) -> Self {
+        Self::new_with_profile(
+            p,
+            MilnorProfile {
+                truncated: false,
+                q_part: 0,
+                p_part: vec![],
+            },
+            unstable_enabled,
+        )
+    }
+
     #[inline]
     pub fn generic(&self) -> bool {
         #[cfg(feature = "odd-primes")]
@@ -338,8 +406,48 @@ impl MilnorAlgebra {
         &self.profile
     }
 
+    /// Whether `elt` lies in the sub-Hopf algebra specified by the profile, i.e. whether it is a
+    /// basis element of this algebra. Unlike [`MilnorAlgebra::try_basis_element_to_index`], this
+    /// does not require the basis to be computed, and the degree of `elt` is ignored.
+    pub fn element_in_profile(&self, elt: &MilnorBasisElement) -> bool {
+        if elt.q_part & !self.profile.q_part != 0 {
+            return false;
+        }
+        let p = *self.prime() as PPartEntry;
+        elt.p_part.iter().enumerate().all(|(i, &r)| {
+            // If p^h overflows, then the bound is vacuous.
+            match p.checked_pow(self.profile.get_p_part(i)) {
+                Some(bound) => r < bound,
+                None => true,
+            }
+        })
+    }
+
+    /// The basis element of index `idx` in degree `degree`, or `None` if the basis in this degree
+    /// has not been computed or `idx` is out of range.
+    pub fn try_basis_element_from_index(
+        &self,
+        degree: i32,
+        idx: usize,
+    ) -> Option<&MilnorBasisElement> {
+        let table = self.basis_table.get(usize::try_from(degree).ok()?)?;
+        table.get(idx)
+    }
+
     pub fn basis_element_from_index(&self, degree: i32, idx: usize) -> &MilnorBasisElement {
-        &self.basis_table[degree as usize][idx]
+        if let Some(elt) = self.try_basis_element_from_index(degree, idx) {
+            return elt;
+        }
+        match usize::try_from(degree)
+            .ok()
+            .and_then(|d| self.basis_table.get(d))
+        {
+            Some(table) => panic!(
+                "Index {idx} out of range in degree {degree}, which has dimension {}",
+                table.len()
+            ),
+            None => panic!("Basis in degree {degree} has not been computed"),
+        }
     }
 
     pub fn try_basis_element_to_index(&self, elt: &MilnorBasisElement) -> Option<usize> {
@@ -349,14 +457,175 @@ impl MilnorAlgebra {
     }
 
     pub fn basis_element_to_index(&self, elt: &MilnorBasisElement) -> usize {
+        debug_assert_eq!(
+            elt.degree,
+            self.compute_degree(elt),
+            "Inconsistent degree for element: {elt:?}"
+        );
         self.try_basis_element_to_index(elt)
             .unwrap_or_else(|| panic!("Didn't find element: {elt:?}"))
     }
 
+    /// Print a basis element as the monomial in the dual Steenrod algebra it is dual to, e.g.
+    /// `Q_0 P(2)` is printed as `τ_0 ξ_1^2`.
+    pub fn basis_element_to_string_dual(&self, degree: i32, idx: usize) -> String {
+        let elt = self.basis_element_from_index(degree, idx);
+        if elt.degree == 0 {
+            return "1".to_string();
+        }
+        let taus = BitflagIterator::set_bit_iterator(elt.q_part as u64).map(|i| format!("τ_{i}"));
+        let xis = elt
+            .p_part
+            .iter()
+            .enumerate()
+            .filter(|&(_, &r)| r != 0)
+            .map(|(i, &r)| {
+                if r == 1 {
+                    format!("ξ_{}", i + 1)
+                } else {
+                    format!("ξ_{}^{r}", i + 1)
+                }
+            });
+        taus.chain(xis).join(" ")
+    }
+
+    /// Computes the degree of `elt` from its `p_part` and `q_part`, ignoring the `degree` field.
+    /// This can be used to validate externally constructed elements.
+    pub fn compute_degree(&self, elt: &MilnorBasisElement) -> i32 {
+        let xi_degrees = combinatorics::xi_degrees(self.prime());
+        let tau_degrees = combinatorics::tau_degrees(self.prime());
+
+        self.q()
+            * std::iter::zip(xi_degrees, &elt.p_part)
+                .map(|(&a, &b)| a * b as i32)
+                .sum::<i32>()
+            + BitflagIterator::set_bit_iterator(elt.q_part as u64)
+                .map(|k| tau_degrees[k])
+                .sum::<i32>()
+    }
+
     /// Gives a list of PPart's in degree `t`.
     pub fn ppart_table(&self, t: i32) -> &[PPart] {
         &self.ppart_table[t as usize]
     }
+
+    /// Cache the P parts computed by [`Algebra::compute_basis`] in a subdirectory of `dir`
+    /// determined by the prime and profile, and read them from there when they have been computed
+    /// by a previous run. Enumerating the P parts is the most expensive part of computing the
+    /// basis, so this speeds up repeated computations to large degrees. New P parts are only
+    /// written if [`Self::set_save_basis_cache`] has not disabled it.
+    pub fn set_basis_cache_dir(&mut self, dir: &Path) -> std::io::Result<()> {
+        let mut dir = dir.to_owned();
+        dir.push(self.basis_cache_name());
+        std::fs::create_dir_all(&dir)?;
+        self.basis_cache_dir = Some(dir);
+        Ok(())
+    }
+
+    /// Whether [`Algebra::compute_basis`] writes newly computed P parts to the basis cache set by
+    /// [`Self::set_basis_cache_dir`]. The cache is still read from if this is disabled. This is
+    /// enabled by default.
+    pub fn set_save_basis_cache(&mut self, save: bool) {
+        self.save_basis_cache = save;
+    }
+
+    fn basis_cache_name(&self) -> String {
+        let mut name = format!("milnor_{}", self.prime());
+        if !self.profile.is_trivial() {
+            name.push_str(&format!(
+                "_q{:x}_p{}",
+                self.profile.q_part,
+                self.profile.p_part.iter().join("-")
+            ));
+            if self.profile.truncated {
+                name.push_str("_truncated");
+            }
+        }
+        name
+    }
+
+    /// Read the P parts in degree `d` from the basis cache. This returns `None` if there is no
+    /// cache or if the cache file is missing, unreadable or corrupted, in which case the caller
+    /// recomputes the P parts. Since [`Self::compute_ppart`] relies on the order of the rows, a
+    /// row that is not in the order produced by [`Self::compute_ppart`] counts as corrupted.
+    fn load_ppart_row(&self, d: usize) -> Option<Vec<PPart>> {
+        let path = self.basis_cache_dir.as_ref()?.join(format!("ppart_{d}"));
+        let data = std::fs::read(path).ok()?;
+        if data.len() % 4 != 0 {
+            return None;
+        }
+        let mut words = data
+            .chunks_exact(4)
+            .map(|w| u32::from_le_bytes(w.try_into().unwrap()));
+
+        let xi_degrees = combinatorics::xi_degrees(self.prime());
+        let num_rows = words.next()?;
+        let mut row = Vec::new();
+        for _ in 0..num_rows {
+            let len = words.next()? as usize;
+            if len > xi_degrees.len() {
+                return None;
+            }
+            let ppart: PPart = (0..len).map(|_| words.next()).collect::<Option<_>>()?;
+            let degree: i64 = ppart
+                .iter()
+                .zip(xi_degrees)
+                .map(|(&e, &deg)| e as i64 * deg as i64)
+                .sum();
+            if degree != d as i64 {
+                return None;
+            }
+            row.push(ppart);
+        }
+        if words.next().is_some() {
+            return None;
+        }
+        if !row
+            .windows(2)
+            .all(|w| Self::ppart_order_key(&w[0]).lt(Self::ppart_order_key(&w[1])))
+        {
+            return None;
+        }
+        Some(row)
+    }
+
+    /// The P parts of a degree are produced by [`Self::compute_ppart`] in increasing
+    /// lexicographic order of this key. A P part of length $n$ is obtained by incrementing the
+    /// last entry of a P part of length at most $n$ in a lower degree, so the key lists the
+    /// lengths of the P parts we pass through when we undo this one step at a time.
+    fn ppart_order_key(ppart: &[PPartEntry]) -> impl Iterator<Item = usize> + '_ {
+        (0..ppart.len())
+            .rev()
+            .flat_map(move |i| (0..ppart[i]).map(move |_| i + 1))
+    }
+
+    /// Write the P parts in degree `d` to the basis cache, if there is one and saving to it is
+    /// enabled.
+    fn save_ppart_row(&self, d: usize, row: &[PPart]) -> std::io::Result<()> {
+        let dir = match &self.basis_cache_dir {
+            Some(dir) if self.save_basis_cache => dir,
+            _ => return Ok(()),
+        };
+        let mut data = Vec::new();
+        data.extend_from_slice(&(row.len() as u32).to_le_bytes());
+        for ppart in row {
+            data.extend_from_slice(&(ppart.len() as u32).to_le_bytes());
+            for &entry in ppart {
+                data.extend_from_slice(&entry.to_le_bytes());
+            }
+        }
+
+        // Write to a temporary file first so that an interrupted write does not leave a truncated
+        // cache file behind.
+        let path = dir.join(format!("ppart_{d}"));
+        let tmp_path = dir.join(format!("ppart_{d}.tmp"));
+        let result =
+            std::fs::write(&tmp_path, data).and_then(|()| std::fs::rename(&tmp_path, &path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&tmp_path);
+        }
+        result
+    }
 }
 
 impl Algebra for MilnorAlgebra {
@@ -486,6 +755,10 @@ impl Algebra for MilnorAlgebra {
         }
     }
 
+    fn computed_through(&self) -> i32 {
+        self.basis_element_to_index_map.len() as i32 - 1
+    }
+
     fn dimension(&self, degree: i32) -> usize {
         if degree < 0 {
             return 0;
@@ -493,6 +766,13 @@ impl Algebra for MilnorAlgebra {
         self.basis_table[degree as usize].len()
     }
 
+    fn basis(&self, degree: i32) -> std::ops::Range<usize> {
+        if degree < 0 {
+            return 0..0;
+        }
+        0..self.basis_table[degree as usize].len()
+    }
+
     #[cfg(not(feature = "cache-multiplication"))]
     fn multiply_basis_elements(
         &self,
@@ -582,6 +862,18 @@ impl Algebra for MilnorAlgebra {
         format!("{}", self.basis_element_from_index(degree, idx))
     }
 
+    fn basis_element_to_string_unicode(&self, degree: i32, idx: usize) -> String {
+        let elt = self.basis_element_from_index(degree, idx);
+        if elt.degree == 0 {
+            return "1".to_string();
+        }
+        let q_part = BitflagIterator::set_bit_iterator(elt.q_part as u64)
+            .map(|i| format!("Q{}", unicode_subscript(i as u32)));
+        let p_part =
+            (!elt.p_part.is_empty()).then(|| format!("P({})", elt.p_part.iter().format(", ")));
+        q_part.chain(p_part).join(" ")
+    }
+
     fn basis_element_from_string(&self, elt: &str) -> Option<(i32, usize)> {
         use crate::steenrod_parser::{brackets, digits, p_or_sq};
         use nom::{
@@ -596,9 +888,9 @@ impl Algebra for MilnorAlgebra {
         let p = self.prime();
 
         let mut parser = alt((
-            map(char('1'), |_| (0, 0)),
-            map(char('b'), |_| (1, 0)),
-            map(preceded(p_or_sq, digits), |i| self.beps_pn(0, i)),
+            map(char('1'), |_| Some((0, 0))),
+            map(char('b'), |_| Some((1, 0))),
+            map(preceded(p_or_sq, digits), |i| self.try_beps_pn(0, i)),
             map(
                 tuple((tag("P^"), digits, char('_'), digits)),
                 |(_, s, _, t)| {
@@ -611,7 +903,8 @@ impl Algebra for MilnorAlgebra {
                     };
                     elt.p_part[t - 1] = entry as PPartEntry;
                     self.compute_basis(degree);
-                    (degree, self.basis_element_to_index(&elt))
+                    self.try_basis_element_to_index(&elt)
+                        .map(|idx| (degree, idx))
                 },
             ),
             map(
@@ -632,13 +925,14 @@ impl Algebra for MilnorAlgebra {
                     elt.compute_degree(p);
                     self.compute_basis(elt.degree);
 
-                    (elt.degree, self.basis_element_to_index(&elt))
+                    self.try_basis_element_to_index(&elt)
+                        .map(|idx| (elt.degree, idx))
                 },
             ),
         ));
 
         if let Ok(("", res)) = parser(elt) {
-            Some(res)
+            res
         } else {
             None
         }
@@ -712,6 +1006,9 @@ impl GeneratedAlgebra for MilnorAlgebra {
         if degree == 0 {
             return vec![];
         } else if degree == 1 {
+            if self.generic() && self.profile.q_part & 1 == 0 {
+                return vec![];
+            }
             return vec![0]; // Q_0
         }
 
@@ -813,29 +1110,22 @@ impl GeneratedAlgebra for MilnorAlgebra {
                     (first_degree, first_index),
                     (second_degree, second_index),
                 ));
-                for e1 in 0..=b {
-                    let e2 = b - e1;
-                    // e1 and e2 determine where a bockstein shows up.
-                    // e1 determines whether a bockstein shows up in front
-                    // e2 determines whether a bockstein shows up in middle
-                    // So our output term looks like b^{e1} P^{x+y-j} b^{e2} P^{j}
-                    for j in 0..=x / *p {
-                        let c = combinatorics::adem_relation_coefficient(p, x, y, j, e1, e2);
-                        if c == 0 {
-                            continue;
-                        }
-                        if j == 0 {
-                            relation.push((
-                                c,
-                                self.try_beps_pn(e1, (x + y) as PPartEntry)?,
-                                (e2 as i32, 0),
-                            ));
-                            continue;
-                        }
-                        let first_sq = self.try_beps_pn(e1, (x + y - j) as PPartEntry)?;
-                        let second_sq = self.try_beps_pn(e2, j as PPartEntry)?;
-                        relation.push((c, first_sq, second_sq));
+                // e1 and e2 determine where a bockstein shows up.
+                // e1 determines whether a bockstein shows up in front
+                // e2 determines whether a bockstein shows up in middle
+                // So our output term looks like b^{e1} P^{x+y-j} b^{e2} P^{j}
+                for (j, e1, e2, c) in combinatorics::adem_relation_coefficients(p, x, y, b) {
+                    if j == 0 {
+                        relation.push((
+                            c,
+                            self.try_beps_pn(e1, (x + y) as PPartEntry)?,
+                            (e2 as i32, 0),
+                        ));
+                        continue;
                     }
+                    let first_sq = self.try_beps_pn(e1, (x + y - j) as PPartEntry)?;
+                    let second_sq = self.try_beps_pn(e2, j as PPartEntry)?;
+                    relation.push((c, first_sq, second_sq));
                 }
                 result.push(relation);
                 Some(())
@@ -868,12 +1158,13 @@ impl MilnorAlgebra {
         }
 
         self.ppart_table.extend(new_deg as usize, |d| {
+            if let Some(row) = self.load_ppart_row(d) {
+                return row;
+            }
+
             let d = d as i32;
             let mut new_row = vec![]; // Improve this
-            for i in 0..xi_degrees.len() {
-                if xi_degrees[i] > d {
-                    break;
-                }
+            for i in 0..combinatorics::max_xi_index(self.prime(), d) {
                 if profile_list[i] == 0 {
                     continue;
                 }
@@ -896,6 +1187,10 @@ impl MilnorAlgebra {
                     new_row.push(new);
                 }
             }
+            self.ppart_rows_computed.fetch_add(1, Ordering::Relaxed);
+            // Failing to write the cache only costs a recomputation in a later run, so this is not
+            // an error.
+            let _ = self.save_ppart_row(d as usize, &new_row);
             new_row
         });
     }
@@ -907,15 +1202,25 @@ impl MilnorAlgebra {
         self.basis_table.extend(max_degree as usize, |d| {
             let mut table = vec![];
             let residue = d as u32 % q;
+            // The number of τ_i's of degree at most d
+            let num_taus = if d == 0 {
+                0
+            } else {
+                let n = 1 + combinatorics::max_xi_index(self.prime(), (d as i32 - 1) / q as i32);
+                std::cmp::min(n, tau_degrees.len())
+            };
 
             for q_part in 0u32.. {
+                if q_part >> num_taus != 0 {
+                    break;
+                }
                 if q_part.count_ones() % q != residue {
                     continue;
                 }
 
                 let mut q_degree = 0;
                 let mut bs = q_part;
-                for &entry in tau_degrees {
+                for &entry in &tau_degrees[..num_taus] {
                     q_degree += entry * (bs & 1) as i32;
                     bs >>= 1;
                     if bs == 0 {
@@ -1073,6 +1378,23 @@ impl MilnorAlgebra {
         });
     }
 
+    /// Add `coef * m1 * m2` to `res` if the product has degree at most `max_total_degree`, and
+    /// do nothing otherwise. This is useful in batch routines that only need products up to a
+    /// fixed degree.
+    pub fn multiply_up_to_degree(
+        &self,
+        res: SliceMut,
+        coef: u32,
+        m1: &MilnorBasisElement,
+        m2: &MilnorBasisElement,
+        max_total_degree: i32,
+    ) {
+        if m1.degree + m2.degree > max_total_degree {
+            return;
+        }
+        self.multiply(res, coef, m1, m2);
+    }
+
     pub fn multiply_with_allocation(
         &self,
         mut res: SliceMut,
@@ -1083,6 +1405,11 @@ impl MilnorAlgebra {
         mut allocation: PPartAllocation,
     ) -> PPartAllocation {
         let target_deg = m1.degree + m2.degree;
+        assert_eq!(
+            res.as_slice().len(),
+            self.dimension_unstable(target_deg, excess),
+            "Result vector has the wrong length for the product of {m1} and {m2} in degree {target_deg}"
+        );
         if self.generic() {
             let m1f = self.multiply_qpart(m1, m2.q_part);
             for (cc, basis) in m1f {
@@ -1124,6 +1451,41 @@ impl MilnorAlgebra {
         allocation
     }
 
+    /// Add `coef * m1 * m2 * m3` to `res`. This is equivalent to two calls to [`Self::multiply`],
+    /// but reuses the same allocation for both multiplications.
+    pub fn multiply_three(
+        &self,
+        mut res: SliceMut,
+        coef: u32,
+        m1: &MilnorBasisElement,
+        m2: &MilnorBasisElement,
+        m3: &MilnorBasisElement,
+    ) {
+        let p = self.prime();
+        let mut intermediate = FpVector::new(p, self.dimension(m1.degree + m2.degree));
+        PPartAllocation::with_local(|mut allocation| {
+            allocation = self.multiply_with_allocation(
+                intermediate.as_slice_mut(),
+                1,
+                m1,
+                m2,
+                i32::MAX,
+                allocation,
+            );
+            for (i, c) in intermediate.iter_nonzero() {
+                allocation = self.multiply_with_allocation(
+                    res.copy(),
+                    (coef * c) % *p,
+                    self.basis_element_from_index(m1.degree + m2.degree, i),
+                    m3,
+                    i32::MAX,
+                    allocation,
+                );
+            }
+            allocation
+        });
+    }
+
     pub fn multiply_basis_by_element(
         &self,
         res: SliceMut,
@@ -1325,6 +1687,16 @@ impl<'a, const MOD4: bool> PPartMultiplier<'a, MOD4> {
         }
     }
 
+    /// Turn the multiplier into an ordinary iterator that yields each coefficient together with a
+    /// clone of the corresponding basis element. This allocates for every term, so performance
+    /// critical code should use [`Iterator::next`] and read off [`PPartMultiplier::ans`] instead.
+    pub fn iter_cloned(mut self) -> impl Iterator<Item = (u32, MilnorBasisElement)> + 'a {
+        std::iter::from_fn(move || {
+            let coef = self.next()?;
+            Some((coef, self.ans.clone()))
+        })
+    }
+
     /// This compute the first l > k such that (sum + l) choose l != 0 mod p, stopping if we reach
     /// max + 1. This is useful for incrementing the matrix.
     ///
@@ -1816,6 +2188,102 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_milnor_string_dual() {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(3), false);
+        algebra.compute_basis(40);
+        let check = |q_part: u32, p_part: Vec<PPartEntry>, milnor: &str, dual: &str| {
+            let mut elt = MilnorBasisElement {
+                q_part,
+                p_part,
+                degree: 0,
+            };
+            elt.degree = algebra.compute_degree(&elt);
+            let idx = algebra.basis_element_to_index(&elt);
+            assert_eq!(algebra.basis_element_to_string(elt.degree, idx), milnor);
+            assert_eq!(algebra.basis_element_to_string_dual(elt.degree, idx), dual);
+        };
+        check(0, vec![], "1", "1");
+        check(1, vec![], "Q_0", "τ_0");
+        check(1, vec![2], "Q_0 P(2)", "τ_0 ξ_1^2");
+        check(0, vec![0, 1], "P(0, 1)", "ξ_2");
+        check(0b101, vec![1, 1], "Q_0 Q_2 P(1, 1)", "τ_0 τ_2 ξ_1 ξ_2");
+    }
+
+    #[test]
+    fn test_milnor_string_unicode() {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(3), false);
+        algebra.compute_basis(40);
+        let check = |q_part: u32, p_part: Vec<PPartEntry>, expected: &str| {
+            let mut elt = MilnorBasisElement {
+                q_part,
+                p_part,
+                degree: 0,
+            };
+            elt.degree = algebra.compute_degree(&elt);
+            let idx = algebra.basis_element_to_index(&elt);
+            assert_eq!(
+                algebra.basis_element_to_string_unicode(elt.degree, idx),
+                expected
+            );
+        };
+        check(0, vec![], "1");
+        check(1, vec![], "Q₀");
+        check(0, vec![2, 1], "P(2, 1)");
+        check(0b101, vec![1], "Q₀ Q₂ P(1)");
+    }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 32)]
+    #[case(3, 106)]
+    fn test_milnor_string_dual_degree(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(max_degree);
+        for t in 0..max_degree {
+            for i in 0..algebra.dimension(t) {
+                let dual = algebra.basis_element_to_string_dual(t, i);
+                let mut elt = MilnorBasisElement {
+                    q_part: 0,
+                    p_part: vec![],
+                    degree: 0,
+                };
+                for factor in dual.split(' ').filter(|&x| x != "1") {
+                    if let Some(k) = factor.strip_prefix("τ_") {
+                        elt.q_part |= 1 << k.parse::<u32>().unwrap();
+                    } else {
+                        let factor = factor.strip_prefix("ξ_").unwrap();
+                        let (k, r) = factor.split_once('^').unwrap_or((factor, "1"));
+                        let k: usize = k.parse().unwrap();
+                        elt.p_part.resize(k, 0);
+                        elt.p_part[k - 1] = r.parse().unwrap();
+                    }
+                }
+                assert_eq!(algebra.compute_degree(&elt), t, "{dual}");
+                elt.degree = t;
+                assert_eq!(algebra.basis_element_to_index(&elt), i, "{dual}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_prime_basis() {
+        let p = ValidPrime::new(19);
+        let algebra = MilnorAlgebra::new(p, false);
+        let max_degree = 1500;
+        algebra.compute_basis(max_degree);
+        for t in 0..=max_degree {
+            for elt in algebra.basis_table[t as usize].iter() {
+                assert!(elt.p_part.len() <= combinatorics::max_xi_index(p, t / algebra.q()));
+                assert!(elt.q_part >> combinatorics::MAX_XI_TAU == 0);
+                assert_eq!(algebra.compute_degree(elt), t, "{elt}");
+            }
+        }
+        // Q_0 Q_1 P(21), Q_0 Q_1 P(1, 1), Q_0 Q_2 P(2) and Q_1 Q_2 P(1)
+        assert_eq!(algebra.dimension(1 + 37 + 36 * 21), 4);
+    }
+
     use crate::module::ModuleFailedRelationError;
     #[rstest(p, max_degree, case(2, 32), case(3, 106))]
     #[trace]
@@ -1869,6 +2337,22 @@ mod tests {
         }
     }
 
+    #[rstest(p, case(2), case(3))]
+    #[trace]
+    fn test_computed_through(p: u32) {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(p), false);
+        assert_eq!(algebra.computed_through(), -1);
+
+        algebra.compute_basis(20);
+        assert_eq!(algebra.computed_through(), 20);
+        let dim = algebra.dimension(20);
+
+        algebra.compute_basis(20);
+        algebra.compute_basis(10);
+        assert_eq!(algebra.computed_through(), 20);
+        assert_eq!(algebra.dimension(20), dim);
+    }
+
     #[test]
     fn test_clone_into() {
         let mut other = MilnorBasisElement::default();
@@ -1900,6 +2384,209 @@ mod tests {
         });
     }
 
+    #[rstest]
+    #[trace]
+    #[case(2, 32)]
+    #[case(3, 106)]
+    fn test_compute_degree(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(max_degree);
+        for t in 0..max_degree {
+            for i in 0..algebra.dimension(t) {
+                let elt = algebra.basis_element_from_index(t, i);
+                assert_eq!(algebra.compute_degree(elt), t, "Wrong degree for {elt}");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Inconsistent degree")]
+    fn test_inconsistent_degree() {
+        let algebra = MilnorAlgebra::new(fp::prime::TWO, false);
+        algebra.compute_basis(10);
+        algebra.basis_element_to_index(&MilnorBasisElement {
+            q_part: 0,
+            p_part: vec![1, 1],
+            degree: 5,
+        });
+    }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 8)]
+    #[case(3, 24)]
+    fn test_multiply_three(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(3 * max_degree);
+
+        for d1 in 0..=max_degree {
+            for d2 in 0..=max_degree {
+                for d3 in 0..=max_degree {
+                    let target_dim = algebra.dimension(d1 + d2 + d3);
+                    let mut fused = FpVector::new(p, target_dim);
+                    let mut intermediate = FpVector::new(p, algebra.dimension(d1 + d2));
+                    let mut sequential = FpVector::new(p, target_dim);
+                    let mut m3_vec = FpVector::new(p, algebra.dimension(d3));
+
+                    for i1 in 0..algebra.dimension(d1) {
+                        for i2 in 0..algebra.dimension(d2) {
+                            for i3 in 0..algebra.dimension(d3) {
+                                let m1 = algebra.basis_element_from_index(d1, i1);
+                                let m2 = algebra.basis_element_from_index(d2, i2);
+                                let m3 = algebra.basis_element_from_index(d3, i3);
+
+                                fused.set_to_zero();
+                                algebra.multiply_three(fused.as_slice_mut(), *p - 1, m1, m2, m3);
+
+                                intermediate.set_to_zero();
+                                algebra.multiply(intermediate.as_slice_mut(), 1, m1, m2);
+                                m3_vec.set_to_zero();
+                                m3_vec.set_entry(i3, 1);
+                                sequential.set_to_zero();
+                                algebra.multiply_element_by_element(
+                                    sequential.as_slice_mut(),
+                                    *p - 1,
+                                    d1 + d2,
+                                    intermediate.as_slice(),
+                                    d3,
+                                    m3_vec.as_slice(),
+                                );
+
+                                assert_eq!(fused, sequential, "{m1} * {m2} * {m3}");
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 40, None)]
+    #[case(2, 40, Some(MilnorProfile { q_part: !0, p_part: vec!(3, 2, 1), truncated: true }))]
+    #[case(3, 120, None)]
+    fn test_basis_cache(
+        #[case] p: u32,
+        #[case] max_degree: i32,
+        #[case] profile: Option<MilnorProfile>,
+    ) {
+        let p = ValidPrime::new(p);
+        let dir = tempfile::TempDir::new().unwrap();
+        let profile = profile.unwrap_or_default();
+        let new_algebra = || {
+            let mut algebra = MilnorAlgebra::new_with_profile(p, profile.clone(), false);
+            algebra.set_basis_cache_dir(dir.path()).unwrap();
+            algebra
+        };
+
+        let first = new_algebra();
+        first.compute_basis(max_degree);
+        let cache_dir = dir.path().join(first.basis_cache_name());
+        let q = first.q();
+        let num_rows = (max_degree / q) as usize + 1;
+        // The row in degree 0 is not cached
+        let files: Vec<(usize, Vec<u8>)> = (1..num_rows)
+            .map(|d| {
+                (
+                    d,
+                    std::fs::read(cache_dir.join(format!("ppart_{d}"))).unwrap(),
+                )
+            })
+            .collect();
+
+        let computed =
+            |algebra: &MilnorAlgebra| algebra.ppart_rows_computed.load(Ordering::Relaxed);
+        assert_eq!(computed(&first), num_rows - 1);
+
+        // Every row is read from the cache rather than recomputed
+        let second = new_algebra();
+        second.compute_basis(max_degree);
+        assert_eq!(computed(&second), 0);
+        for d in 0..=max_degree {
+            assert_eq!(
+                first.basis_table[d as usize],
+                second.basis_table[d as usize]
+            );
+        }
+
+        // Rows in the wrong order are rejected, since compute_ppart relies on the order
+        for d in 1..num_rows {
+            let mut row = first.ppart_table[d].clone();
+            row.reverse();
+            first.save_ppart_row(d, &row).unwrap();
+        }
+        let reversed = new_algebra();
+        reversed.compute_basis(max_degree);
+        // Rows with at most one P part look the same in either order
+        let reordered = (1..num_rows)
+            .filter(|&d| first.ppart_table[d].len() > 1)
+            .count();
+        assert_eq!(computed(&reversed), reordered);
+        for d in 0..num_rows {
+            assert_eq!(reversed.ppart_table[d], first.ppart_table[d], "degree {d}");
+        }
+        // ... and the cache files are repaired
+        for (d, data) in &files {
+            assert_eq!(
+                &std::fs::read(cache_dir.join(format!("ppart_{d}"))).unwrap(),
+                data,
+                "degree {d}"
+            );
+        }
+
+        // Corrupted cache files are ignored and recomputed
+        let path = cache_dir.join(format!("ppart_{}", max_degree / q));
+        let data = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &data[..data.len() - 2]).unwrap();
+        let fourth = new_algebra();
+        fourth.compute_basis(max_degree);
+        assert_eq!(computed(&fourth), 1);
+        for d in 0..=max_degree {
+            assert_eq!(
+                first.basis_table[d as usize],
+                fourth.basis_table[d as usize]
+            );
+        }
+        // ... and the cache file is repaired
+        assert_eq!(std::fs::read(&path).unwrap(), data);
+
+        // Nothing is written if saving is disabled
+        std::fs::remove_file(&path).unwrap();
+        let mut unsaved = new_algebra();
+        unsaved.set_save_basis_cache(false);
+        unsaved.compute_basis(max_degree);
+        assert_eq!(computed(&unsaved), 1);
+        assert!(!path.exists());
+
+        // Failing to write is reported by save_ppart_row, but compute_basis carries on
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+        let unwritable = new_algebra();
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+        let d = num_rows - 1;
+        assert!(unwritable.save_ppart_row(d, &first.ppart_table[d]).is_err());
+        unwritable.compute_basis(max_degree);
+        assert_eq!(computed(&unwritable), num_rows - 1);
+        assert_eq!(
+            first.basis_table[max_degree as usize],
+            unwritable.basis_table[max_degree as usize]
+        );
+
+        // A different prime or profile does not pick up the cache
+        let mut third = MilnorAlgebra::new(ValidPrime::new(5), false);
+        third.set_basis_cache_dir(dir.path()).unwrap();
+        assert_ne!(third.basis_cache_name(), first.basis_cache_name());
+        third.compute_basis(max_degree);
+        assert!(dir
+            .path()
+            .join(third.basis_cache_name())
+            .join("ppart_1")
+            .exists());
+    }
+
     #[test]
     fn test_ppart_multiplier_2() {
         let r = vec![1, 4];
@@ -1941,6 +2628,223 @@ mod tests {
         assert_eq!(m.next(), None);
     }
 
+    #[test]
+    fn test_element_in_profile() {
+        let elt = |p_part: &[PPartEntry]| MilnorBasisElement {
+            q_part: 0,
+            p_part: p_part.to_vec(),
+            degree: 0,
+        };
+
+        let a0 = MilnorAlgebra::new_with_profile(
+            fp::prime::TWO,
+            MilnorProfile {
+                truncated: true,
+                q_part: !0,
+                p_part: vec![1],
+            },
+            false,
+        );
+        assert!(a0.element_in_profile(&elt(&[])));
+        assert!(a0.element_in_profile(&elt(&[1])));
+        assert!(!a0.element_in_profile(&elt(&[2])));
+        assert!(!a0.element_in_profile(&elt(&[0, 1])));
+
+        let a1 = MilnorAlgebra::new_with_profile(
+            fp::prime::TWO,
+            MilnorProfile {
+                truncated: true,
+                q_part: !0,
+                p_part: vec![2, 1],
+            },
+            false,
+        );
+        assert!(a1.element_in_profile(&elt(&[1])));
+        assert!(a1.element_in_profile(&elt(&[3, 1])));
+        assert!(!a1.element_in_profile(&elt(&[4])));
+        assert!(!a1.element_in_profile(&elt(&[0, 2])));
+        assert!(!a1.element_in_profile(&elt(&[0, 0, 1])));
+
+        // Compare with the basis of the sub-Hopf algebra
+        let max_degree = 20;
+        let algebra = MilnorAlgebra::new(fp::prime::TWO, false);
+        algebra.compute_basis(max_degree);
+        a1.compute_basis(max_degree);
+        for d in 0..=max_degree {
+            for i in 0..algebra.dimension(d) {
+                let elt = algebra.basis_element_from_index(d, i);
+                assert_eq!(
+                    a1.element_in_profile(elt),
+                    a1.try_basis_element_to_index(elt).is_some(),
+                    "{elt}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_basis_element_from_index() {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(2), false);
+        algebra.compute_basis(10);
+
+        // P(4) and P(1, 1) span degree 4
+        assert_eq!(algebra.dimension(4), 2);
+        let elt = algebra.try_basis_element_from_index(4, 1).unwrap();
+        assert_eq!(elt, algebra.basis_element_from_index(4, 1));
+        assert_eq!(algebra.try_basis_element_from_index(4, 2), None);
+        assert_eq!(algebra.try_basis_element_from_index(-1, 0), None);
+        assert_eq!(algebra.try_basis_element_from_index(11, 0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index 2 out of range in degree 4, which has dimension 2")]
+    fn test_basis_element_from_index_out_of_range() {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(2), false);
+        algebra.compute_basis(10);
+        algebra.basis_element_from_index(4, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Basis in degree 11 has not been computed")]
+    fn test_basis_element_from_index_not_computed() {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(2), false);
+        algebra.compute_basis(10);
+        algebra.basis_element_from_index(11, 0);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Result vector has the wrong length for the product of P(1) and P(2) in degree 3"
+    )]
+    fn test_multiply_wrong_length() {
+        let p = ValidPrime::new(2);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(3);
+        let m1 = algebra.basis_element_from_index(1, 0);
+        let m2 = algebra.basis_element_from_index(2, 0);
+        let mut result = FpVector::new(p, algebra.dimension(3) + 1);
+        algebra.multiply(result.as_slice_mut(), 1, m1, m2);
+    }
+
+    #[test]
+    fn test_display() {
+        let algebra = MilnorAlgebra::new(ValidPrime::new(2), false);
+        assert_eq!(algebra.to_string(), "MilnorAlgebra(p=2)");
+
+        let a1 = MilnorProfile {
+            truncated: true,
+            q_part: 0b111,
+            p_part: vec![2, 1],
+        };
+        let algebra = MilnorAlgebra::new_with_profile(ValidPrime::new(3), a1, false);
+        assert_eq!(algebra.to_string(), "MilnorAlgebra(p=3, profile=A(1))");
+
+        let profile = MilnorProfile {
+            truncated: true,
+            q_part: !0,
+            p_part: vec![2, 2, 1],
+        };
+        let algebra = MilnorAlgebra::new_with_profile(ValidPrime::new(2), profile, false);
+        assert_eq!(
+            algebra.to_string(),
+            "MilnorAlgebra(p=2, profile=[2, 2, 1] truncated)"
+        );
+
+        let algebra = MilnorAlgebra::new_restricted(ValidPrime::new(3), false);
+        assert_eq!(
+            algebra.to_string(),
+            "MilnorAlgebra(p=3, profile=[], q_part=0b0)"
+        );
+    }
+
+    #[test]
+    fn test_new_restricted() {
+        let p = ValidPrime::new(3);
+        let max_degree = 60;
+        let algebra = MilnorAlgebra::new(p, false);
+        let restricted = MilnorAlgebra::new_restricted(p, false);
+        algebra.compute_basis(max_degree);
+        restricted.compute_basis(max_degree);
+
+        assert!(restricted.generators(1).is_empty());
+        for d in 0..=max_degree {
+            for i in 0..restricted.dimension(d) {
+                assert_eq!(restricted.basis_element_from_index(d, i).q_part, 0);
+            }
+            let even_dim = (0..algebra.dimension(d))
+                .filter(|&i| algebra.basis_element_from_index(d, i).q_part == 0)
+                .count();
+            assert_eq!(restricted.dimension(d), even_dim);
+        }
+
+        for d1 in 0..=max_degree {
+            for d2 in 0..=max_degree - d1 {
+                for i1 in 0..restricted.dimension(d1) {
+                    for i2 in 0..restricted.dimension(d2) {
+                        let mut result = FpVector::new(p, restricted.dimension(d1 + d2));
+                        restricted.multiply_basis_elements(
+                            result.as_slice_mut(),
+                            1,
+                            d1,
+                            i1,
+                            d2,
+                            i2,
+                        );
+
+                        let index = |d, i| {
+                            algebra
+                                .basis_element_to_index(restricted.basis_element_from_index(d, i))
+                        };
+                        let mut full_result = FpVector::new(p, algebra.dimension(d1 + d2));
+                        algebra.multiply_basis_elements(
+                            full_result.as_slice_mut(),
+                            1,
+                            d1,
+                            index(d1, i1),
+                            d2,
+                            index(d2, i2),
+                        );
+
+                        let mut expected = FpVector::new(p, restricted.dimension(d1 + d2));
+                        for (j, c) in full_result.iter_nonzero() {
+                            let elt = algebra.basis_element_from_index(d1 + d2, j);
+                            assert_eq!(elt.q_part, 0);
+                            expected.set_entry(restricted.basis_element_to_index(elt), c);
+                        }
+                        assert_eq!(result, expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ppart_multiplier_iter_cloned() {
+        let p = ValidPrime::new(3);
+        let r = vec![3, 4];
+        let s = vec![1, 4];
+        let new_multiplier = || {
+            PPartMultiplier::<false>::new_from_allocation(
+                p,
+                &r,
+                &s,
+                PPartAllocation::default(),
+                0,
+                0,
+            )
+        };
+
+        let mut m = new_multiplier();
+        let mut expected = Vec::new();
+        while let Some(c) = m.next() {
+            expected.push((c, m.ans.clone()));
+        }
+        assert!(!expected.is_empty());
+
+        let result: Vec<_> = new_multiplier().iter_cloned().collect();
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_ppart_multiplier_3() {
         let r = vec![3, 4];
@@ -2012,6 +2916,277 @@ mod tests {
         })
         .is_valid());
     }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 30)]
+    #[case(3, 60)]
+    #[case(5, 80)]
+    fn test_antipode(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(max_degree);
+
+        let chi = |degree: i32, idx: usize| -> FpVector {
+            let mut result = FpVector::new(p, algebra.dimension(degree));
+            Bialgebra::antipode(&algebra, result.as_slice_mut(), 1, degree, idx);
+            result
+        };
+
+        for degree in 1..=max_degree {
+            let dim = algebra.dimension(degree);
+            for idx in 0..dim {
+                let elt = algebra.basis_element_from_index(degree, idx);
+
+                // The defining relation \sum x' \chi(x'') = 0
+                let mut sum = FpVector::new(p, dim);
+                for (coef, left, right) in algebra.signed_coproduct(elt) {
+                    let right_idx = algebra.basis_element_to_index(&right);
+                    algebra.multiply_basis_by_element(
+                        sum.as_slice_mut(),
+                        coef,
+                        &left,
+                        right.degree,
+                        chi(right.degree, right_idx).as_slice(),
+                    );
+                }
+                assert!(sum.is_zero(), "Antipode relation fails for {elt}");
+
+                // \chi^2 = id
+                let mut chi2 = FpVector::new(p, dim);
+                for (i, c) in chi(degree, idx).iter_nonzero() {
+                    chi2.add(&chi(degree, i), c);
+                }
+                let mut expected = FpVector::new(p, dim);
+                expected.set_entry(idx, 1);
+                assert_eq!(chi2, expected, "\\chi^2 is not the identity on {elt}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiply_up_to_degree() {
+        let p = ValidPrime::new(3);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(30);
+
+        for d1 in 0..=15 {
+            for d2 in 0..=15 {
+                let target_dim = algebra.dimension(d1 + d2);
+                for i1 in 0..algebra.dimension(d1) {
+                    for i2 in 0..algebra.dimension(d2) {
+                        let m1 = algebra.basis_element_from_index(d1, i1);
+                        let m2 = algebra.basis_element_from_index(d2, i2);
+
+                        let mut expected = FpVector::new(p, target_dim);
+                        algebra.multiply(expected.as_slice_mut(), 2, m1, m2);
+
+                        let mut result = FpVector::new(p, target_dim);
+                        algebra.multiply_up_to_degree(result.as_slice_mut(), 2, m1, m2, 20);
+                        if d1 + d2 <= 20 {
+                            assert_eq!(result, expected, "Wrong product of {m1} and {m2}");
+                        } else {
+                            assert!(result.is_zero());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 20)]
+    #[case(3, 60)]
+    #[case(5, 80)]
+    fn test_coproduct(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(max_degree);
+
+        for degree in 0..=max_degree {
+            for idx in 0..algebra.dimension(degree) {
+                let elt = algebra.basis_element_from_index(degree, idx);
+                if algebra.decompose(degree, idx).len() != 1 {
+                    continue;
+                }
+                let mut coproduct: Vec<_> = algebra
+                    .coproduct(degree, idx)
+                    .into_iter()
+                    .map(|(l_deg, l_idx, r_deg, r_idx)| {
+                        (
+                            algebra.basis_element_from_index(l_deg, l_idx).clone(),
+                            algebra.basis_element_from_index(r_deg, r_idx).clone(),
+                        )
+                    })
+                    .collect();
+                coproduct.sort_by_key(|(l, r)| (l.degree, l.to_string(), r.to_string()));
+
+                let unit = MilnorBasisElement {
+                    q_part: 0,
+                    p_part: vec![],
+                    degree: 0,
+                };
+                let mut expected = if degree == 0 {
+                    vec![(unit.clone(), unit)]
+                } else if elt.q_part != 0 {
+                    // Q_i is primitive
+                    assert!(elt.p_part.is_empty(), "{elt} is not a factor");
+                    vec![(unit.clone(), elt.clone()), (elt.clone(), unit)]
+                } else {
+                    // \Delta P(R) = \sum_{R' + R'' = R} P(R') \otimes P(R'')
+                    elt.p_part
+                        .iter()
+                        .map(|&r| 0..=r)
+                        .multi_cartesian_product()
+                        .map(|left| {
+                            let right = std::iter::zip(&elt.p_part, &left).map(|(r, l)| r - l);
+                            let make = |p_part: Vec<u32>| {
+                                let mut b = MilnorBasisElement {
+                                    q_part: 0,
+                                    p_part,
+                                    degree: 0,
+                                };
+                                while b.p_part.last() == Some(&0) {
+                                    b.p_part.pop();
+                                }
+                                b.degree = algebra.compute_degree(&b);
+                                b
+                            };
+                            (make(left.clone()), make(right.collect()))
+                        })
+                        .collect()
+                };
+                expected.sort_by_key(|(l, r)| (l.degree, l.to_string(), r.to_string()));
+                assert_eq!(coproduct, expected, "Wrong coproduct of {elt}");
+            }
+        }
+    }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 20)]
+    #[case(3, 60)]
+    fn test_decompose(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(max_degree);
+
+        for degree in 0..=max_degree {
+            for idx in 0..algebra.dimension(degree) {
+                let elt = algebra.basis_element_from_index(degree, idx);
+                let factors = algebra.decompose(degree, idx);
+
+                // The first factor acts first, so it is the rightmost one in the product.
+                let (mut product_deg, first) = factors[0];
+                let mut product = FpVector::new(p, algebra.dimension(product_deg));
+                product.set_entry(first, 1);
+                for &(d, i) in &factors[1..] {
+                    let mut next = FpVector::new(p, algebra.dimension(product_deg + d));
+                    algebra.multiply_basis_element_by_element(
+                        next.as_slice_mut(),
+                        1,
+                        d,
+                        i,
+                        product_deg,
+                        product.as_slice(),
+                    );
+                    product = next;
+                    product_deg += d;
+                }
+                let mut expected = FpVector::new(p, algebra.dimension(degree));
+                expected.set_entry(idx, 1);
+                assert_eq!(product, expected, "Wrong decomposition of {elt}");
+
+                // The factors have unsigned coproducts, which is what Bialgebra::coproduct returns
+                for (d, i) in factors {
+                    let factor = algebra.basis_element_from_index(d, i);
+                    for (coef, _, _) in algebra.signed_coproduct(factor) {
+                        assert_eq!(coef, 1, "Signed coproduct of factor {factor} of {elt}");
+                    }
+                }
+            }
+        }
+    }
+
+    /// A Milnor algebra that uses the default implementation of [`Bialgebra::antipode`].
+    struct DefaultAntipode(MilnorAlgebra);
+
+    impl std::fmt::Display for DefaultAntipode {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    macro_rules! dispatch_default_antipode {
+        () => {};
+        ($vis:vis fn $method:ident$(<$($lt:lifetime),+>)?(&$($lt2:lifetime)?self$(, $arg:ident: $ty:ty )*$(,)?) $(-> $ret:ty)?; $($tail:tt)*) => {
+            $vis fn $method$(<$($lt),+>)?(&$($lt2)?self, $($arg: $ty),* ) $(-> $ret)* {
+                self.0.$method($($arg),*)
+            }
+            dispatch_default_antipode!{$($tail)*}
+        };
+    }
+
+    crate::dispatch_algebra!(DefaultAntipode, dispatch_default_antipode);
+
+    impl Bialgebra for DefaultAntipode {
+        dispatch_default_antipode! {
+            fn coproduct(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize, i32, usize)>;
+            fn decompose(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize)>;
+        }
+    }
+
+    #[rstest]
+    #[trace]
+    #[case(2, 12)]
+    #[case(3, 25)]
+    fn test_default_antipode(#[case] p: u32, #[case] max_degree: i32) {
+        let p = ValidPrime::new(p);
+        let algebra = DefaultAntipode(MilnorAlgebra::new(p, false));
+        algebra.compute_basis(max_degree);
+
+        for degree in 0..=max_degree {
+            for idx in 0..algebra.dimension(degree) {
+                let mut expected = FpVector::new(p, algebra.dimension(degree));
+                Bialgebra::antipode(&algebra.0, expected.as_slice_mut(), 1, degree, idx);
+                let mut result = FpVector::new(p, algebra.dimension(degree));
+                algebra.antipode(result.as_slice_mut(), 1, degree, idx);
+                assert_eq!(
+                    result,
+                    expected,
+                    "Wrong antipode of {}",
+                    algebra.basis_element_to_string(degree, idx)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_antipode_sq() {
+        // The antipode of Sq^n is the sum of all Milnor basis elements in degree n.
+        let p = ValidPrime::new(2);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(20);
+
+        for n in 1..=20 {
+            let dim = algebra.dimension(n);
+            let mut chi = FpVector::new(p, dim);
+            algebra.antipode(&mut chi, 1, algebra.basis_element_from_index(n, 0));
+            assert_eq!(chi.to_vec(), vec![1; dim], "Wrong antipode of Sq{n}");
+        }
+
+        // At odd primes, \chi(Q_0) = -Q_0 and \chi(P^1) = -P^1.
+        let p = ValidPrime::new(3);
+        let algebra = MilnorAlgebra::new(p, false);
+        algebra.compute_basis(4);
+        let mut chi = FpVector::new(p, 1);
+        algebra.antipode(&mut chi, 1, algebra.basis_element_from_index(1, 0));
+        assert_eq!(chi.to_vec(), [2]);
+        let mut chi = FpVector::new(p, 1);
+        algebra.antipode(&mut chi, 1, algebra.basis_element_from_index(4, 0));
+        assert_eq!(chi.to_vec(), [2]);
+    }
 }
 
 impl MilnorAlgebra {
@@ -2026,66 +3201,182 @@ impl MilnorAlgebra {
         }
         element.last().unwrap() > max.last().unwrap()
     }
-}
-
-impl Bialgebra for MilnorAlgebra {
-    fn coproduct(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize, i32, usize)> {
-        assert_eq!(*self.prime(), 2, "Coproduct at odd primes not supported");
-        if op_deg == 0 {
-            return vec![(0, 0, 0, 0)];
-        }
-        let xi_degrees = combinatorics::xi_degrees(self.prime());
-
-        let mut len = 1;
-        let p_part = &self.basis_element_from_index(op_deg, op_idx).p_part;
 
-        for i in p_part.iter() {
-            len *= i + 1;
-        }
-        let len = len as usize;
-        let mut result = Vec::with_capacity(len);
+    /// The coproduct of a basis element at an arbitrary prime, as a list of terms
+    /// `(coef, left, right)`. The $Q_i$ are primitive and
+    /// $\Delta P(R) = \sum_{R' + R'' = R} P(R') \otimes P(R'')$. The sign of a term is the sign
+    /// picked up when moving the $Q_i$ of the right factor past those of the left factor.
+    fn signed_coproduct(
+        &self,
+        elt: &MilnorBasisElement,
+    ) -> Vec<(u32, MilnorBasisElement, MilnorBasisElement)> {
+        let p = *self.prime();
+        let p_part = &elt.p_part;
 
+        let mut p_splits = Vec::new();
         let mut cur_ppart: PPart = vec![0; p_part.len()];
         loop {
-            let mut left_degree: i32 = 0;
-            for i in 0..cur_ppart.len() {
-                left_degree += cur_ppart[i] as i32 * xi_degrees[i];
-            }
-            let right_degree: i32 = op_deg - left_degree;
-
+            let mut right_ppart: PPart = std::iter::zip(p_part, &cur_ppart)
+                .map(|(r, l)| r - l)
+                .collect();
             let mut left_ppart = cur_ppart.clone();
             while let Some(0) = left_ppart.last() {
                 left_ppart.pop();
             }
-
-            let mut right_ppart = cur_ppart
-                .iter()
-                .enumerate()
-                .map(|(i, v)| p_part[i] - *v)
-                .collect::<Vec<_>>();
             while let Some(0) = right_ppart.last() {
                 right_ppart.pop();
             }
+            p_splits.push((left_ppart, right_ppart));
+            if p_part.is_empty() || Self::increment_p_part(&mut cur_ppart, p_part) {
+                break;
+            }
+        }
 
-            let left_idx = self.basis_element_to_index(&MilnorBasisElement {
-                degree: left_degree,
-                q_part: 0,
-                p_part: left_ppart,
-            });
-            let right_idx = self.basis_element_to_index(&MilnorBasisElement {
-                degree: right_degree,
-                q_part: 0,
-                p_part: right_ppart,
-            });
+        let mut result = Vec::with_capacity(p_splits.len() << elt.q_part.count_ones());
+        // Iterate over all submasks of elt.q_part
+        let mut left_q = elt.q_part;
+        loop {
+            let right_q = elt.q_part & !left_q;
+            let num_swaps: u32 = BitflagIterator::set_bit_iterator(left_q as u64)
+                .map(|k| (right_q & ((1 << k) - 1)).count_ones())
+                .sum();
+            let coef = if num_swaps & 1 == 0 { 1 } else { p - 1 };
+
+            for (left_ppart, right_ppart) in &p_splits {
+                let mut left = MilnorBasisElement {
+                    q_part: left_q,
+                    p_part: left_ppart.clone(),
+                    degree: 0,
+                };
+                let mut right = MilnorBasisElement {
+                    q_part: right_q,
+                    p_part: right_ppart.clone(),
+                    degree: 0,
+                };
+                left.degree = self.compute_degree(&left);
+                right.degree = self.compute_degree(&right);
+                result.push((coef, left, right));
+            }
 
-            result.push((left_degree, left_idx, right_degree, right_idx));
-            if Self::increment_p_part(&mut cur_ppart, p_part) {
+            if left_q == 0 {
                 break;
             }
+            left_q = (left_q - 1) & elt.q_part;
         }
         result
     }
+
+    /// Compute the antipodes (also known as the conjugations) of all basis elements up to degree
+    /// `max_degree`. The antipode is computed recursively from the coproduct via the relation
+    /// $\sum x' \chi(x'') = 0$ for $|x| > 0$, where $\Delta x = \sum x' \otimes x''$. The basis
+    /// must have been computed up to `max_degree`.
+    pub fn compute_antipodes(&self, max_degree: i32) {
+        let p = self.prime();
+        self.antipode_table.extend(max_degree as usize, |degree| {
+            let degree = degree as i32;
+            let dim = self.dimension(degree);
+            if degree == 0 {
+                let mut unit = FpVector::new(p, dim);
+                unit.set_entry(0, 1);
+                return vec![unit];
+            }
+            self.basis_table[degree as usize]
+                .iter()
+                .map(|elt| {
+                    let mut result = FpVector::new(p, dim);
+                    for (coef, left, right) in self.signed_coproduct(elt) {
+                        if left.degree == 0 {
+                            continue;
+                        }
+                        let right_idx = self.basis_element_to_index(&right);
+                        self.multiply_basis_by_element(
+                            result.as_slice_mut(),
+                            *p - coef,
+                            &left,
+                            right.degree,
+                            self.antipode_table[right.degree as usize][right_idx].as_slice(),
+                        );
+                    }
+                    result
+                })
+                .collect()
+        });
+    }
+
+    /// Add `coef` times the antipode $\chi(\mathrm{elt})$ to `res`. This is
+    /// [`Bialgebra::antipode`] for a basis element that is given by its [`MilnorBasisElement`]
+    /// instead of its index.
+    pub fn antipode(&self, res: &mut FpVector, coef: u32, elt: &MilnorBasisElement) {
+        let idx = self.basis_element_to_index(elt);
+        Bialgebra::antipode(self, res.as_slice_mut(), coef, elt.degree, idx);
+    }
+}
+
+impl Bialgebra for MilnorAlgebra {
+    /// The antipodes are memoized, see [`MilnorAlgebra::compute_antipodes`].
+    fn antipode(&self, mut result: SliceMut, coeff: u32, op_deg: i32, op_idx: usize) {
+        self.compute_antipodes(op_deg);
+        result.add(
+            self.antipode_table[op_deg as usize][op_idx].as_slice(),
+            coeff,
+        );
+    }
+
+    /// The factors returned by [`Bialgebra::decompose`] have at most one $Q_i$, so the
+    /// coefficients of [`MilnorAlgebra::signed_coproduct`] are all 1.
+    fn coproduct(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize, i32, usize)> {
+        let elt = self.basis_element_from_index(op_deg, op_idx);
+        self.signed_coproduct(elt)
+            .into_iter()
+            .map(|(coef, left, right)| {
+                assert_eq!(
+                    coef, 1,
+                    "Coproduct of Milnor basis element that is not returned by decompose"
+                );
+                (
+                    left.degree,
+                    self.basis_element_to_index(&left),
+                    right.degree,
+                    self.basis_element_to_index(&right),
+                )
+            })
+            .collect()
+    }
+
+    /// At odd primes, $Q(E) P(R) = Q_{e_1} \cdots Q_{e_k} P(R)$ is decomposed into its $Q_i$ and
+    /// $P(R)$, starting with $P(R)$.
     fn decompose(&self, op_deg: i32, op_idx: usize) -> Vec<(i32, usize)> {
-        vec![(op_deg, op_idx)]
+        let elt = self.basis_element_from_index(op_deg, op_idx);
+        if elt.q_part.count_ones() + u32::from(!elt.p_part.is_empty()) <= 1 {
+            return vec![(op_deg, op_idx)];
+        }
+        let tau_degrees = combinatorics::tau_degrees(self.prime());
+        let mut result = Vec::with_capacity(elt.q_part.count_ones() as usize + 1);
+        if !elt.p_part.is_empty() {
+            let p_part = MilnorBasisElement {
+                q_part: 0,
+                p_part: elt.p_part.clone(),
+                degree: 0,
+            };
+            let degree = self.compute_degree(&p_part);
+            result.push((
+                degree,
+                self.basis_element_to_index(&MilnorBasisElement { degree, ..p_part }),
+            ));
+        }
+        let mut q_parts: Vec<u32> = BitflagIterator::set_bit_iterator(elt.q_part as u64)
+            .map(|i| i as u32)
+            .collect();
+        q_parts.reverse();
+        for i in q_parts {
+            let degree = tau_degrees[i as usize];
+            let q = MilnorBasisElement {
+                q_part: 1 << i,
+                p_part: vec![],
+                degree,
+            };
+            result.push((degree, self.basis_element_to_index(&q)));
+        }
+        result
     }
 }
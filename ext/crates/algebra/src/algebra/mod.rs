@@ -5,7 +5,7 @@ pub mod adem_algebra;
 pub use adem_algebra::{AdemAlgebra, AdemAlgebraT};
 
 mod algebra_trait;
-pub use algebra_trait::{Algebra, GeneratedAlgebra, MuAlgebra, UnstableAlgebra};
+pub use algebra_trait::{Algebra, GeneratedAlgebra, MuAlgebra, ProductInfo, UnstableAlgebra};
 
 mod bialgebra_trait;
 pub use bialgebra_trait::Bialgebra;
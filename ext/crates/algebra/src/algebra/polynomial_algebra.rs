@@ -264,6 +264,10 @@ impl<A: PolynomialAlgebra> Algebra for A {
         }
     }
 
+    fn computed_through(&self) -> i32 {
+        self.max_computed_degree()
+    }
+
     fn dimension(&self, degree: i32) -> usize {
         if degree < 0 {
             0
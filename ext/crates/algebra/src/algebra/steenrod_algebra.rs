@@ -142,6 +142,15 @@ impl Bialgebra for SteenrodAlgebra {
             SteenrodAlgebra::MilnorAlgebra(a) => a.coproduct(op_deg, op_idx),
         }
     }
+
+    fn antipode(&self, result: SliceMut, coeff: u32, op_deg: i32, op_idx: usize) {
+        match self {
+            SteenrodAlgebra::AdemAlgebra(a) => a.antipode(result, coeff, op_deg, op_idx),
+            SteenrodAlgebra::MilnorAlgebra(a) => {
+                Bialgebra::antipode(a, result, coeff, op_deg, op_idx)
+            }
+        }
+    }
 }
 
 #[cfg(feature = "json")]
@@ -170,13 +179,36 @@ impl SteenrodAlgebra {
             }
         }
 
+        if let Some(profile) = &spec.profile {
+            profile.validate().map_err(|e| {
+                anyhow!(
+                    "Profile with p_part {:?} is not a sub-Hopf algebra: {e}",
+                    profile.p_part
+                )
+            })?;
+        }
+
+        let profile = spec.profile.unwrap_or_default();
+        if algebra_type == AlgebraType::Adem && !profile.is_trivial() {
+            if unstable {
+                return Err(anyhow!(
+                    "The unstable Adem algebra does not support non-trivial profiles"
+                ));
+            }
+            if profile.p_part.is_empty() || !profile.is_an(*spec.p != 2) {
+                return Err(anyhow!(
+                    "The Adem algebra only supports the profiles of A(n). Use the Milnor algebra instead"
+                ));
+            }
+        }
+
         Ok(match algebra_type {
-            AlgebraType::Adem => Self::AdemAlgebra(AdemAlgebra::new(spec.p, unstable)),
-            AlgebraType::Milnor => Self::MilnorAlgebra(MilnorAlgebra::new_with_profile(
-                spec.p,
-                spec.profile.unwrap_or_default(),
-                unstable,
-            )),
+            AlgebraType::Adem => {
+                Self::AdemAlgebra(AdemAlgebra::new_with_profile(spec.p, profile, unstable))
+            }
+            AlgebraType::Milnor => {
+                Self::MilnorAlgebra(MilnorAlgebra::new_with_profile(spec.p, profile, unstable))
+            }
         })
     }
 }
@@ -303,3 +335,116 @@ impl crate::UnstableAlgebra for SteenrodAlgebra {
         fn multiply_element_by_element_unstable(&self, result: SliceMut, coeff: u32, r_degree: i32, r: Slice, s_degree: i32, s: Slice, excess: i32);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_adem_profile() {
+        let json = serde_json::json!({
+            "p": 2,
+            "profile": { "truncated": true, "p_part": [2, 1] }
+        });
+        assert!(matches!(
+            SteenrodAlgebra::from_json(&json, AlgebraType::Adem, false),
+            Ok(SteenrodAlgebra::AdemAlgebra(_))
+        ));
+        assert!(SteenrodAlgebra::from_json(&json, AlgebraType::Adem, true).is_err());
+        assert!(SteenrodAlgebra::from_json(&json, AlgebraType::Milnor, false).is_ok());
+
+        // E(1) is not of the form A(n)
+        let json = serde_json::json!({
+            "p": 2,
+            "profile": { "truncated": true, "p_part": [1, 1] }
+        });
+        assert!(SteenrodAlgebra::from_json(&json, AlgebraType::Adem, false).is_err());
+        assert!(SteenrodAlgebra::from_json(&json, AlgebraType::Milnor, false).is_ok());
+
+        let json = serde_json::json!({
+            "p": 2,
+            "algebra": ["milnor"],
+            "profile": { "truncated": true, "p_part": [2, 1] }
+        });
+        assert!(matches!(
+            SteenrodAlgebra::from_json(&json, AlgebraType::Adem, false),
+            Ok(SteenrodAlgebra::MilnorAlgebra(_))
+        ));
+
+        let json = serde_json::json!({ "p": 2 });
+        assert!(SteenrodAlgebra::from_json(&json, AlgebraType::Adem, false).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn test_milnor_profile_from_json() {
+        // A(1) has dimension 8
+        let json = serde_json::json!({
+            "p": 2,
+            "profile": { "truncated": true, "p_part": [2, 1] }
+        });
+        let algebra = SteenrodAlgebra::from_json(&json, AlgebraType::Milnor, false).unwrap();
+        algebra.compute_basis(10);
+        let dims: Vec<usize> = (0..=7).map(|d| algebra.dimension(d)).collect();
+        assert_eq!(dims, [1, 1, 1, 2, 1, 1, 1, 0]);
+        assert!(matches!(&algebra, SteenrodAlgebra::MilnorAlgebra(a) if a.profile().is_an(false)));
+
+        let json = serde_json::json!({
+            "p": 2,
+            "profile": { "truncated": true, "p_part": [3, 1] }
+        });
+        let Err(err) = SteenrodAlgebra::from_json(&json, AlgebraType::Milnor, false) else {
+            panic!("Invalid profile accepted");
+        };
+        let err = err.to_string();
+        assert!(
+            err.ends_with(
+                "is not a sub-Hopf algebra: p_part[0] = 3 > 2 but the profile is truncated after 2 entries"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_filtration_one_product_info() {
+        for algebra in [
+            SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(fp::prime::TWO, false)),
+            SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(fp::prime::TWO, false)),
+        ] {
+            let info = algebra.filtration_one_product_info();
+            let names: Vec<&str> = info.iter().map(|x| &*x.name).collect();
+            assert_eq!(names, ["h_0", "h_1", "h_2", "h_3"]);
+            let stems: Vec<i32> = info.iter().map(|x| x.stem).collect();
+            assert_eq!(stems, [0, 1, 3, 7]);
+
+            for (x, (name, op_degree, op_index)) in
+                std::iter::zip(&info, algebra.default_filtration_one_products())
+            {
+                assert_eq!(x.name, name);
+                assert_eq!((x.op_degree, x.op_index), (op_degree, op_index));
+            }
+        }
+    }
+
+    #[test]
+    fn test_basis() {
+        for algebra in [
+            SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(fp::prime::TWO, false)),
+            SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(fp::prime::TWO, false)),
+        ] {
+            algebra.compute_basis(20);
+            for degree in 0..=20 {
+                assert_eq!(algebra.basis(degree), 0..algebra.dimension(degree));
+                assert_eq!(
+                    algebra.iter_basis(degree).count(),
+                    algebra.dimension(degree)
+                );
+            }
+            // `Algebra` must remain usable as a trait object
+            let algebra: &dyn Algebra = &algebra;
+            assert_eq!(algebra.basis(-1), 0..0);
+            assert_eq!(algebra.basis(20), 0..algebra.dimension(20));
+        }
+    }
+}
@@ -57,6 +57,24 @@ pub(crate) fn module_gens_from_json(
 
 #[cfg(test)]
 pub mod test {
+    use std::sync::Arc;
+
+    use crate::module::FDModule;
+    use crate::AdemAlgebra;
+
+    /// The module C2, with cells `x0` and `x1` connected by Sq1.
+    pub fn c2_module(algebra: Arc<AdemAlgebra>) -> FDModule<AdemAlgebra> {
+        let mut c2 = FDModule::new(
+            algebra,
+            "C2".to_string(),
+            bivec::BiVec::from_vec(0, vec![1, 1]),
+        );
+        c2.set_basis_element_name(0, 0, "x0".to_string());
+        c2.set_basis_element_name(1, 0, "x1".to_string());
+        c2.set_action(1, 0, 0, 0, &[1]);
+        c2
+    }
+
     pub fn joker_json() -> serde_json::Value {
         use serde_json::json;
 
@@ -0,0 +1,106 @@
+use crate::algebra::Algebra;
+use crate::module::Module;
+use fp::vector::SliceMut;
+use std::sync::Arc;
+
+/// The augmentation ideal $I(A) = \ker(A \to \mathbb{F}_p)$ of an algebra, viewed as a left module
+/// over itself. This agrees with $A$ in positive degrees and vanishes in degree $0$.
+pub struct AugmentationIdealModule<A: Algebra> {
+    algebra: Arc<A>,
+}
+
+impl<A: Algebra> AugmentationIdealModule<A> {
+    pub fn new(algebra: Arc<A>) -> Self {
+        Self { algebra }
+    }
+}
+
+impl<A: Algebra> std::fmt::Display for AugmentationIdealModule<A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "I({})", self.algebra)
+    }
+}
+
+impl<A: Algebra> Module for AugmentationIdealModule<A> {
+    type Algebra = A;
+
+    fn algebra(&self) -> Arc<A> {
+        Arc::clone(&self.algebra)
+    }
+
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        f(&self.algebra)
+    }
+
+    fn min_degree(&self) -> i32 {
+        0
+    }
+
+    fn compute_basis(&self, degree: i32) {
+        self.algebra.compute_basis(degree);
+    }
+
+    fn max_computed_degree(&self) -> i32 {
+        self.algebra.computed_through()
+    }
+
+    fn dimension(&self, degree: i32) -> usize {
+        if degree <= 0 {
+            0
+        } else {
+            self.algebra.dimension(degree)
+        }
+    }
+
+    fn act_on_basis(
+        &self,
+        result: SliceMut,
+        coeff: u32,
+        op_degree: i32,
+        op_index: usize,
+        mod_degree: i32,
+        mod_index: usize,
+    ) {
+        assert!(mod_degree > 0);
+        self.algebra
+            .multiply_basis_elements(result, coeff, op_degree, op_index, mod_degree, mod_index);
+    }
+
+    fn basis_element_to_string(&self, degree: i32, idx: usize) -> String {
+        self.algebra.basis_element_to_string(degree, idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AdemAlgebra, MilnorAlgebra};
+
+    #[test]
+    fn test_augmentation_ideal_module() {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(MilnorAlgebra::new(p, false));
+        let module = AugmentationIdealModule::new(Arc::clone(&algebra));
+        module.compute_basis(30);
+        assert_eq!(module.max_computed_degree(), algebra.computed_through());
+        assert!(module.max_computed_degree() >= 30);
+
+        for degree in 0..=30 {
+            assert_eq!(
+                module.dimension(degree),
+                algebra.dimension(degree) - usize::from(degree == 0),
+                "degree {degree}"
+            );
+        }
+        module.check_associativity(8, 10).unwrap();
+
+        let algebra = Arc::new(AdemAlgebra::new(fp::prime::ValidPrime::new(3), false));
+        let module = AugmentationIdealModule::new(Arc::clone(&algebra));
+        module.compute_basis(40);
+        assert_eq!(module.dimension(0), 0);
+        for degree in 1..=40 {
+            assert_eq!(module.dimension(degree), algebra.dimension(degree));
+        }
+        module.check_associativity(10, 12).unwrap();
+    }
+}
@@ -1,6 +1,8 @@
-use crate::algebra::Algebra;
-use crate::module::{Module, ZeroModule};
+use crate::algebra::{Algebra, Bialgebra, GeneratedAlgebra};
+use crate::module::{Module, QuotientModule, ZeroModule};
 use bivec::BiVec;
+use fp::matrix::Subspace;
+use fp::prime::minus_one_to_the_n;
 use fp::vector::{FpVector, SliceMut};
 
 use std::fmt::Write as _;
@@ -8,8 +10,8 @@ use std::sync::Arc;
 
 #[cfg(feature = "json")]
 use {
-    crate::algebra::GeneratedAlgebra,
     crate::module::ModuleFailedRelationError,
+    crate::steenrod_evaluator::SteenrodEvaluator,
     anyhow::{anyhow, Context},
     serde::Deserialize,
     serde_json::{json, value::Value},
@@ -133,6 +135,10 @@ impl<A: Algebra> Module for FiniteDimensionalModule<A> {
         Arc::clone(&self.algebra)
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        f(&self.algebra)
+    }
+
     fn min_degree(&self) -> i32 {
         self.graded_dimension.min_degree()
     }
@@ -399,6 +405,168 @@ impl<A: Algebra> FiniteDimensionalModule<A> {
         let output_degree = input_degree + operation_degree;
         &mut self.actions[input_degree][output_degree][operation_idx][input_idx]
     }
+
+    /// The direct sum of `self` and `other`. In each degree, the basis consists of the basis of
+    /// `self` followed by the basis of `other`.
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        let min_degree = std::cmp::min(self.min_degree(), other.min_degree());
+        let max_degree = std::cmp::max(
+            self.graded_dimension.max_degree(),
+            other.graded_dimension.max_degree(),
+        );
+        let graded_dimension = BiVec::from_vec(
+            min_degree,
+            (min_degree..=max_degree)
+                .map(|t| self.dimension(t) + other.dimension(t))
+                .collect(),
+        );
+        let mut result = Self::new(
+            Arc::clone(&self.algebra),
+            format!("{} ⊕ {}", self.name, other.name),
+            graded_dimension,
+        );
+
+        for t in min_degree..=max_degree {
+            let offset = self.dimension(t);
+            for idx in 0..offset {
+                result.set_basis_element_name(t, idx, self.gen_names[t][idx].clone());
+            }
+            for idx in 0..other.dimension(t) {
+                result.set_basis_element_name(t, offset + idx, other.gen_names[t][idx].clone());
+            }
+        }
+
+        for input_degree in min_degree..=max_degree {
+            let input_offset = self.dimension(input_degree);
+            for output_degree in input_degree + 1..=max_degree {
+                let op_degree = output_degree - input_degree;
+                let output_offset = self.dimension(output_degree);
+                let output_dim = result.dimension(output_degree);
+                for op_idx in 0..self.algebra.dimension(op_degree) {
+                    if output_offset > 0 {
+                        for idx in 0..input_offset {
+                            let v = self.action(op_degree, op_idx, input_degree, idx);
+                            result
+                                .action_mut(op_degree, op_idx, input_degree, idx)
+                                .slice_mut(0, output_offset)
+                                .assign(v.as_slice());
+                        }
+                    }
+                    if output_dim > output_offset {
+                        for idx in 0..other.dimension(input_degree) {
+                            let v = other.action(op_degree, op_idx, input_degree, idx);
+                            result
+                                .action_mut(op_degree, op_idx, input_degree, input_offset + idx)
+                                .slice_mut(output_offset, output_dim)
+                                .assign(v.as_slice());
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// The quotient of the module by the submodule generated by the basis elements `sub`, which
+    /// are specified as `(degree, index)` pairs. The listed elements need not span a submodule
+    /// themselves; we quotient out by everything they generate under the algebra action.
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the pairs does not specify a basis element of the module.
+    pub fn quotient(&self, sub: &[(i32, usize)]) -> Self {
+        for &(t, idx) in sub {
+            assert!(
+                idx < self.dimension(t),
+                "({t}, {idx}) is not a basis element of {}",
+                self.name
+            );
+        }
+
+        let p = self.prime();
+        let min_degree = self.min_degree();
+        let max_degree = self.graded_dimension.max_degree();
+        let mut quotient = QuotientModule::new(Arc::new(self.clone()), max_degree);
+
+        // A vector in degree t is only hit by operations on lower degrees, so each subspace is
+        // complete by the time we reach it.
+        for t in min_degree..=max_degree {
+            quotient.quotient_basis_elements(
+                t,
+                sub.iter().filter(|&&(d, _)| d == t).map(|&(_, idx)| idx),
+            );
+
+            let mut images = Vec::new();
+            for input_degree in min_degree..t {
+                let op_degree = t - input_degree;
+                for input in quotient.subspaces[input_degree].iter() {
+                    for op_idx in 0..self.algebra.dimension(op_degree) {
+                        let mut image = FpVector::new(p, self.dimension(t));
+                        self.act(
+                            image.as_slice_mut(),
+                            1,
+                            op_degree,
+                            op_idx,
+                            input_degree,
+                            input,
+                        );
+                        images.push(image);
+                    }
+                }
+            }
+            for image in &images {
+                quotient.quotient(t, image.as_slice());
+            }
+        }
+
+        let mut result = Self::from(&quotient);
+        result.name = format!(
+            "{} / ({})",
+            self.name,
+            sub.iter()
+                .map(|&(t, idx)| self.basis_element_to_string(t, idx))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        result
+    }
+
+    /// The basis elements that are not hit by positive degree operations on lower classes, as
+    /// `(degree, index)` pairs. In each degree, these are the basis elements that are not pivots
+    /// of the image of the action, so they project to a basis of the indecomposables $M / A^+ M$
+    /// and form a minimal set of generators of the module.
+    pub fn minimal_generators(&self) -> Vec<(i32, usize)> {
+        let p = self.prime();
+        let min_degree = self.min_degree();
+        let max_degree = self.graded_dimension.max_degree();
+        self.algebra.compute_basis(max_degree - min_degree);
+
+        let mut result = Vec::new();
+        for t in min_degree..=max_degree {
+            let dim = self.dimension(t);
+            let mut image = Subspace::new(p, dim + 1, dim);
+            let mut inputs = (min_degree..t).flat_map(|input_degree| {
+                let op_degree = t - input_degree;
+                (0..self.algebra.dimension(op_degree)).flat_map(move |op_idx| {
+                    (0..self.dimension(input_degree))
+                        .map(move |input_idx| (op_degree, op_idx, input_degree, input_idx))
+                })
+            });
+            image.add_vectors(|row| {
+                let (op_degree, op_idx, input_degree, input_idx) = inputs.next()?;
+                self.act_on_basis(row, 1, op_degree, op_idx, input_degree, input_idx);
+                Some(())
+            });
+            result.extend(
+                image.pivots()[..dim]
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &pivot)| pivot < 0)
+                    .map(|(idx, _)| (t, idx)),
+            );
+        }
+        result
+    }
 }
 
 impl<M: Module> From<&M> for FiniteDimensionalModule<M::Algebra> {
@@ -450,6 +618,66 @@ impl<M: Module> From<&M> for FiniteDimensionalModule<M::Algebra> {
     }
 }
 
+impl<A: Bialgebra> FiniteDimensionalModule<A> {
+    /// The dual module $\mathrm{Hom}(M, \mathbb{F}_p)$, where the algebra acts via the antipode
+    /// $\chi$, i.e. $(a f)(x) = (-1)^{|a||f|} f(\chi(a) x)$. The dual of the basis element `x` in
+    /// degree $t$ is named `x*` and lives in degree $-t$.
+    pub fn dual(&self) -> Self {
+        let p = self.prime();
+        let min_degree = self.min_degree();
+        let max_degree = self.graded_dimension.max_degree();
+        let mut graded_dimension = BiVec::with_capacity(-max_degree, 1 - min_degree);
+        for t in (min_degree..=max_degree).rev() {
+            graded_dimension.push(self.dimension(t));
+        }
+
+        let mut result = Self::new(
+            Arc::clone(&self.algebra),
+            format!("{}*", self.name),
+            graded_dimension,
+        );
+        for t in min_degree..=max_degree {
+            for idx in 0..self.dimension(t) {
+                result.set_basis_element_name(-t, idx, format!("{}*", self.gen_names[t][idx]));
+            }
+        }
+
+        self.algebra.compute_basis(max_degree - min_degree);
+        let mut chi = FpVector::new(p, 0);
+        let mut scratch = FpVector::new(p, 0);
+        for input_degree in min_degree..=max_degree {
+            for output_degree in input_degree + 1..=max_degree {
+                let op_degree = output_degree - input_degree;
+                // The action of `op` on the dual of `x` in degree `-output_degree`. The coefficient
+                // of the dual of `y` is the coefficient of `x` in `chi(op) y`, up to sign.
+                let sign = minus_one_to_the_n(*p, op_degree * output_degree);
+                for op_idx in 0..self.algebra.dimension(op_degree) {
+                    chi.set_scratch_vector_size(self.algebra.dimension(op_degree));
+                    self.algebra
+                        .antipode(chi.as_slice_mut(), 1, op_degree, op_idx);
+                    for y in 0..self.dimension(input_degree) {
+                        scratch.set_scratch_vector_size(self.dimension(output_degree));
+                        self.act_by_element_on_basis(
+                            scratch.as_slice_mut(),
+                            1,
+                            op_degree,
+                            chi.as_slice(),
+                            input_degree,
+                            y,
+                        );
+                        for (x, c) in scratch.iter_nonzero() {
+                            result
+                                .action_mut(op_degree, op_idx, -output_degree, x)
+                                .set_entry(y, c * sign % *p);
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
 #[cfg(feature = "json")]
 impl<A: GeneratedAlgebra> FiniteDimensionalModule<A> {
     pub fn from_json(algebra: Arc<A>, json: &Value) -> anyhow::Result<Self> {
@@ -463,7 +691,18 @@ impl<A: GeneratedAlgebra> FiniteDimensionalModule<A> {
             }
         }
 
-        let actions = Vec::<String>::deserialize(&json["actions"]).unwrap();
+        // If there is no `actions` field, use the actions in the basis of our algebra, e.g.
+        // `milnor_actions` for the Milnor algebra.
+        let field = if json["actions"].is_null() {
+            format!("{}_actions", algebra.prefix())
+        } else {
+            "actions".to_string()
+        };
+        if json[&field].is_null() {
+            return Err(anyhow!("Module has neither an actions nor a {field} field"));
+        }
+        let actions = Vec::<String>::deserialize(&json[&field])
+            .with_context(|| format!("Invalid {field} field"))?;
         for action in actions {
             result
                 .parse_action(&gen_to_idx, &action, false)
@@ -475,9 +714,17 @@ impl<A: GeneratedAlgebra> FiniteDimensionalModule<A> {
                 result.check_validity(input_degree, output_degree)?;
             }
         }
+        if json.get("adem_actions").is_some() && json.get("milnor_actions").is_some() {
+            for other in ["adem_actions", "milnor_actions"] {
+                if other != field {
+                    check_actions(&result, json, other, &field, &gen_to_idx)?;
+                }
+            }
+        }
         Ok(result)
     }
 
+    /// Write the module into `json`, in the format accepted by [`Self::from_json`].
     pub fn to_json(&self, json: &mut Value) {
         if !self.name.is_empty() {
             json["name"] = Value::String(self.name.clone());
@@ -521,29 +768,7 @@ impl<A: GeneratedAlgebra> FiniteDimensionalModule<A> {
             row.set_to_zero();
         }
 
-        if rhs == "0" {
-            return Ok(());
-        }
-
-        for item in rhs.split(" + ") {
-            let (coef, gen) = match item.split_once(' ') {
-                Some((coef, gen)) => (
-                    str::parse(coef)
-                        .map_err(|_| anyhow!("Invalid item on right-hand side: {item}"))?,
-                    gen,
-                ),
-                None => (1, item),
-            };
-            let (deg, idx) = gen_to_idx(gen.trim())?;
-            if deg != input_deg + op_deg {
-                return Err(anyhow!(
-                    "Degree of {gen} is {deg} but degree of LHS is {}",
-                    input_deg + op_deg
-                ));
-            }
-            row.add_basis_element(idx, coef);
-        }
-        Ok(())
+        add_rhs(&gen_to_idx, rhs, input_deg + op_deg, row)
     }
 
     pub fn check_validity(
@@ -681,11 +906,188 @@ impl<A: GeneratedAlgebra> FiniteDimensionalModule<A> {
     }
 }
 
+/// Add the right-hand side `rhs` of an action, e.g. `x2 + 2 x3`, to `row`. The generators must
+/// have degree `degree`.
+#[cfg(feature = "json")]
+fn add_rhs(
+    gen_to_idx: impl for<'a> Fn(&'a str) -> anyhow::Result<(i32, usize)>,
+    rhs: &str,
+    degree: i32,
+    row: &mut FpVector,
+) -> anyhow::Result<()> {
+    if rhs == "0" {
+        return Ok(());
+    }
+
+    for item in rhs.split(" + ") {
+        let (coef, gen) = match item.split_once(' ') {
+            Some((coef, gen)) => (
+                str::parse(coef).map_err(|_| anyhow!("Invalid item on right-hand side: {item}"))?,
+                gen,
+            ),
+            None => (1, item),
+        };
+        let (deg, idx) = gen_to_idx(gen.trim())?;
+        if deg != degree {
+            return Err(anyhow!(
+                "Degree of {gen} is {deg} but degree of LHS is {degree}"
+            ));
+        }
+        row.add_basis_element(idx, coef);
+    }
+    Ok(())
+}
+
+/// Check that the actions in `field`, which is either `adem_actions` or `milnor_actions`, agree
+/// with those of `module`, which was parsed from `module_field`. The operations in `field` are
+/// converted to the basis of the algebra of `module`, so they must lie in its profile. Generators
+/// of the algebra that `field` does not mention must act by zero.
+#[cfg(feature = "json")]
+fn check_actions<A: GeneratedAlgebra>(
+    module: &FiniteDimensionalModule<A>,
+    json: &Value,
+    field: &str,
+    module_field: &str,
+    gen_to_idx: impl for<'a> Fn(&'a str) -> anyhow::Result<(i32, usize)>,
+) -> anyhow::Result<()> {
+    let algebra = module.algebra();
+    let p = algebra.prime();
+    let module_is_adem = match algebra.prefix() {
+        "adem" => true,
+        "milnor" => false,
+        _ => return Err(anyhow!("Cannot check {field} over {algebra}")),
+    };
+    let field_is_adem = field == "adem_actions";
+
+    let max_op_degree = module.max_degree().unwrap() - module.min_degree();
+    let ev = SteenrodEvaluator::new(p);
+    ev.adem.compute_basis(max_op_degree);
+    ev.milnor.compute_basis(max_op_degree);
+    algebra.compute_basis(max_op_degree);
+
+    // Convert an operation named in the basis of `field` to an element of `algebra`.
+    let to_algebra = |op: &str| -> anyhow::Result<(i32, FpVector)> {
+        let (degree, idx) = if field_is_adem {
+            ev.adem.basis_element_from_string(op)
+        } else {
+            ev.milnor.basis_element_from_string(op)
+        }
+        .ok_or_else(|| anyhow!("Invalid algebra element: {op}"))?;
+
+        let mut adem_op = FpVector::new(p, ev.adem.dimension(degree));
+        let mut milnor_op = FpVector::new(p, ev.milnor.dimension(degree));
+        if field_is_adem {
+            adem_op.set_entry(idx, 1);
+            if !module_is_adem {
+                ev.adem_to_milnor(&mut milnor_op, 1, degree, &adem_op);
+            }
+        } else {
+            milnor_op.set_entry(idx, 1);
+            if module_is_adem {
+                ev.milnor_to_adem(&mut adem_op, 1, degree, &milnor_op);
+            }
+        }
+
+        let mut result = FpVector::new(p, algebra.dimension(degree));
+        let terms = if module_is_adem { &adem_op } else { &milnor_op };
+        for (i, c) in terms.iter_nonzero() {
+            let name = if module_is_adem {
+                ev.adem.basis_element_to_string(degree, i)
+            } else {
+                ev.milnor.basis_element_to_string(degree, i)
+            };
+            let (_, idx) = algebra
+                .basis_element_from_string(&name)
+                .ok_or_else(|| anyhow!("{op} is not in {}", algebra))?;
+            result.add_basis_element(idx, c);
+        }
+        Ok((degree, result))
+    };
+
+    let disagree = |op_deg: i32,
+                    op: &FpVector,
+                    input_deg: i32,
+                    input_idx: usize,
+                    value: &FpVector,
+                    expected: &FpVector| {
+        let output_deg = op_deg + input_deg;
+        anyhow!(
+            "Adem and Milnor actions disagree: {op} {gen} is {value} in {module_field} but \
+             {expected} in {field}",
+            op = algebra.element_to_string(op_deg, op.as_slice()),
+            gen = module.basis_element_to_string(input_deg, input_idx),
+            value = module.element_to_string(output_deg, value.as_slice()),
+            expected = module.element_to_string(output_deg, expected.as_slice()),
+        )
+    };
+
+    let actions = Vec::<String>::deserialize(&json[field])
+        .with_context(|| format!("Invalid {field} field"))?;
+    let mut listed = Vec::new();
+    for entry in &actions {
+        let (lhs, rhs) = entry
+            .split_once(" = ")
+            .ok_or_else(|| anyhow!("Invalid action: {entry}"))?;
+        let (op, gen) = lhs
+            .rsplit_once(' ')
+            .ok_or_else(|| anyhow!("Invalid action: {entry}"))?;
+        let (op_deg, op) = to_algebra(op)?;
+        let (input_deg, input_idx) = gen_to_idx(gen.trim())?;
+
+        let output_deg = op_deg + input_deg;
+        let mut expected = FpVector::new(p, module.dimension(output_deg));
+        add_rhs(&gen_to_idx, rhs, output_deg, &mut expected)
+            .with_context(|| format!("Failed to parse action: {entry}"))?;
+        let mut value = FpVector::new(p, module.dimension(output_deg));
+        module.act_by_element_on_basis(
+            value.as_slice_mut(),
+            1,
+            op_deg,
+            op.as_slice(),
+            input_deg,
+            input_idx,
+        );
+        if value != expected {
+            return Err(disagree(
+                op_deg, &op, input_deg, input_idx, &value, &expected,
+            ));
+        }
+
+        let mut terms = op.iter_nonzero();
+        if let (Some((op_idx, 1)), None) = (terms.next(), terms.next()) {
+            listed.push((op_deg, op_idx, input_deg, input_idx));
+        }
+    }
+
+    for input_deg in module.min_degree()..=module.max_degree().unwrap() {
+        for output_deg in input_deg + 1..=module.max_degree().unwrap() {
+            let op_deg = output_deg - input_deg;
+            let zero = FpVector::new(p, module.dimension(output_deg));
+            for op_idx in algebra.generators(op_deg) {
+                for input_idx in 0..module.dimension(input_deg) {
+                    if listed.contains(&(op_deg, op_idx, input_deg, input_idx)) {
+                        continue;
+                    }
+                    let value = module.action(op_deg, op_idx, input_deg, input_idx);
+                    if !value.is_zero() {
+                        let mut op = FpVector::new(p, algebra.dimension(op_deg));
+                        op.set_entry(op_idx, 1);
+                        return Err(disagree(op_deg, &op, input_deg, input_idx, value, &zero));
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::algebra::AdemAlgebra;
+    use crate::algebra::{AdemAlgebra, AlgebraType, MilnorAlgebra, SteenrodAlgebra};
+    use crate::module::RealProjectiveSpace;
     use bivec::BiVec;
+    use rstest::rstest;
 
     #[test]
     fn test_module_check_validity() {
@@ -707,4 +1109,374 @@ mod tests {
         adem_module.set_action(2, 0, 0, 0, &[1]);
         adem_module.check_validity(0, 2).unwrap();
     }
+
+    #[test]
+    fn test_quotient() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let c2 = crate::test::c2_module(Arc::clone(&algebra));
+
+        // Quotienting out the top cell leaves the bottom cell
+        let bottom = c2.quotient(&[(1, 0)]);
+        assert_eq!(bottom.name, "C2 / (x1)");
+        assert_eq!(bottom.dimension(0), 1);
+        assert_eq!(bottom.dimension(1), 0);
+        assert_eq!(bottom.basis_element_to_string(0, 0), "x0");
+
+        // The bottom cell generates the whole module
+        let zero = c2.quotient(&[(0, 0)]);
+        assert_eq!(zero.total_dimension(), 0);
+    }
+
+    #[test]
+    fn test_direct_sum() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let c2 = crate::test::c2_module(Arc::clone(&algebra));
+
+        let mut ceta = FiniteDimensionalModule::new(
+            Arc::clone(&algebra),
+            "Ceta".to_string(),
+            BiVec::from_vec(1, vec![1, 0, 1]),
+        );
+        ceta.set_basis_element_name(1, 0, "y1".to_string());
+        ceta.set_basis_element_name(3, 0, "y3".to_string());
+        ceta.set_action(2, 0, 1, 0, &[1]);
+
+        let sum = c2.direct_sum(&ceta);
+        assert_eq!(sum.name, "C2 ⊕ Ceta");
+        assert_eq!(sum.min_degree(), 0);
+        let dims: Vec<usize> = (0..=4).map(|t| sum.dimension(t)).collect();
+        assert_eq!(dims, [1, 2, 0, 1, 0]);
+        assert_eq!(sum.basis_element_to_string(1, 0), "x1");
+        assert_eq!(sum.basis_element_to_string(1, 1), "y1");
+
+        assert_eq!(sum.action(1, 0, 0, 0), &FpVector::from_slice(p, &[1, 0]));
+        assert_eq!(sum.action(2, 0, 1, 0), &FpVector::from_slice(p, &[0]));
+        assert_eq!(sum.action(2, 0, 1, 1), &FpVector::from_slice(p, &[1]));
+        sum.check_associativity(3, 3).unwrap();
+    }
+
+    #[test]
+    fn test_minimal_generators() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+
+        // x1 is listed as a generator but is Sq1 x0, so only x0 and y1 are needed
+        let mut module = FiniteDimensionalModule::new(
+            Arc::clone(&algebra),
+            "M".to_string(),
+            BiVec::from_vec(0, vec![1, 2]),
+        );
+        module.set_basis_element_name(0, 0, "x0".to_string());
+        module.set_basis_element_name(1, 0, "x1".to_string());
+        module.set_basis_element_name(1, 1, "y1".to_string());
+        module.set_action(1, 0, 0, 0, &[1, 0]);
+        assert_eq!(module.minimal_generators(), [(0, 0), (1, 1)]);
+
+        // The joker is cyclic. We only set the action of the algebra generators, which suffices
+        // to determine the image.
+        let mut joker = FiniteDimensionalModule::new(
+            algebra,
+            "Joker".to_string(),
+            BiVec::from_vec(0, vec![1, 1, 1, 1, 1]),
+        );
+        joker.set_action(1, 0, 0, 0, &[1]);
+        joker.set_action(2, 0, 0, 0, &[1]);
+        joker.set_action(2, 0, 1, 0, &[1]);
+        joker.set_action(2, 0, 2, 0, &[1]);
+        joker.set_action(1, 0, 3, 0, &[1]);
+        assert_eq!(joker.minimal_generators(), [(0, 0)]);
+    }
+
+    #[test]
+    fn test_dual_c2() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let c2 = crate::test::c2_module(Arc::clone(&algebra));
+
+        let dual = c2.dual();
+        assert_eq!(dual.name, "C2*");
+        assert_eq!(dual.min_degree(), -1);
+        assert_eq!(dual.max_degree(), Some(0));
+        assert_eq!(dual.basis_element_to_string(-1, 0), "x1*");
+        assert_eq!(dual.action(1, 0, -1, 0).entry(0), 1);
+        dual.dual().test_equal(&c2).unwrap();
+    }
+
+    #[rstest]
+    #[case(AlgebraType::Adem)]
+    #[case(AlgebraType::Milnor)]
+    fn test_dual_rp(#[case] algebra_type: AlgebraType) {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(match algebra_type {
+            AlgebraType::Adem => SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(p, false)),
+            AlgebraType::Milnor => SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p, false)),
+        });
+        let rp = FiniteDimensionalModule::from(&RealProjectiveSpace::new(
+            Arc::clone(&algebra),
+            1,
+            Some(10),
+            false,
+        ));
+
+        let dual = rp.dual();
+        dual.check_associativity(9, 9).unwrap();
+        dual.dual().test_equal(&rp).unwrap();
+
+        // Sq3 x1 = 0, but chi(Sq3) x1 = Sq2 Sq1 x1 = x4.
+        let (_, sq3) = algebra.basis_element_from_string("Sq3").unwrap();
+        assert!(rp.action(3, sq3, 1, 0).is_zero());
+        assert_eq!(dual.action(3, sq3, -4, 0).entry(0), 1);
+    }
+
+    #[cfg(feature = "json")]
+    #[rstest]
+    #[case(AlgebraType::Adem)]
+    #[case(AlgebraType::Milnor)]
+    fn test_dual_odd_prime(#[case] algebra_type: AlgebraType) {
+        let p = fp::prime::ValidPrime::new(3);
+        let algebra = Arc::new(match algebra_type {
+            AlgebraType::Adem => SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(p, false)),
+            AlgebraType::Milnor => SteenrodAlgebra::MilnorAlgebra(MilnorAlgebra::new(p, false)),
+        });
+        algebra.compute_basis(10);
+        let module = FiniteDimensionalModule::from_json(
+            Arc::clone(&algebra),
+            &serde_json::json!({
+                "type" : "finite dimensional module",
+                "p": 3,
+                "gens": {"x0": 0, "x1": 1, "x4": 4, "x5": 5},
+                "actions": ["b x0 = x1", "P1 x0 = x4", "P1 x1 = x5", "b x4 = x5"]
+            }),
+        )
+        .unwrap();
+        module.check_associativity(5, 5).unwrap();
+
+        let dual = module.dual();
+        dual.check_associativity(5, 5).unwrap();
+        // (b x5*)(x4) = -x5*(chi(b) x4) = x5*(b x4) = 1
+        // (P1 x5*)(x1) = x5*(chi(P1) x1) = -x5*(P1 x1) = -1
+        assert_eq!(dual.action(1, 0, -5, 0).entry(0), 1);
+        assert_eq!(dual.action(4, 0, -5, 0).entry(0), 2);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_quotient_closes_under_action() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let joker =
+            FiniteDimensionalModule::from_json(Arc::clone(&algebra), &crate::test::joker_json())
+                .unwrap();
+
+        // x2 generates x4 = Sq2 x2 as well
+        let quotient = joker.quotient(&[(2, 0)]);
+        for (t, dim) in [(0, 1), (1, 1), (2, 0), (3, 1), (4, 0)] {
+            assert_eq!(quotient.dimension(t), dim);
+        }
+        let (_, sq2) = algebra.basis_element_from_string("Sq2").unwrap();
+        assert_eq!(quotient.action(2, sq2, 1, 0).entry(0), 1);
+        assert_eq!(quotient.basis_element_to_string(3, 0), "x3");
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_check_associativity() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let c2 = crate::test::c2_module(Arc::clone(&algebra));
+        c2.check_associativity(4, 1).unwrap();
+
+        let mut joker =
+            FiniteDimensionalModule::from_json(Arc::clone(&algebra), &crate::test::joker_json())
+                .unwrap();
+        joker.check_associativity(4, 4).unwrap();
+
+        // Set Sq^3 x0 = x3. However, Sq^1 (Sq^2 x0) = Sq^1 x2 = 0.
+        let (_, sq3) = algebra.basis_element_from_string("Sq3").unwrap();
+        joker.set_action(3, sq3, 0, 0, &[1]);
+        assert_eq!(
+            joker.check_associativity(4, 4).unwrap_err(),
+            "Action is not associative: (Sq1 * Sq2) * x0 = x3, but Sq1 * (Sq2 * x0) = 0"
+        );
+    }
+
+    #[test]
+    fn test_try_act() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+
+        let c2 = crate::test::c2_module(Arc::clone(&algebra));
+
+        let input = FpVector::from_slice(p, &[1]);
+        let mut result = FpVector::new(p, 1);
+        c2.try_act(result.as_slice_mut(), 1, 1, 0, 0, input.as_slice())
+            .unwrap();
+        assert_eq!(result, input);
+
+        // Constructing the module computes the algebra through degree 2
+        assert_eq!(
+            c2.try_act(result.as_slice_mut(), 1, 3, 0, 0, input.as_slice()),
+            Err(
+                "Operation has degree 3, but the algebra basis has only been computed through degree 2"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            c2.try_act(result.as_slice_mut(), 1, 1, 1, 0, input.as_slice()),
+            Err("Operation index 1 out of range in degree 1, which has dimension 1".to_string())
+        );
+        assert_eq!(
+            c2.try_act(
+                FpVector::new(p, 2).as_slice_mut(),
+                1,
+                1,
+                0,
+                0,
+                input.as_slice()
+            ),
+            Err("Result has length 2, but the module has dimension 1 in degree 1".to_string())
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_adem_milnor_actions() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(MilnorAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let joker = crate::test::joker_json();
+        let mut json = joker.clone();
+        json["adem_actions"] = json["actions"].take();
+        json["milnor_actions"] = json["adem_actions"].clone();
+        json.as_object_mut().unwrap().remove("actions");
+
+        let module = FiniteDimensionalModule::from_json(Arc::clone(&algebra), &json).unwrap();
+        assert!(
+            module == FiniteDimensionalModule::from_json(Arc::clone(&algebra), &joker).unwrap()
+        );
+
+        let json = json!({
+            "type" : "finite dimensional module",
+            "p": 2,
+            "gens": {"x0": 0, "x1": 1},
+            "adem_actions": ["Sq1 x0 = x1"],
+            "milnor_actions": []
+        });
+        let Err(err) = FiniteDimensionalModule::from_json(Arc::clone(&algebra), &json) else {
+            panic!("Inconsistent actions accepted");
+        };
+        assert_eq!(
+            err.to_string(),
+            "Adem and Milnor actions disagree: P(1) x0 is 0 in milnor_actions but x1 in adem_actions"
+        );
+
+        let json = json!({
+            "type" : "finite dimensional module",
+            "p": 2,
+            "gens": {"x0": 0, "x1": 1},
+            "adem_actions": ["Sq1 x0 = x1"]
+        });
+        let Err(err) = FiniteDimensionalModule::from_json(algebra, &json) else {
+            panic!("Module without milnor_actions accepted");
+        };
+        assert_eq!(
+            err.to_string(),
+            "Module has neither an actions nor a milnor_actions field"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_adem_milnor_actions_profile() {
+        use crate::algebra::milnor_algebra::MilnorProfile;
+
+        let p = fp::prime::ValidPrime::new(2);
+        let profile = MilnorProfile {
+            truncated: true,
+            q_part: !0,
+            p_part: vec![2, 1],
+        };
+        let algebra = Arc::new(MilnorAlgebra::new_with_profile(p, profile, false));
+        algebra.compute_basis(10);
+
+        let mut json = crate::test::joker_json();
+        json["adem_actions"] = json["actions"].take();
+        json["milnor_actions"] = json!([
+            "P(1) x0 = x1",
+            "P(2) x1 = x3",
+            "P(1) x3 = x4",
+            "P(2) x0 = x2",
+            "P(2) x2 = x4"
+        ]);
+        json.as_object_mut().unwrap().remove("actions");
+        FiniteDimensionalModule::from_json(Arc::clone(&algebra), &json).unwrap();
+
+        json["adem_actions"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!("Sq4 x0 = 0"));
+        let Err(err) = FiniteDimensionalModule::from_json(algebra, &json) else {
+            panic!("Operation outside the profile accepted");
+        };
+        assert_eq!(
+            err.to_string(),
+            "Sq4 is not in MilnorAlgebra(p=2, profile=A(1))"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_round_trip() {
+        let p = fp::prime::ValidPrime::new(2);
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let modules = [
+            json!({
+                "type": "finite dimensional module",
+                "name": "C(2)",
+                "gens": { "x0": 0, "x1": 1 },
+                "actions": ["Sq1 x0 = x1"]
+            }),
+            json!({
+                "type": "finite dimensional module",
+                "name": "Joker",
+                "gens": { "x0": 0, "x1": 1, "x2": 2, "x3": 3, "x4": 4 },
+                "actions": [
+                    "Sq1 x0 = x1",
+                    "Sq2 x0 = x2",
+                    "Sq2 x1 = x3",
+                    "Sq2 x2 = x4",
+                    "Sq1 x3 = x4"
+                ]
+            }),
+        ];
+
+        for json in modules {
+            let module = FiniteDimensionalModule::from_json(Arc::clone(&algebra), &json).unwrap();
+
+            let mut output = json!({});
+            module.to_json(&mut output);
+            let reloaded =
+                FiniteDimensionalModule::from_json(Arc::clone(&algebra), &output).unwrap();
+
+            assert_eq!(reloaded.name, module.name);
+            if let Err(msg) = module.test_equal(&reloaded) {
+                panic!("{}: {msg}", module.name);
+            }
+        }
+    }
 }
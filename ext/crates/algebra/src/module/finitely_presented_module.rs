@@ -172,6 +172,10 @@ impl<A: Algebra> Module for FinitelyPresentedModule<A> {
         self.generators.algebra()
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        self.generators.with_algebra(f)
+    }
+
     fn min_degree(&self) -> i32 {
         self.generators.min_degree()
     }
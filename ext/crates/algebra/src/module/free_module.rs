@@ -63,6 +63,10 @@ impl<const U: bool, A: MuAlgebra<U>> Module for MuFreeModule<U, A> {
         Arc::clone(&self.algebra)
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        f(&self.algebra)
+    }
+
     fn min_degree(&self) -> i32 {
         self.min_degree
     }
@@ -240,6 +244,12 @@ impl<const U: bool, A: MuAlgebra<U>> MuFreeModule<U, A> {
         self.num_gens[degree]
     }
 
+    /// The sum of the dimensions of the module in all degrees strictly below `t`. The module must
+    /// be computed through degree `t - 1`.
+    pub fn total_dimension_below(&self, t: i32) -> usize {
+        (self.min_degree..t).map(|i| self.dimension(i)).sum()
+    }
+
     pub fn add_generators(&self, degree: i32, num_gens: usize, names: Option<Vec<String>>) {
         // We need to acquire the lock because changing num_gens modifies the behaviour of
         // extend_table_entries, and the two cannot happen concurrently.
@@ -284,6 +294,26 @@ impl<const U: bool, A: MuAlgebra<U>> MuFreeModule<U, A> {
         }
     }
 
+    /// The internal index of the generator `(gen_deg, gen_idx)`, i.e. its position in the list
+    /// of all generators ordered by degree.
+    pub fn internal_generator_index(&self, gen_deg: i32, gen_idx: usize) -> usize {
+        assert!(gen_idx < self.number_of_gens_in_degree(gen_deg));
+        self.gen_deg_idx_to_internal_idx[gen_deg] + gen_idx
+    }
+
+    /// The generator `(gen_deg, gen_idx)` with the given internal index. This is the inverse of
+    /// [`MuFreeModule::internal_generator_index`].
+    pub fn generator_from_internal_index(&self, internal_gen_idx: usize) -> (i32, usize) {
+        let mut offset = 0;
+        for (gen_deg, &num_gens) in self.num_gens.iter_enum() {
+            if internal_gen_idx < offset + num_gens {
+                return (gen_deg, internal_gen_idx - offset);
+            }
+            offset += num_gens;
+        }
+        panic!("Internal generator index {internal_gen_idx} out of range ({offset} generators)");
+    }
+
     /// Given a generator `(gen_deg, gen_idx)`, find the first index in degree `degree` with
     /// elements from the generator.
     pub fn internal_generator_offset(&self, degree: i32, internal_gen_idx: usize) -> usize {
@@ -340,6 +370,8 @@ impl<const U: bool, A: MuAlgebra<U>> MuFreeModule<U, A> {
         &self.basis_element_to_opgen[degree][index]
     }
 
+    /// Declare that there are no generators in degrees up to `degree` other than those that
+    /// have already been added. This is idempotent.
     pub fn extend_by_zero(&self, degree: i32) {
         self.algebra.compute_basis(degree - self.min_degree);
         self.compute_basis(degree);
@@ -348,6 +380,28 @@ impl<const U: bool, A: MuAlgebra<U>> MuFreeModule<U, A> {
         }
     }
 
+    /// Declare that there are no generators in degrees `min_degree..=max_degree`. Generators
+    /// in degrees below `min_degree` must already have been added. This is idempotent.
+    ///
+    /// # Panics
+    /// This panics if generators have not been added in some degree below `min_degree`, or if
+    /// there are already generators in some degree in the range.
+    pub fn extend_by_zero_through(&self, min_degree: i32, max_degree: i32) {
+        assert!(
+            self.num_gens.len() >= min_degree,
+            "Generators in degree {} have not been added",
+            self.num_gens.len()
+        );
+        for t in min_degree..std::cmp::min(self.num_gens.len(), max_degree + 1) {
+            assert_eq!(
+                self.number_of_gens_in_degree(t),
+                0,
+                "There are generators in degree {t}"
+            );
+        }
+        self.extend_by_zero(max_degree);
+    }
+
     /// A version of element_to_string that names the generator as x_(t - s, s, idx). The input s
     /// only affects how the output is displayed.
     pub fn element_to_string_pretty(&self, s: u32, t: i32, vec: Slice) -> String {
@@ -674,3 +728,120 @@ impl std::ops::IndexMut<usize> for AdmissibleMatrix {
     }
 }
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Algebra, MilnorAlgebra};
+    use fp::vector::FpVector;
+
+    #[test]
+    fn test_extend_by_zero_through() {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(MilnorAlgebra::new(p, false));
+
+        let zero = FreeModule::new(Arc::clone(&algebra), "zero".to_string(), 0);
+        zero.extend_by_zero_through(0, 5);
+        zero.extend_by_zero_through(0, 5);
+        zero.extend_by_zero_through(3, 8);
+        assert_eq!(zero.max_computed_degree(), 8);
+        for t in 0..=8 {
+            assert_eq!(zero.number_of_gens_in_degree(t), 0);
+            assert_eq!(zero.dimension(t), 0);
+        }
+
+        // Sq^2 acts trivially
+        let mut result = FpVector::new(p, 0);
+        zero.act(
+            result.as_slice_mut(),
+            1,
+            2,
+            0,
+            3,
+            FpVector::new(p, 0).as_slice(),
+        );
+        assert!(result.is_empty());
+
+        let module = FreeModule::new(Arc::clone(&algebra), "F".to_string(), 0);
+        module.add_generators(0, 1, None);
+        module.extend_by_zero_through(1, 5);
+        module.extend_by_zero_through(1, 5);
+        for t in 1..=5 {
+            assert_eq!(module.number_of_gens_in_degree(t), 0);
+            assert_eq!(module.dimension(t), algebra.dimension(t));
+        }
+    }
+
+    #[test]
+    fn test_with_algebra() {
+        let algebra = Arc::new(MilnorAlgebra::new(fp::prime::TWO, false));
+        algebra.compute_basis(20);
+        let module = FreeModule::new(Arc::clone(&algebra), "F".to_string(), 0);
+        assert_eq!(Arc::strong_count(&algebra), 2);
+
+        // Querying the algebra in a loop does not touch the refcount
+        let mut total = 0;
+        for _ in 0..1000 {
+            total += module.with_algebra(|a| {
+                assert_eq!(Arc::strong_count(&algebra), 2);
+                a.dimension(20)
+            });
+        }
+        assert_eq!(total, 1000 * algebra.dimension(20));
+
+        // ... while `algebra` clones the `Arc`
+        let a = module.algebra();
+        assert_eq!(Arc::strong_count(&algebra), 3);
+        drop(a);
+
+        // Boxed modules fall back to the default implementation
+        let boxed: Box<dyn Module<Algebra = MilnorAlgebra>> = Box::new(module);
+        assert_eq!(
+            boxed.with_algebra(|a| a.dimension(20)),
+            algebra.dimension(20)
+        );
+    }
+
+    #[test]
+    fn test_total_dimension_below() {
+        let algebra = Arc::new(MilnorAlgebra::new(fp::prime::TWO, false));
+        algebra.compute_basis(10);
+
+        let module = FreeModule::new(Arc::clone(&algebra), "F".to_string(), -1);
+        module.add_generators(-1, 1, None);
+        module.add_generators(0, 0, None);
+        module.add_generators(1, 2, None);
+        module.add_generators(2, 0, None);
+        module.add_generators(3, 1, None);
+        module.extend_by_zero(6);
+
+        assert_eq!(module.number_of_gens_in_degree(-2), 0);
+        assert_eq!(module.number_of_gens_in_degree(-1), 1);
+        assert_eq!(module.number_of_gens_in_degree(1), 2);
+        assert_eq!(module.number_of_gens_in_degree(3), 1);
+        assert_eq!(module.number_of_gens_in_degree(5), 0);
+
+        let mut total = 0;
+        for t in -1..=7 {
+            assert_eq!(module.total_dimension_below(t), total);
+            if t <= 6 {
+                let expected = algebra.dimension(t + 1)
+                    + 2 * algebra.dimension(t - 1)
+                    + algebra.dimension(t - 3);
+                assert_eq!(module.dimension(t), expected);
+                total += expected;
+            }
+        }
+        assert_eq!(module.total_dimension_below(-5), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "There are generators in degree 2")]
+    fn test_extend_by_zero_through_nonzero() {
+        let algebra = Arc::new(MilnorAlgebra::new(fp::prime::TWO, false));
+        let module = FreeModule::new(algebra, "F".to_string(), 0);
+        module.extend_by_zero(1);
+        module.add_generators(2, 1, None);
+        module.extend_by_zero_through(0, 4);
+    }
+}
@@ -56,6 +56,10 @@ impl<M: Module> Module for HomModule<M> {
         Arc::clone(&self.algebra)
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        f(&self.algebra)
+    }
+
     fn min_degree(&self) -> i32 {
         self.block_structures.min_degree()
     }
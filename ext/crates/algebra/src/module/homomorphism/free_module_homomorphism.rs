@@ -253,6 +253,61 @@ where
     pub fn set_quasi_inverse(&self, degree: i32, quasi_inverse: Option<QuasiInverse>) {
         self.quasi_inverses.push_checked(quasi_inverse, degree);
     }
+
+    /// Lift `self` through `g`. If `self` is $f: F \to C$ and `g` is $g: B \to C$, this returns a
+    /// map $h: F \to B$ with $g h = f$, or `None` if the image of some generator of $F$ does not
+    /// lie in the image of $g$.
+    ///
+    /// The lift is computed on generators using the quasi-inverses of `g`, which must be available
+    /// in every degree `self` takes nonzero values in.
+    pub fn lift(
+        &self,
+        g: &MuFreeModuleHomomorphism<U, M>,
+    ) -> Option<MuFreeModuleHomomorphism<U, MuFreeModule<U, M::Algebra>>> {
+        let p = self.prime();
+        let shift = self.degree_shift - g.degree_shift;
+        let result = MuFreeModuleHomomorphism::new(self.source(), g.source(), shift);
+
+        for t in self.min_degree()..self.next_degree() {
+            let num_gens = self.source.number_of_gens_in_degree(t);
+            if t < result.min_degree() {
+                // The lift is necessarily zero here
+                if (0..num_gens).any(|i| !self.output(t, i).is_zero()) {
+                    return None;
+                }
+                continue;
+            }
+
+            let output_degree = t - self.degree_shift;
+            let lift_degree = t - shift;
+            g.source.compute_basis(lift_degree);
+            let lift_dim = g.source.dimension(lift_degree);
+            let output_dim = self.target.dimension(output_degree);
+
+            let mut rows = Vec::with_capacity(num_gens);
+            for i in 0..num_gens {
+                let mut output = self.output(t, i).clone();
+                let mut row = FpVector::new(p, lift_dim);
+                if !output.is_zero() {
+                    // Outputs may be shorter than the dimension of the target
+                    output.extend_len(output_dim);
+                    let qi = g.quasi_inverse(output_degree).unwrap_or_else(|| {
+                        panic!("Quasi-inverse of g missing in degree {output_degree}")
+                    });
+                    qi.apply(row.as_slice_mut(), 1, output.as_slice());
+
+                    let mut image = FpVector::new(p, output_dim);
+                    g.apply(image.as_slice_mut(), 1, lift_degree, row.as_slice());
+                    if image != output {
+                        return None;
+                    }
+                }
+                rows.push(row);
+            }
+            result.add_generators_from_rows(t, rows);
+        }
+        Some(result)
+    }
 }
 
 impl<const U: bool, M: Module> ZeroHomomorphism<MuFreeModule<U, M::Algebra>, M>
@@ -155,6 +155,27 @@ pub trait ModuleHomomorphism: Send + Sync {
         matrix
     }
 
+    /// Whether the homomorphism is injective at input degree `degree`. This uses
+    /// [`ModuleHomomorphism::kernel`] if it is available, and computes the kernel otherwise.
+    fn is_injective(&self, degree: i32) -> bool {
+        match self.kernel(degree) {
+            Some(kernel) => kernel.is_empty(),
+            None => self.auxiliary_data(degree).1.is_empty(),
+        }
+    }
+
+    /// Whether the homomorphism is surjective at input degree `degree`. This uses
+    /// [`ModuleHomomorphism::image`] if it is available, and computes the image otherwise.
+    fn is_surjective(&self, degree: i32) -> bool {
+        let output_degree = degree - self.degree_shift();
+        self.target().compute_basis(output_degree);
+        let target_dimension = self.target().dimension(output_degree);
+        match self.image(degree) {
+            Some(image) => image.dimension() == target_dimension,
+            None => self.auxiliary_data(degree).0.dimension() == target_dimension,
+        }
+    }
+
     /// Attempt to apply quasi inverse to the input. Returns whether the operation was
     /// successful. This is required to either always succeed or always fail for each degree.
     #[must_use]
@@ -177,3 +198,61 @@ pub trait ZeroHomomorphism<S: Module, T: Module<Algebra = S::Algebra>>:
 pub trait IdentityHomomorphism<S: Module>: ModuleHomomorphism<Source = S, Target = S> {
     fn identity_homomorphism(s: Arc<S>) -> Self;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::module::{FDModule, FreeModule};
+    use crate::MilnorAlgebra;
+    use bivec::BiVec;
+    use fp::vector::FpVector;
+
+    #[test]
+    fn test_injective_surjective() {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(MilnorAlgebra::new(p, false));
+        let joker = Arc::new(
+            FDModule::from_json(Arc::clone(&algebra), &crate::test::joker_json()).unwrap(),
+        );
+
+        // The augmentation of the free module on one generator onto the Joker
+        let free = Arc::new(FreeModule::new(Arc::clone(&algebra), "F0".to_string(), 0));
+        free.add_generators(0, 1, None);
+        free.extend_by_zero(4);
+        let augmentation = FreeModuleHomomorphism::new(Arc::clone(&free), Arc::clone(&joker), 0);
+        augmentation.add_generators_from_rows(0, vec![FpVector::from_slice(p, &[1])]);
+        augmentation.extend_by_zero(4);
+
+        for t in 0..=4 {
+            assert!(augmentation.is_surjective(t), "t = {t}");
+        }
+        assert!(augmentation.is_injective(0));
+        assert!(!augmentation.is_injective(3));
+
+        // The inclusion of the top cell of the Joker
+        let top_cell = Arc::new(
+            FDModule::from_json(
+                Arc::clone(&algebra),
+                &serde_json::json!({
+                    "type": "finite dimensional module",
+                    "p": 2,
+                    "gens": { "y": 4 },
+                    "actions": []
+                }),
+            )
+            .unwrap(),
+        );
+        let inclusion = FullModuleHomomorphism::from_matrices(
+            top_cell,
+            Arc::clone(&joker),
+            0,
+            BiVec::from_vec(4, vec![Matrix::from_vec(p, &[vec![1]])]),
+        );
+
+        for t in 0..=4 {
+            assert!(inclusion.is_injective(t), "t = {t}");
+        }
+        assert!(inclusion.is_surjective(4));
+        assert!(!inclusion.is_surjective(0));
+    }
+}
@@ -1,3 +1,4 @@
+mod augmentation_ideal_module;
 mod finite_dimensional_module;
 mod finitely_presented_module;
 mod free_module;
@@ -15,6 +16,7 @@ pub mod block_structure;
 
 pub mod homomorphism;
 
+pub use augmentation_ideal_module::AugmentationIdealModule;
 pub use finite_dimensional_module::FiniteDimensionalModule as FDModule;
 pub use finitely_presented_module::FinitelyPresentedModule as FPModule;
 pub use free_module::{
@@ -3,7 +3,7 @@ use itertools::Itertools;
 use std::sync::Arc;
 
 use fp::prime::ValidPrime;
-use fp::vector::{Slice, SliceMut};
+use fp::vector::{FpVector, Slice, SliceMut};
 
 use crate::algebra::Algebra;
 
@@ -28,6 +28,17 @@ pub trait Module: std::fmt::Display + std::any::Any + Send + Sync {
     /// The algebra the module is over.
     fn algebra(&self) -> Arc<Self::Algebra>;
 
+    /// Call `f` with a reference to the algebra the module is over. Unlike [`Module::algebra`],
+    /// this need not clone an `Arc`, which avoids refcount traffic in hot paths. Modules that hold
+    /// on to their algebra should override the default implementation.
+    #[auto_impl(keep_default_for(Box))]
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R
+    where
+        Self: Sized,
+    {
+        f(&self.algebra())
+    }
+
     /// The minimum degree of the module, which is required to be bounded below
     fn min_degree(&self) -> i32;
 
@@ -80,6 +91,12 @@ pub trait Module: std::fmt::Display + std::any::Any + Send + Sync {
         self.max_degree()
     }
 
+    /// The range of degrees in which the module can be non-zero, or `None` if the module is
+    /// unbounded. This is `min_degree()..=max_degree()`.
+    fn dimension_range(&self) -> Option<std::ops::RangeInclusive<i32>> {
+        Some(self.min_degree()..=self.max_degree()?)
+    }
+
     fn total_dimension(&self) -> usize {
         let max_degree = self
             .max_degree()
@@ -119,6 +136,70 @@ pub trait Module: std::fmt::Display + std::any::Any + Send + Sync {
         }
     }
 
+    /// A checked version of [`Module::act`]. Instead of panicking deep inside the algebra or the
+    /// module when the arguments are out of range, this returns a description of the first
+    /// problem found. This is meant for validation tooling; use [`Module::act`] otherwise.
+    ///
+    /// In addition to the requirements of [`Module::act`], the length of `result` must be the
+    /// dimension of the module in the output degree.
+    fn try_act(
+        &self,
+        result: SliceMut,
+        coeff: u32,
+        op_degree: i32,
+        op_index: usize,
+        input_degree: i32,
+        input: Slice,
+    ) -> Result<(), String> {
+        let algebra = self.algebra();
+        let output_degree = input_degree + op_degree;
+
+        if op_degree < 0 {
+            return Err(format!("Operation has negative degree {op_degree}"));
+        }
+        if op_degree > algebra.computed_through() {
+            return Err(format!(
+                "Operation has degree {op_degree}, but the algebra basis has only been computed through degree {}",
+                algebra.computed_through()
+            ));
+        }
+        if op_index >= algebra.dimension(op_degree) {
+            return Err(format!(
+                "Operation index {op_index} out of range in degree {op_degree}, which has dimension {}",
+                algebra.dimension(op_degree)
+            ));
+        }
+        if input_degree < self.min_degree() {
+            return Err(format!(
+                "Input degree {input_degree} is below the minimum degree {} of the module",
+                self.min_degree()
+            ));
+        }
+        if output_degree > self.max_computed_degree() {
+            return Err(format!(
+                "Output degree {output_degree} is beyond the maximum computed degree {} of the module",
+                self.max_computed_degree()
+            ));
+        }
+        if input.len() > self.dimension(input_degree) {
+            return Err(format!(
+                "Input has length {}, but the module has dimension {} in degree {input_degree}",
+                input.len(),
+                self.dimension(input_degree)
+            ));
+        }
+        if result.as_slice().len() != self.dimension(output_degree) {
+            return Err(format!(
+                "Result has length {}, but the module has dimension {} in degree {output_degree}",
+                result.as_slice().len(),
+                self.dimension(output_degree)
+            ));
+        }
+
+        self.act(result, coeff, op_degree, op_index, input_degree, input);
+        Ok(())
+    }
+
     fn act_by_element(
         &self,
         mut result: SliceMut,
@@ -166,6 +247,121 @@ pub trait Module: std::fmt::Display + std::any::Any + Send + Sync {
         }
     }
 
+    /// The action of a basis element of the algebra on a basis element of the module, as a newly
+    /// allocated vector. This is a convenience wrapper around [`Module::act_on_basis`].
+    ///
+    /// ```
+    /// # use algebra::module::{FDModule, Module};
+    /// # use algebra::{AdemAlgebra, Algebra};
+    /// # use bivec::BiVec;
+    /// # use fp::prime::TWO;
+    /// # use std::sync::Arc;
+    /// let algebra = Arc::new(AdemAlgebra::new(TWO, false));
+    /// algebra.compute_basis(1);
+    ///
+    /// let mut c2 = FDModule::new(algebra, String::from("C2"), BiVec::from_vec(0, vec![1, 1]));
+    /// c2.set_action(1, 0, 0, 0, &[1]);
+    ///
+    /// // Sq^1 hits the top class
+    /// let result = c2.act_on_basis_owned(1, 0, 0, 0);
+    /// assert_eq!(result.len(), 1);
+    /// assert_eq!(result.entry(0), 1);
+    /// ```
+    fn act_on_basis_owned(
+        &self,
+        op_degree: i32,
+        op_index: usize,
+        input_degree: i32,
+        input_index: usize,
+    ) -> FpVector {
+        let mut result = FpVector::new(self.prime(), self.dimension(input_degree + op_degree));
+        self.act_on_basis(
+            result.as_slice_mut(),
+            1,
+            op_degree,
+            op_index,
+            input_degree,
+            input_index,
+        );
+        result
+    }
+
+    /// Check that the action is associative, i.e. $(ab)m = a(bm)$ for all basis elements $a, b$ of
+    /// the algebra of degree at most `max_op_degree` and basis elements $m$ of the module of degree
+    /// at most `max_module_degree`. If this fails, the error describes the first failure found.
+    fn check_associativity(
+        &self,
+        max_op_degree: i32,
+        max_module_degree: i32,
+    ) -> Result<(), String> {
+        let p = self.prime();
+        let algebra = self.algebra();
+        algebra.compute_basis(2 * max_op_degree);
+        self.compute_basis(max_module_degree + 2 * max_op_degree);
+
+        let mut bm = FpVector::new(p, 0);
+        let mut a_bm = FpVector::new(p, 0);
+        let mut ab = FpVector::new(p, 0);
+        let mut ab_m = FpVector::new(p, 0);
+
+        for m_deg in self.min_degree()..=max_module_degree {
+            for m_idx in 0..self.dimension(m_deg) {
+                for b_deg in 0..=max_op_degree {
+                    for b_idx in 0..algebra.dimension(b_deg) {
+                        bm.set_scratch_vector_size(self.dimension(m_deg + b_deg));
+                        self.act_on_basis(bm.as_slice_mut(), 1, b_deg, b_idx, m_deg, m_idx);
+
+                        for a_deg in 0..=max_op_degree {
+                            let output_deg = m_deg + b_deg + a_deg;
+                            for a_idx in 0..algebra.dimension(a_deg) {
+                                a_bm.set_scratch_vector_size(self.dimension(output_deg));
+                                self.act(
+                                    a_bm.as_slice_mut(),
+                                    1,
+                                    a_deg,
+                                    a_idx,
+                                    m_deg + b_deg,
+                                    bm.as_slice(),
+                                );
+
+                                ab.set_scratch_vector_size(algebra.dimension(a_deg + b_deg));
+                                algebra.multiply_basis_elements(
+                                    ab.as_slice_mut(),
+                                    1,
+                                    a_deg,
+                                    a_idx,
+                                    b_deg,
+                                    b_idx,
+                                );
+                                ab_m.set_scratch_vector_size(self.dimension(output_deg));
+                                self.act_by_element_on_basis(
+                                    ab_m.as_slice_mut(),
+                                    1,
+                                    a_deg + b_deg,
+                                    ab.as_slice(),
+                                    m_deg,
+                                    m_idx,
+                                );
+
+                                if a_bm != ab_m {
+                                    let a = algebra.basis_element_to_string(a_deg, a_idx);
+                                    let b = algebra.basis_element_to_string(b_deg, b_idx);
+                                    let m = self.basis_element_to_string(m_deg, m_idx);
+                                    return Err(format!(
+                                        "Action is not associative: ({a} * {b}) * {m} = {}, but {a} * ({b} * {m}) = {}",
+                                        self.element_to_string(output_deg, ab_m.as_slice()),
+                                        self.element_to_string(output_deg, a_bm.as_slice()),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Gives the name of an element. The default implementation is derived from
     /// [`Module::basis_element_to_string`] in the obvious way.
     fn element_to_string(&self, degree: i32, element: Slice) -> String {
@@ -137,6 +137,10 @@ impl<M: Module> Module for QuotientModule<M> {
         self.module.algebra()
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        self.module.with_algebra(f)
+    }
+
     fn min_degree(&self) -> i32 {
         self.module.min_degree()
     }
@@ -60,6 +60,10 @@ where
         Arc::clone(&self.algebra)
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        f(&self.algebra)
+    }
+
     fn min_degree(&self) -> i32 {
         self.min
     }
@@ -124,7 +128,18 @@ where
 }
 
 // Compute the coefficient of the operation on x^j.
-fn coef_adem(algebra: &AdemAlgebra, op_deg: i32, op_idx: usize, mut j: i32) -> bool {
+fn coef_adem(algebra: &AdemAlgebra, op_deg: i32, op_idx: usize, j: i32) -> bool {
+    algebra
+        .to_admissibles(op_deg, op_idx)
+        .iter_nonzero()
+        .filter(|&(i, _)| coef_admissible(algebra, op_deg, i, j))
+        .count()
+        & 1
+        == 1
+}
+
+// Compute the coefficient of the admissible monomial on x^j.
+fn coef_admissible(algebra: &AdemAlgebra, op_deg: i32, op_idx: usize, mut j: i32) -> bool {
     let elt: &AdemBasisElement = algebra.basis_element_from_index(op_deg, op_idx);
     // Apply Sq^i to x^j and see if it is zero
     for i in elt.ps.iter().rev() {
@@ -8,6 +8,7 @@ mod json {
 
     use crate::module::{FDModule, FPModule, RealProjectiveSpace, SuspensionModule};
     use anyhow::anyhow;
+    use bivec::BiVec;
     use std::sync::Arc;
 
     pub fn from_json(
@@ -30,6 +31,19 @@ mod json {
                 RealProjectiveSpace::from_json(algebra, json)?,
                 json,
             )),
+            Some("sphere") => {
+                let degree = json["degree"]
+                    .as_i64()
+                    .ok_or_else(|| anyhow!("Missing sphere degree"))?;
+                let degree = i32::try_from(degree)
+                    .map_err(|_| anyhow!("Sphere degree {degree} out of range"))?;
+                let module = FDModule::new(
+                    algebra,
+                    format!("S^{degree}"),
+                    BiVec::from_vec(degree, vec![1]),
+                );
+                Ok(box_new(module, json))
+            }
             Some("finite dimensional module") => {
                 Ok(box_new(FDModule::from_json(algebra, json)?, json))
             }
@@ -46,3 +60,56 @@ mod json {
 pub use json::*;
 
 use super::Module;
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+
+    use crate::algebra::{AdemAlgebra, Algebra};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_dimension_range() {
+        let algebra = Arc::new(SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(
+            fp::prime::TWO,
+            false,
+        )));
+        algebra.compute_basis(10);
+
+        let mut json = crate::test::joker_json();
+        let joker = from_json(Arc::clone(&algebra), &json).unwrap();
+        assert_eq!(joker.dimension_range(), Some(0..=4));
+
+        json["shift"] = serde_json::json!(-3);
+        let shifted = from_json(Arc::clone(&algebra), &json).unwrap();
+        assert_eq!(shifted.dimension_range(), Some(-3..=1));
+
+        let rp_inf = from_json(
+            algebra,
+            &serde_json::json!({
+                "p": 2,
+                "type": "real projective space",
+                "min": 1,
+            }),
+        )
+        .unwrap();
+        assert_eq!(rp_inf.dimension_range(), None);
+    }
+
+    #[test]
+    fn test_sphere_degree_out_of_range() {
+        let algebra = Arc::new(SteenrodAlgebra::AdemAlgebra(AdemAlgebra::new(
+            fp::prime::TWO,
+            false,
+        )));
+        let json = serde_json::json!({
+            "p": 2,
+            "type": "sphere",
+            "degree": 1_i64 << 32,
+        });
+        let Err(err) = from_json(algebra, &json) else {
+            panic!("Sphere of degree 2^32 accepted");
+        };
+        assert_eq!(err.to_string(), "Sphere degree 4294967296 out of range");
+    }
+}
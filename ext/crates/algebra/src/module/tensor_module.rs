@@ -4,7 +4,6 @@ use once::OnceBiVec;
 use crate::algebra::{Algebra, Bialgebra};
 use crate::module::block_structure::BlockStructure;
 use crate::module::{Module, ZeroModule};
-use fp::prime::minus_one_to_the_n;
 use fp::vector::{FpVector, Slice, SliceMut};
 
 use std::sync::Arc;
@@ -60,84 +59,41 @@ where
         input: Slice,
     ) {
         let algebra = self.algebra();
-        let p = self.prime();
-
-        let coproduct = algebra.coproduct(op_degree, op_index).into_iter();
         let output_degree = mod_degree + op_degree;
 
-        let mut left_result = FpVector::new(p, 0);
-        let mut right_result = FpVector::new(p, 0);
+        let mut idx = 0;
+        for left_deg in self.left.min_degree()..=(mod_degree - self.right.min_degree()) {
+            let right_deg = mod_degree - left_deg;
 
-        for (op_deg_l, op_idx_l, op_deg_r, op_idx_r) in coproduct {
-            let mut idx = 0;
-            for left_deg in self.left.min_degree()..=(mod_degree - self.right.min_degree()) {
-                let right_deg = mod_degree - left_deg;
+            // Here we use `Module::dimension(&*m, i)` instead of `m.dimension(i)` because there are
+            // multiple `dimension` methods in scope and rust-analyzer gets confused if we're not
+            // explicit enough.
+            let left_source_dim = Module::dimension(&*self.left, left_deg);
+            let right_source_dim = Module::dimension(&*self.right, right_deg);
 
-                // Here we use `Module::dimension(&*m, i)` instead of `m.dimension(i)` because there are
-                // multiple `dimension` methods in scope and rust-analyzer gets confused if we're not
-                // explicit enough.
-                let left_source_dim = Module::dimension(&*self.left, left_deg);
-                let right_source_dim = Module::dimension(&*self.right, right_deg);
-
-                let left_target_dim = Module::dimension(&*self.left, left_deg + op_deg_l);
-                let right_target_dim = Module::dimension(&*self.right, right_deg + op_deg_r);
-
-                if left_target_dim == 0
-                    || right_target_dim == 0
-                    || left_source_dim == 0
-                    || right_source_dim == 0
-                {
-                    idx += left_source_dim * right_source_dim;
+            for i in 0..left_source_dim {
+                let row = input.slice(idx, idx + right_source_dim);
+                idx += right_source_dim;
+                if row.is_zero() {
                     continue;
                 }
 
-                left_result.set_scratch_vector_size(left_target_dim);
-                right_result.set_scratch_vector_size(right_target_dim);
-
-                for i in 0..left_source_dim {
-                    self.left.act_on_basis(
-                        left_result.as_slice_mut(),
-                        coeff,
-                        op_deg_l,
-                        op_idx_l,
-                        left_deg,
-                        i,
-                    );
-
-                    if left_result.is_zero() {
-                        idx += right_source_dim;
-                        continue;
-                    }
-
-                    for j in 0..right_source_dim {
-                        let entry = input.entry(idx);
-                        idx += 1;
-                        if entry == 0 {
-                            continue;
-                        }
-                        self.right.act_on_basis(
-                            right_result.as_slice_mut(),
-                            entry,
-                            op_deg_r,
-                            op_idx_r,
-                            right_deg,
-                            j,
-                        );
-
-                        if right_result.is_zero() {
-                            continue;
-                        }
+                algebra.act_diagonally(
+                    &*self.left,
+                    &*self.right,
+                    coeff,
+                    (op_degree, op_index),
+                    (left_deg, i),
+                    (right_deg, row),
+                    |deg, left_result, right_result| {
                         result.add_tensor(
-                            self.offset(output_degree, left_deg + op_deg_l),
-                            minus_one_to_the_n(*self.prime(), op_deg_r * left_deg),
-                            left_result.as_slice(),
-                            right_result.as_slice(),
+                            self.offset(output_degree, deg),
+                            1,
+                            left_result,
+                            right_result,
                         );
-
-                        right_result.set_to_zero();
-                    }
-                    left_result.set_to_zero();
-                }
+                    },
+                );
             }
         }
     }
@@ -154,6 +110,10 @@ where
         self.left.algebra()
     }
 
+    fn with_algebra<R>(&self, f: impl FnOnce(&Self::Algebra) -> R) -> R {
+        self.left.with_algebra(f)
+    }
+
     fn min_degree(&self) -> i32 {
         self.left.min_degree() + self.right.min_degree()
     }
@@ -237,7 +197,7 @@ where
                     FpVector::new(p, self.dimension(working_degree + op_degree));
                 self.act_helper(
                     working_element.as_slice_mut(),
-                    coeff,
+                    1,
                     op_degree,
                     op_index,
                     working_degree,
@@ -250,7 +210,7 @@ where
                         FpVector::new(p, self.dimension(working_degree + op_degree));
                     self.act_helper(
                         new_element.as_slice_mut(),
-                        coeff,
+                        1,
                         op_degree,
                         op_index,
                         working_degree,
@@ -312,3 +272,149 @@ where
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdemAlgebra;
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_tensor_odd_prime() {
+        let p = fp::prime::ValidPrime::new(3);
+        let algebra = Arc::new(crate::MilnorAlgebra::new(p, false));
+        algebra.compute_basis(20);
+        // Q_1 x0 = (P1 b - b P1) x0 = x5
+        let module = Arc::new(
+            crate::module::FDModule::from_json(
+                Arc::clone(&algebra),
+                &serde_json::json!({
+                    "type" : "finite dimensional module",
+                    "p": 3,
+                    "gens": {"x0": 0, "x1": 1, "x4": 4, "x5": 5},
+                    "actions": ["b x0 = x1", "P1 x0 = x4", "P1 x1 = x5"]
+                }),
+            )
+            .unwrap(),
+        );
+        let tensor = TensorModule::new(Arc::clone(&module), Arc::clone(&module));
+        tensor.compute_basis(10);
+        tensor.check_associativity(10, 10).unwrap();
+
+        let q1 = algebra.basis_element_from_string("Q_1").unwrap();
+        let mut result = FpVector::new(p, tensor.dimension(5));
+        tensor.act_on_basis(result.as_slice_mut(), 1, q1.0, q1.1, 0, 0);
+        assert_eq!(
+            tensor.element_to_string(5, result.as_slice()),
+            "x0.x5 + x5.x0"
+        );
+    }
+
+    #[test]
+    fn test_cartan_formula() {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(4);
+        let c2 = Arc::new(crate::test::c2_module(Arc::clone(&algebra)));
+        let tensor = TensorModule::new(Arc::clone(&c2), Arc::clone(&c2));
+        tensor.compute_basis(2);
+
+        let sq2 = algebra.basis_element_from_string("Sq2").unwrap();
+        assert_eq!(algebra.decompose(sq2.0, sq2.1), [sq2]);
+
+        // Sq^2(x0 x0) = Sq^2 x0 x0 + Sq^1 x0 Sq^1 x0 + x0 Sq^2 x0 = x1 x1
+        let mut expected = FpVector::new(p, tensor.dimension(2));
+        expected.set_entry(0, 1);
+        assert_eq!(tensor.basis_element_to_string(2, 0), "x1.x1");
+
+        let mut x0 = FpVector::new(p, 1);
+        x0.set_entry(0, 1);
+        let mut result = FpVector::new(p, tensor.dimension(2));
+        algebra.act_diagonally(
+            &*c2,
+            &*c2,
+            1,
+            sq2,
+            (0, 0),
+            (0, x0.as_slice()),
+            |deg, l, r| {
+                result
+                    .as_slice_mut()
+                    .add_tensor(tensor.offset(2, deg), 1, l, r);
+            },
+        );
+        assert_eq!(result, expected);
+
+        result.set_to_zero();
+        tensor.act_on_basis(result.as_slice_mut(), 1, sq2.0, sq2.1, 0, 0);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_act_matches_act_diagonally() {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(12);
+        let joker = Arc::new(
+            crate::module::FDModule::from_json(Arc::clone(&algebra), &crate::test::joker_json())
+                .unwrap(),
+        );
+        let c2 = Arc::new(crate::test::c2_module(Arc::clone(&algebra)));
+        let tensor = TensorModule::new(Arc::clone(&joker), Arc::clone(&c2));
+        tensor.compute_basis(12);
+
+        for n in 1..=7 {
+            let (op_deg, op_idx) = algebra
+                .basis_element_from_string(&format!("Sq{n}"))
+                .unwrap();
+            assert_eq!(algebra.decompose(op_deg, op_idx), [(op_deg, op_idx)]);
+            for mod_deg in 0..=5 {
+                let output_deg = mod_deg + op_deg;
+                let mut input_idx = 0;
+                for left_deg in 0..=mod_deg {
+                    let right_deg = mod_deg - left_deg;
+                    for i in 0..joker.dimension(left_deg) {
+                        for j in 0..c2.dimension(right_deg) {
+                            let mut y = FpVector::new(p, c2.dimension(right_deg));
+                            y.set_entry(j, 1);
+                            let mut expected = FpVector::new(p, tensor.dimension(output_deg));
+                            algebra.act_diagonally(
+                                &*joker,
+                                &*c2,
+                                1,
+                                (op_deg, op_idx),
+                                (left_deg, i),
+                                (right_deg, y.as_slice()),
+                                |deg, l, r| {
+                                    expected.as_slice_mut().add_tensor(
+                                        tensor.offset(output_deg, deg),
+                                        1,
+                                        l,
+                                        r,
+                                    );
+                                },
+                            );
+
+                            let mut result = FpVector::new(p, tensor.dimension(output_deg));
+                            tensor.act_on_basis(
+                                result.as_slice_mut(),
+                                1,
+                                op_deg,
+                                op_idx,
+                                mod_deg,
+                                input_idx,
+                            );
+                            assert_eq!(
+                                result, expected,
+                                "Sq{op_deg} on ({left_deg}, {i}) x ({right_deg}, {j})"
+                            );
+                            input_idx += 1;
+                        }
+                    }
+                }
+                assert_eq!(input_idx, tensor.dimension(mod_deg));
+            }
+        }
+        tensor.check_associativity(5, 7).unwrap();
+    }
+}
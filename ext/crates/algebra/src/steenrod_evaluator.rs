@@ -176,7 +176,9 @@ impl SteenrodEvaluator {
             }
             AlgebraBasisElt::Q(x) => {
                 let tau_degrees = crate::algebra::combinatorics::tau_degrees(p);
-                let degree = tau_degrees[x as usize];
+                let degree = *tau_degrees
+                    .get(x as usize)
+                    .ok_or_else(|| anyhow!("Q{x} is out of range"))?;
                 self.compute_basis(degree);
                 let mut result = FpVector::new(p, self.dimension(degree));
                 self.adem_q(&mut result, 1, x);
@@ -465,6 +467,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_evaluate_q_out_of_range() {
+        let ev = SteenrodEvaluator::new(ValidPrime::new(2));
+        let err = ev.evaluate_algebra_adem("Q100").unwrap_err();
+        assert_eq!(err.to_string(), "Q100 is out of range");
+        assert!(ev.evaluate_algebra_milnor("Sq1 * Q4294967295").is_err());
+    }
+
     #[rstest(p, max_degree, case(2, 32), case(3, 60))]
     #[trace]
     fn test_cob_adem_to_milnor(p: u32, max_degree: i32) {
@@ -503,4 +513,96 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_coproduct_adem_vs_milnor() {
+        use crate::algebra::Bialgebra;
+        use std::collections::BTreeSet;
+
+        // An element of A ⊗ A at p = 2, as a set of (left degree, left index, right degree, right
+        // index)
+        type Tensor = BTreeSet<(i32, usize, i32, usize)>;
+        fn toggle(tensor: &mut Tensor, term: (i32, usize, i32, usize)) {
+            if !tensor.remove(&term) {
+                tensor.insert(term);
+            }
+        }
+
+        let p = ValidPrime::new(2);
+        let max_degree = 20;
+        let ev = SteenrodEvaluator::new(p);
+        ev.compute_basis(2 * max_degree);
+
+        let to_milnor = |degree: i32, idx: usize| {
+            let mut adem = FpVector::new(p, ev.dimension(degree));
+            adem.set_entry(idx, 1);
+            let mut result = FpVector::new(p, ev.dimension(degree));
+            ev.adem_to_milnor(&mut result, 1, degree, &adem);
+            result
+        };
+
+        for degree in 0..=max_degree {
+            for idx in 0..ev.dimension(degree) {
+                // The coproduct is multiplicative, and decompose writes the element as a product
+                // of squares, starting with the one that acts first.
+                let mut adem_coproduct = Tensor::from([(0, 0, 0, 0)]);
+                for (op_deg, op_idx) in ev.adem.decompose(degree, idx) {
+                    let mut next = Tensor::new();
+                    for (a_deg, a_idx, b_deg, b_idx) in ev.adem.coproduct(op_deg, op_idx) {
+                        for &(c_deg, c_idx, d_deg, d_idx) in &adem_coproduct {
+                            let mut left = FpVector::new(p, ev.dimension(a_deg + c_deg));
+                            let mut right = FpVector::new(p, ev.dimension(b_deg + d_deg));
+                            ev.adem.multiply_basis_elements(
+                                left.as_slice_mut(),
+                                1,
+                                a_deg,
+                                a_idx,
+                                c_deg,
+                                c_idx,
+                            );
+                            ev.adem.multiply_basis_elements(
+                                right.as_slice_mut(),
+                                1,
+                                b_deg,
+                                b_idx,
+                                d_deg,
+                                d_idx,
+                            );
+                            for (l, _) in left.iter_nonzero() {
+                                for (r, _) in right.iter_nonzero() {
+                                    toggle(&mut next, (a_deg + c_deg, l, b_deg + d_deg, r));
+                                }
+                            }
+                        }
+                    }
+                    adem_coproduct = next;
+                }
+
+                let mut converted = Tensor::new();
+                for &(l_deg, l_idx, r_deg, r_idx) in &adem_coproduct {
+                    let left = to_milnor(l_deg, l_idx);
+                    let right = to_milnor(r_deg, r_idx);
+                    for (l, _) in left.iter_nonzero() {
+                        for (r, _) in right.iter_nonzero() {
+                            toggle(&mut converted, (l_deg, l, r_deg, r));
+                        }
+                    }
+                }
+
+                let mut milnor_coproduct = Tensor::new();
+                for (i, _) in to_milnor(degree, idx).iter_nonzero() {
+                    for term in ev.milnor.coproduct(degree, i) {
+                        toggle(&mut milnor_coproduct, term);
+                    }
+                }
+
+                assert_eq!(
+                    converted,
+                    milnor_coproduct,
+                    "{}",
+                    ev.adem.basis_element_to_string(degree, idx)
+                );
+            }
+        }
+    }
 }
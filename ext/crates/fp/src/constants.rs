@@ -35,6 +35,16 @@ macro_rules! populate_binomial_table {
     };
 }
 
+/// `ENTRY_VALUES[i] == i`. Entries of vectors are packed into limbs, so indexing into a vector
+/// returns a reference into this table instead.
+pub(crate) static ENTRY_VALUES: [u32; MAX_PRIME] = {
+    let mut result = [0; MAX_PRIME];
+    const_for! { i in 0 .. MAX_PRIME {
+        result[i] = i as u32;
+    }}
+    result
+};
+
 pub(crate) const BINOMIAL4_TABLE_SIZE: usize = 50;
 
 pub(crate) const BINOMIAL4_TABLE: [[u32; BINOMIAL4_TABLE_SIZE]; BINOMIAL4_TABLE_SIZE] = {
@@ -284,6 +284,40 @@ impl Matrix {
     }
 }
 
+/// The number of entries (rows times columns) below which [`for_each_row`] does not parallelize.
+/// Starting a parallel iterator costs a few microseconds, which is more than the cost of
+/// reducing a smaller block sequentially.
+#[cfg(feature = "concurrent")]
+const PARALLEL_ROW_REDUCE_CUTOFF: usize = 1 << 20;
+
+/// Apply `f` to each of `rows`, in parallel if `parallel` is set and there are at least
+/// [`PARALLEL_ROW_REDUCE_CUTOFF`] entries. This is used by [`Matrix::row_reduce`], where
+/// `parallel` can only be set with the `concurrent` feature.
+#[cfg_attr(not(feature = "concurrent"), allow(unused_variables))]
+fn for_each_row(rows: &mut [FpVector], parallel: bool, f: impl Fn(&mut FpVector) + Send + Sync) {
+    #[cfg(feature = "concurrent")]
+    if parallel && rows.len() * rows.first().map_or(0, FpVector::len) >= PARALLEL_ROW_REDUCE_CUTOFF
+    {
+        rows.par_iter_mut().for_each(f);
+        return;
+    }
+    rows.iter_mut().for_each(f);
+}
+
+impl FpVector {
+    /// The outer product of `self` and `other`, i.e. the matrix whose `(i, j)`th entry is
+    /// `self[i] * other[j]`. This lives here rather than with the rest of [`FpVector`] so that it
+    /// is defined once for both choices of the `odd-primes` feature.
+    pub fn outer_product(&self, other: &Self) -> Matrix {
+        assert_eq!(self.prime(), other.prime());
+        let mut result = Matrix::new(self.prime(), self.len(), other.len());
+        for (i, c) in self.iter_nonzero() {
+            result.row_mut(i).add(other.as_slice(), c);
+        }
+        result
+    }
+}
+
 impl<'a> IntoIterator for &'a Matrix {
     type Item = &'a FpVector;
     type IntoIter = std::slice::Iter<'a, FpVector>;
@@ -497,6 +531,19 @@ impl Matrix {
     /// assert_eq!(m, Matrix::from_vec(p, &result));
     /// ```
     pub fn row_reduce(&mut self) -> usize {
+        self.row_reduce_inner(false)
+    }
+
+    /// A version of [`Matrix::row_reduce`] that reduces the other rows against each new pivot in
+    /// parallel. The result is identical to that of [`Matrix::row_reduce`]. Blocks of rows with
+    /// fewer than $2^{20}$ entries are still reduced sequentially, since the overhead of
+    /// parallelization would dominate.
+    #[cfg(feature = "concurrent")]
+    pub fn par_row_reduce(&mut self) -> usize {
+        self.row_reduce_inner(true)
+    }
+
+    fn row_reduce_inner(&mut self, parallel: bool) -> usize {
         let p = self.p;
         self.initialize_pivots();
 
@@ -521,12 +568,9 @@ impl Matrix {
 
                     if table.len() == k {
                         table.generate(self);
-                        for j in 0..table.rows()[0] {
-                            table.reduce(self[j].limbs_mut());
-                        }
-                        for j in i + 1..self.rows() {
-                            table.reduce(self[j].limbs_mut());
-                        }
+                        let reduce = |v: &mut FpVector| table.reduce(v.limbs_mut());
+                        for_each_row(&mut self.vectors[..table.rows()[0]], parallel, reduce);
+                        for_each_row(&mut self.vectors[i + 1..], parallel, reduce);
                         table.clear();
                     }
                 } else {
@@ -535,9 +579,9 @@ impl Matrix {
             }
             if !table.is_empty() {
                 table.generate(self);
-                for j in 0..table.rows()[0] {
-                    table.reduce(self[j].limbs_mut());
-                }
+                for_each_row(&mut self.vectors[..table.rows()[0]], parallel, |v| {
+                    table.reduce(v.limbs_mut())
+                });
                 table.clear();
             }
         } else {
@@ -545,14 +589,19 @@ impl Matrix {
                 if let Some((c, v)) = self[i].first_nonzero() {
                     self.pivots[c] = i as isize;
                     self[i].scale(prime::inverse(p, v));
-                    for j in 0..self.rows() {
-                        if i == j {
-                            continue;
-                        }
-                        unsafe {
-                            self.row_op(j, i, c, p);
+
+                    let (before, rest) = self.vectors.split_at_mut(i);
+                    let (pivot_row, after) = rest.split_first_mut().unwrap();
+                    let pivot_row = &*pivot_row;
+                    // This is `row_op` with source `pivot_row`
+                    let reduce = |v: &mut FpVector| {
+                        let coef = v.entry(c);
+                        if coef != 0 {
+                            v.add_offset(pivot_row, *p - coef, c);
                         }
-                    }
+                    };
+                    for_each_row(before, parallel, reduce);
+                    for_each_row(after, parallel, reduce);
                 } else {
                     empty_rows.push(i);
                 }
@@ -581,6 +630,34 @@ impl Matrix {
         }
         num_rows
     }
+
+    /// Perform row reduction like [`Matrix::row_reduce`], but use `pivots` as the pivot buffer
+    /// instead of allocating a new one for every matrix. The buffer is moved into the matrix,
+    /// which then has its pivots as usual, and `pivots` is left empty. Once the pivots are no
+    /// longer needed, [`Matrix::take_pivots`] returns the buffer, so that callers that reduce many
+    /// matrices in a row can reuse the same buffer throughout.
+    ///
+    /// # Returns
+    /// The number of non-empty rows in the matrix
+    pub fn row_reduce_reuse(&mut self, pivots: &mut Vec<isize>) -> usize {
+        self.pivots = std::mem::take(pivots);
+        self.row_reduce()
+    }
+
+    /// A version of [`Matrix::row_reduce_reuse`] that reduces in parallel like
+    /// [`Matrix::par_row_reduce`].
+    #[cfg(feature = "concurrent")]
+    pub fn par_row_reduce_reuse(&mut self, pivots: &mut Vec<isize>) -> usize {
+        self.pivots = std::mem::take(pivots);
+        self.par_row_reduce()
+    }
+
+    /// Take the pivot buffer out of the matrix, e.g. to pass it to [`Matrix::row_reduce_reuse`].
+    /// The matrix no longer records any pivots afterwards, so functions that rely on them, such
+    /// as [`Matrix::compute_kernel`], should not be called on it.
+    pub fn take_pivots(&mut self) -> Vec<isize> {
+        std::mem::take(&mut self.pivots)
+    }
 }
 
 impl Matrix {
@@ -763,7 +840,8 @@ impl Matrix {
     /// matrix. This will panic if there are not enough empty rows.
     ///
     /// The rows added are all zero except in a single column, where it is 1. The function returns
-    /// the list of such columns.
+    /// the list of such columns. The rows are added in order of increasing column, so the result
+    /// only depends on the pivots of the matrix.
     ///
     /// # Arguments
     ///  * `first_empty_row` - The first row in the matrix that is empty. This is where we will add
@@ -800,7 +878,9 @@ impl Matrix {
     /// image of B becomes this superspace.
     ///
     /// The rows added are basis vectors of the desired image as specified in the Subspace object.
-    /// The function returns the list of new pivot columns.
+    /// The function returns the list of new pivot columns. The rows are added in order of
+    /// increasing pivot column, so the result only depends on the pivots of the matrix and on
+    /// `desired_image`.
     ///
     /// # Panics
     /// It *may* panic if the current image is not contained in `desired_image`, but is not
@@ -873,6 +953,39 @@ impl Matrix {
         }
     }
 
+    /// Adds `coeff` times the product `self * input` to `result`, where `input` is viewed as a
+    /// column vector. The `i`th entry of the product is the dot product of the `i`th row with
+    /// `input`. Compare [`Matrix::apply`], which multiplies by a row vector on the left instead.
+    ///
+    /// # Example
+    /// ```
+    /// # use fp::prime::ValidPrime;
+    /// let p = ValidPrime::new(7);
+    /// # use fp::matrix::Matrix;
+    /// # use fp::vector::FpVector;
+    /// let input  = [vec![1, 3, 6],
+    ///               vec![0, 3, 4]];
+    ///
+    /// let m = Matrix::from_vec(p, &input);
+    /// let v = FpVector::from_slice(p, &vec![1, 1, 2]);
+    /// let mut result = FpVector::new(p, 2);
+    /// let desired_result = FpVector::from_slice(p, &vec![2, 4]);
+    /// m.mul_vector(result.as_slice_mut(), 1, v.as_slice());
+    /// assert_eq!(result, desired_result);
+    /// ```
+    pub fn mul_vector(&self, mut result: SliceMut, coeff: u32, input: Slice) {
+        assert_eq!(input.len(), self.columns());
+        assert_eq!(result.as_slice().len(), self.rows());
+        let p = *self.p;
+        assert!(coeff < p);
+        for (i, row) in self.vectors.iter().enumerate() {
+            let dot = input
+                .iter_nonzero()
+                .fold(0, |acc, (j, c)| (acc + row.entry(j) * c) % p);
+            result.add_basis_element(i, coeff * dot % p);
+        }
+    }
+
     pub fn trim(&mut self, row_start: usize, row_end: usize, col_start: usize) {
         self.vectors.truncate(row_end);
         self.vectors.drain(0..row_start);
@@ -1080,7 +1193,17 @@ impl AugmentedMatrix<3> {
     ///
     /// This takes ownership of the matrix since it heavily modifies the matrix. This is not
     /// strictly necessary but is fine in most applications.
-    pub fn compute_quasi_inverses(mut self) -> (QuasiInverse, QuasiInverse) {
+    pub fn compute_quasi_inverses(self) -> (QuasiInverse, QuasiInverse) {
+        self.compute_quasi_inverses_reuse(&mut Vec::new())
+    }
+
+    /// A version of [`AugmentedMatrix::compute_quasi_inverses`] that hands the pivot buffer of the
+    /// matrix back in `pivots` when it is done, so that it can be passed to the next call of
+    /// [`Matrix::row_reduce_reuse`].
+    pub fn compute_quasi_inverses_reuse(
+        mut self,
+        pivots: &mut Vec<isize>,
+    ) -> (QuasiInverse, QuasiInverse) {
         let p = self.prime();
 
         let source_columns = self.end[2] - self.start[2];
@@ -1088,6 +1211,7 @@ impl AugmentedMatrix<3> {
         if self.end[0] == 0 {
             let cc_qi = QuasiInverse::new(None, Matrix::new(p, 0, source_columns));
             let res_qi = Matrix::compute_quasi_inverse(&self, self.end[1], self.start[2]);
+            *pivots = self.take_pivots();
             (cc_qi, res_qi)
         } else {
             let mut cc_preimage = Matrix::new(p, self.end[0], source_columns);
@@ -1104,6 +1228,7 @@ impl AugmentedMatrix<3> {
             let mut res_matrix = self.drop_first();
             res_matrix.row_reduce();
             let res_qi = res_matrix.compute_quasi_inverse();
+            *pivots = res_matrix.take_pivots();
 
             (cm_qi, res_qi)
         }
@@ -1238,4 +1363,124 @@ mod tests {
             assert_eq!(m.pivots(), &goal_pivots)
         }
     }
+
+    #[test]
+    fn test_mul_vector() {
+        for (p, rows, columns) in [(2, 7, 15), (2, 70, 130), (3, 20, 13), (5, 9, 30)] {
+            if !crate::prime::is_valid_prime(p) {
+                continue;
+            }
+            let p = ValidPrime::new(p);
+            let input: Vec<Vec<u32>> = (0..rows)
+                .map(|i| {
+                    (0..columns)
+                        .map(|j| ((i * i * 7 + j * 13 + i * j) % 11 % *p as usize) as u32)
+                        .collect()
+                })
+                .collect();
+            let v: Vec<u32> = (0..columns)
+                .map(|j| ((j * j + 3) % 7 % *p as usize) as u32)
+                .collect();
+            let m = Matrix::from_vec(p, &input);
+
+            for coeff in 1..*p {
+                // Accumulate into a result with assorted nonzero entries
+                let initial: Vec<u32> = (0..rows).map(|i| (i * 5 + 1) as u32 % *p).collect();
+                let mut result = FpVector::from_slice(p, &initial);
+                m.mul_vector(
+                    result.as_slice_mut(),
+                    coeff,
+                    FpVector::from_slice(p, &v).as_slice(),
+                );
+
+                let expected: Vec<u32> = std::iter::zip(&input, &initial)
+                    .map(|(row, init)| {
+                        let dot: u32 = std::iter::zip(row, &v).map(|(a, b)| a * b).sum();
+                        (init + coeff * dot) % *p
+                    })
+                    .collect();
+                assert_eq!(
+                    Vec::<u32>::from(&result),
+                    expected,
+                    "p = {p}, coeff = {coeff}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_row_reduce_reuse() {
+        let mut pivots = Vec::new();
+        for (p, rows, columns) in [(2, 7, 15), (2, 70, 130), (3, 20, 13), (5, 9, 30)] {
+            if !crate::prime::is_valid_prime(p) {
+                continue;
+            }
+            let p = ValidPrime::new(p);
+            let input: Vec<Vec<u32>> = (0..rows)
+                .map(|i| {
+                    (0..columns)
+                        .map(|j| ((i * i * 7 + j * 13 + i * j) % 11 % *p as usize) as u32)
+                        .collect()
+                })
+                .collect();
+
+            let mut fresh = Matrix::from_vec(p, &input);
+            let fresh_rows = fresh.row_reduce();
+
+            let old_ptr = pivots.as_ptr();
+            let old_capacity = pivots.capacity();
+            let mut reused = Matrix::from_vec(p, &input);
+            let reused_rows = reused.row_reduce_reuse(&mut pivots);
+
+            assert_eq!(fresh_rows, reused_rows);
+            assert!(pivots.is_empty());
+            assert_eq!(fresh.pivots(), reused.pivots());
+            assert_eq!(
+                fresh.compute_kernel(columns / 2),
+                reused.compute_kernel(columns / 2)
+            );
+            for i in 0..rows {
+                assert_eq!(fresh[i], reused[i]);
+            }
+
+            pivots = reused.take_pivots();
+            assert!(reused.pivots().is_empty());
+            assert_eq!(fresh.pivots(), &*pivots);
+            if old_capacity >= columns {
+                assert_eq!(pivots.as_ptr(), old_ptr);
+            }
+        }
+    }
+
+    #[cfg(feature = "concurrent")]
+    #[test]
+    fn test_par_row_reduce() {
+        use rand::Rng;
+
+        let mut rng = rand::thread_rng();
+        // The last two cases are large enough to be reduced in parallel
+        for (p, rows, columns) in [
+            (2, 7, 15),
+            (2, 300, 500),
+            (3, 20, 13),
+            (5, 100, 150),
+            (2, 1100, 1000),
+            (3, 1100, 1000),
+        ] {
+            if !crate::prime::is_valid_prime(p) {
+                continue;
+            }
+            let p = ValidPrime::new(p);
+            let input: Vec<Vec<u32>> = (0..rows)
+                .map(|_| (0..columns).map(|_| rng.gen_range(0..*p)).collect())
+                .collect();
+
+            let mut sequential = Matrix::from_vec(p, &input);
+            let mut parallel = Matrix::from_vec(p, &input);
+
+            assert_eq!(sequential.row_reduce(), parallel.par_row_reduce());
+            assert_eq!(sequential.pivots(), parallel.pivots());
+            assert_eq!(sequential, parallel);
+        }
+    }
 }
@@ -1,6 +1,8 @@
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 #[cfg(feature = "json")]
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use std::convert::TryFrom;
+use std::io::{Read, Write};
 
 use crate::constants::{
     BINOMIAL4_TABLE, BINOMIAL4_TABLE_SIZE, BINOMIAL_TABLE, INVERSE_TABLE, PRIME_TO_INDEX_MAP,
@@ -49,6 +51,20 @@ impl ValidPrime {
     }
 }
 
+impl ValidPrime {
+    /// Read a prime written by [`ValidPrime::to_bytes`]. This returns an error of kind
+    /// [`InvalidData`](std::io::ErrorKind::InvalidData) if the value read is not a supported prime.
+    pub fn from_bytes(data: &mut impl Read) -> std::io::Result<Self> {
+        let p = data.read_u32::<LittleEndian>()?;
+        Self::try_from(p).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+
+    /// Write the prime as a little endian `u32`.
+    pub fn to_bytes(&self, buffer: &mut impl Write) -> std::io::Result<()> {
+        buffer.write_u32::<LittleEndian>(**self)
+    }
+}
+
 impl std::ops::Deref for ValidPrime {
     type Target = u32;
 
@@ -508,6 +524,27 @@ mod tests {
     use super::*;
     use crate::constants::PRIMES;
 
+    #[test]
+    fn test_bytes_round_trip() {
+        for p in PRIMES.into_iter().filter(|&p| is_valid_prime(p)) {
+            let p = ValidPrime::new(p);
+            let mut buffer = Vec::new();
+            p.to_bytes(&mut buffer).unwrap();
+            assert_eq!(buffer, p.to_le_bytes());
+            assert_eq!(ValidPrime::from_bytes(&mut &buffer[..]).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_bytes_invalid() {
+        for p in [0, 1, 4, 9, 1_000_003] {
+            let err = ValidPrime::from_bytes(&mut &u32::to_le_bytes(p)[..]).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        }
+        let err = ValidPrime::from_bytes(&mut &[2, 0][..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+
     #[test]
     fn inverse_test() {
         for &p in PRIMES.iter() {
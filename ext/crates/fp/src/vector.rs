@@ -10,7 +10,8 @@
 use crate::limb::{entries_per_limb, Limb};
 use crate::prime::ValidPrime;
 use crate::vector_inner::{
-    FpVectorIterator, FpVectorNonZeroIteratorP, FpVectorP, SliceMutP, SliceP,
+    FpVectorIterator, FpVectorNonZeroIteratorP, FpVectorNonZeroRevIterator, FpVectorP, SliceMutP,
+    SliceP,
 };
 use itertools::Itertools;
 #[cfg(feature = "json")]
@@ -210,10 +211,27 @@ impl FpVector {
         match_p!(p, FpVectorP::from(&slice))
     }
 
+    /// Construct a vector from a list of entries, which need not be reduced mod `p`. This is meant
+    /// for exchanging vectors with external tools; see [`FpVector::to_vec`] for the converse.
+    pub fn from_vec(p: ValidPrime, entries: &[u32]) -> Self {
+        let entries: Vec<u32> = entries.iter().map(|&x| x % *p).collect();
+        Self::from_slice(p, &entries)
+    }
+
+    /// The entries of the vector as a dense list.
+    pub fn to_vec(&self) -> Vec<u32> {
+        self.iter().collect()
+    }
+
     pub fn num_limbs(p: ValidPrime, len: usize) -> usize {
         let entries_per_limb = entries_per_limb(p);
         (len + entries_per_limb - 1) / entries_per_limb
     }
+
+    /// The number of bytes used to store the entries of a vector of length `len`.
+    pub fn num_bytes(p: ValidPrime, len: usize) -> usize {
+        Self::num_limbs(p, len) * size_of::<Limb>()
+    }
     pub(crate) fn padded_len(p: ValidPrime, len: usize) -> usize {
         Self::num_limbs(p, len) * entries_per_limb(p)
     }
@@ -285,18 +303,25 @@ impl FpVector {
         pub fn slice_mut(&mut self, start: usize, end: usize) -> (dispatch SliceMut);
         pub fn as_slice_mut(&mut self) -> (dispatch SliceMut);
         pub fn is_zero(&self) -> bool;
+        pub fn hamming_weight(&self) -> usize;
+        pub fn reduce(&mut self);
         pub fn iter(&self) -> FpVectorIterator;
         pub fn iter_nonzero(&self) -> (dispatch FpVectorNonZeroIterator);
+        pub fn iter_nonzero_rev(&self) -> (FpVectorNonZeroRevIterator<'_>);
         pub fn extend_len(&mut self, dim: usize);
         pub fn set_scratch_vector_size(&mut self, dim: usize);
         pub fn add_basis_element(&mut self, index: usize, value: u32);
         pub fn copy_from_slice(&mut self, slice: &[u32]);
         pub(crate) fn trim_start(&mut self, n: usize);
         pub fn add_truncate(&mut self, other: &Self, c: u32) -> (Option<()>);
+        pub fn dot(&self, other: &Self) -> u32;
         pub fn sign_rule(&self, other: &Self) -> bool;
         pub fn add_carry(&mut self, other: &Self, c: u32, rest: &mut [FpVector]) -> bool;
         pub fn first_nonzero(&self) -> (Option<(usize, u32)>);
         pub fn density(&self) -> f32;
+        pub fn permute(&self, perm: &[usize]) -> (dispatch FpVector);
+        pub fn swap_entries(&mut self, i: usize, j: usize);
+        pub fn zero_entries_from(&mut self, start: usize);
 
         pub(crate) fn limbs(&self) -> (&[Limb]);
         pub(crate) fn limbs_mut(&mut self) -> (&mut [Limb]);
@@ -311,7 +336,9 @@ impl<'a> Slice<'a> {
         pub fn entry(&self, index: usize) -> u32;
         pub fn iter(self) -> (FpVectorIterator<'a>);
         pub fn iter_nonzero(self) -> (dispatch FpVectorNonZeroIterator 'a);
+        pub fn iter_nonzero_rev(self) -> (FpVectorNonZeroRevIterator<'a>);
         pub fn is_zero(&self) -> bool;
+        pub fn hamming_weight(&self) -> usize;
         pub fn slice(self, start: usize, end: usize) -> (dispatch Slice 'a);
         pub fn to_owned(self) -> (dispatch FpVector);
     }
@@ -391,6 +418,19 @@ impl std::ops::AddAssign<&Self> for FpVector {
     }
 }
 
+impl std::ops::Index<usize> for FpVector {
+    type Output = u32;
+
+    fn index(&self, index: usize) -> &u32 {
+        assert!(
+            index < self.len(),
+            "Index {index} too large, length of vector is only {}.",
+            self.len()
+        );
+        &crate::constants::ENTRY_VALUES[self.entry(index) as usize]
+    }
+}
+
 impl<'a> Iterator for FpVectorNonZeroIterator<'a> {
     type Item = (usize, u32);
 
@@ -611,6 +651,46 @@ mod test {
         };
     }
 
+    #[rstest]
+    #[case(2)]
+    #[case(3)]
+    fn test_zero_entries_from(#[case] p: u32) {
+        let p = ValidPrime::new(p);
+        for (dim, start) in [
+            (100, 0),
+            (100, 37),
+            (100, 64),
+            (100, 99),
+            (100, 100),
+            (10, 3),
+            (10, 20),
+        ] {
+            let v_arr = random_vector(p, dim);
+            let mut v = FpVector::from_slice(p, &v_arr);
+            v.zero_entries_from(start);
+
+            assert_eq!(v.len(), dim);
+            for (i, entry) in v.iter().enumerate() {
+                if i < start {
+                    assert_eq!(entry, v_arr[i], "Entry {i} changed");
+                } else {
+                    assert_eq!(entry, 0, "Entry {i} not zeroed");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_from_vec() {
+        let p = ValidPrime::new(5);
+        let entries = [0, 1, 2, 3, 4, 5, 6, 13, 24];
+        let v = FpVector::from_vec(p, &entries);
+        assert_eq!(v.to_vec(), [0, 1, 2, 3, 4, 0, 1, 3, 4]);
+
+        let entries = random_vector(p, 100);
+        assert_eq!(FpVector::from_vec(p, &entries).to_vec(), entries);
+    }
+
     test_dim! {
         fn test_serialize(p: ValidPrime, dim: usize) {
             use std::io::{Seek, Cursor};
@@ -639,6 +719,26 @@ mod test {
             v.assert_list_eq(&v_arr);
         }
 
+        fn test_add_assign(p: ValidPrime, dim: usize) {
+            let v_arr = random_vector(p, dim);
+            let w_arr = random_vector(p, dim);
+            let mut v = FpVector::from_slice(p, &v_arr);
+            let w = FpVector::from_slice(p, &w_arr);
+
+            let mut expected = v.clone();
+            expected.add(&w, 1);
+            v += &w;
+            v.assert_vec_eq(&expected);
+        }
+
+        fn test_index(p: ValidPrime, dim: usize) {
+            let v_arr = random_vector(p, dim);
+            let v = FpVector::from_slice(p, &v_arr);
+            for (i, &entry) in v_arr.iter().enumerate() {
+                assert_eq!(v[i], entry);
+            }
+        }
+
         fn test_scale(p: ValidPrime, dim: usize) {
             let mut v_arr = random_vector(p, dim);
             let mut rng = rand::thread_rng();
@@ -921,6 +1021,69 @@ mod test {
             }
         }
 
+        fn test_hamming_weight(p: ValidPrime, dim: usize, slice_start: usize, slice_end: usize) {
+            let v_arr = random_vector(p, dim);
+            let v = FpVector::from_slice(p, &v_arr);
+
+            let count = |arr: &[u32]| arr.iter().filter(|&&x| x != 0).count();
+            assert_eq!(v.hamming_weight(), count(&v_arr));
+            assert_eq!(
+                v.slice(slice_start, slice_end).hamming_weight(),
+                count(&v_arr[slice_start..slice_end])
+            );
+        }
+
+        fn test_dot(p: ValidPrime, dim: usize) {
+            let v_arr = random_vector(p, dim);
+            let w_arr = random_vector(p, dim);
+            let v = FpVector::from_slice(p, &v_arr);
+            let w = FpVector::from_slice(p, &w_arr);
+
+            let dot = std::iter::zip(&v_arr, &w_arr).map(|(a, b)| a * b).sum::<u32>() % *p;
+            assert_eq!(v.dot(&w), dot);
+        }
+
+        fn test_swap_entries(p: ValidPrime, dim: usize) {
+            let mut v_arr = random_vector(p, dim);
+            let mut v = FpVector::from_slice(p, &v_arr);
+
+            // These include swaps within a limb and across limbs
+            for (i, j) in [(0, dim - 1), (1, dim / 2), (dim - 1, 2), (3, 4), (5, 5)] {
+                v.swap_entries(i, j);
+                v_arr.swap(i, j);
+                v.assert_list_eq(&v_arr);
+            }
+        }
+
+        fn test_eq_ignores_allocation(p: ValidPrime, dim: usize) {
+            let v_arr = random_vector(p, dim);
+            let v = FpVector::from_slice(p, &v_arr);
+
+            let mut w = FpVector::new_with_capacity(p, dim, 2 * dim);
+            for (i, &x) in v_arr.iter().enumerate() {
+                w.set_entry(i, x);
+            }
+            assert_eq!(v, w);
+
+            // Put garbage in the unused bits of the last limb
+            let used_bits = (dim - (w.limbs().len() - 1) * entries_per_limb(p)) * limb::bit_length(p);
+            if used_bits < crate::constants::BITS_PER_LIMB {
+                *w.limbs_mut().last_mut().unwrap() |= !0 << used_bits;
+            }
+            assert_eq!(v, w);
+
+            let hash = |x: &FpVector| {
+                use std::hash::{Hash, Hasher};
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                x.hash(&mut hasher);
+                hasher.finish()
+            };
+            assert_eq!(hash(&v), hash(&w));
+
+            w.set_entry(0, (v_arr[0] + 1) % *p);
+            assert_ne!(v, w);
+        }
+
         fn test_iter_nonzero_empty(p: ValidPrime) {
             let v = FpVector::new(p, 0);
             for (_, _) in v.iter_nonzero() {
@@ -982,6 +1145,29 @@ mod test {
             // }
             assert!(diffs_str.is_empty(), "{}", diffs_str);
         }
+
+        fn test_iter_nonzero_rev(p: ValidPrime, dim: usize, slice_start: usize, slice_end: usize) {
+            let v_arr = random_vector(p, dim);
+            let v = FpVector::from_slice(p, &v_arr);
+
+            let mut expected: Vec<_> = v.iter_nonzero().collect();
+            expected.reverse();
+            assert_eq!(v.iter_nonzero_rev().collect::<Vec<_>>(), expected);
+
+            let slice = v.slice(slice_start, slice_end);
+            let mut expected: Vec<_> = slice.iter_nonzero().collect();
+            expected.reverse();
+            assert_eq!(slice.iter_nonzero_rev().collect::<Vec<_>>(), expected);
+        }
+
+        fn test_iter_nonzero_rev_empty(p: ValidPrime) {
+            let v = FpVector::new(p, 0);
+            assert_eq!(v.iter_nonzero_rev().next(), None);
+
+            let v = FpVector::new(p, 100);
+            assert_eq!(v.iter_nonzero_rev().next(), None);
+            assert_eq!(v.slice(30, 30).iter_nonzero_rev().next(), None);
+        }
     }
 
     #[rstest]
@@ -1060,6 +1246,81 @@ mod test {
         assert!(diffs.is_empty(), "{}", diffs_str);
     }
 
+    #[test]
+    fn test_permute() {
+        let p = ValidPrime::new(3);
+        let v = FpVector::from_slice(p, &[1, 2, 0, 1, 2, 2]);
+        let perm = [2, 0, 5, 1, 3, 4];
+        let mut inverse = [0; 6];
+        for (i, &j) in perm.iter().enumerate() {
+            inverse[j] = i;
+        }
+
+        let w = v.permute(&perm);
+        w.assert_list_eq(&[2, 1, 1, 2, 2, 0]);
+        v.assert_vec_eq(&w.permute(&inverse));
+    }
+
+    #[test]
+    fn test_reduce() {
+        let p = ValidPrime::new(5);
+        let mut v = FpVector::from_slice(p, &[1, 2, 3, 4, 0, 1]);
+        // set_entry does not reduce its argument
+        for (i, x) in [7, 12, 19, 5, 10, 0].into_iter().enumerate() {
+            v.set_entry(i, x);
+        }
+        v.reduce();
+        assert_eq!(Vec::<u32>::from(&v), vec![2, 2, 4, 0, 0, 0]);
+
+        let mut w = FpVector::from_slice(p, &[2, 2, 4, 0, 0, 0]);
+        w.reduce();
+        assert_eq!(v, w);
+    }
+
+    #[test]
+    fn test_outer_product() {
+        let p = ValidPrime::new(3);
+        let v = FpVector::from_slice(p, &[1, 2, 0]);
+        let w = FpVector::from_slice(p, &[2, 1, 1, 0]);
+
+        let mut m = v.outer_product(&w);
+        assert_eq!(
+            m.to_vec(),
+            [vec![2, 1, 1, 0], vec![1, 2, 2, 0], vec![0, 0, 0, 0]]
+        );
+        assert_eq!(m.row_reduce(), 1);
+
+        assert_eq!(FpVector::new(p, 3).outer_product(&w).to_vec(), [[0; 4]; 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "different primes")]
+    fn test_add_assign_prime_mismatch() {
+        let mut v = FpVector::new(ValidPrime::new(3), 3);
+        v += &FpVector::new(ValidPrime::new(5), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_len_mismatch() {
+        let mut v = FpVector::new(ValidPrime::new(3), 3);
+        v += &FpVector::new(ValidPrime::new(3), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "too large")]
+    fn test_index_out_of_bounds() {
+        let v = FpVector::new(ValidPrime::new(3), 3);
+        let _ = v[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a permutation")]
+    fn test_permute_invalid() {
+        let v = FpVector::new(ValidPrime::new(3), 3);
+        let _ = v.permute(&[0, 2, 2]);
+    }
+
     #[test]
     fn test_sign_rule_limb() {
         assert!(limb::sign_rule(1, 0b10) == 1);
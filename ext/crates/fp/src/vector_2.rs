@@ -29,11 +29,28 @@ impl FpVector {
         Self::from(&slice)
     }
 
+    /// Construct a vector from a list of entries, which need not be reduced mod `p`. This is meant
+    /// for exchanging vectors with external tools; see [`FpVector::to_vec`] for the converse.
+    pub fn from_vec(p: ValidPrime, entries: &[u32]) -> Self {
+        let entries: Vec<u32> = entries.iter().map(|&x| x % *p).collect();
+        Self::from_slice(p, &entries)
+    }
+
+    /// The entries of the vector as a dense list.
+    pub fn to_vec(&self) -> Vec<u32> {
+        self.iter().collect()
+    }
+
     pub fn num_limbs(_p: ValidPrime, len: usize) -> usize {
         let entries_per_limb = entries_per_limb_const::<2>();
         (len + entries_per_limb - 1) / entries_per_limb
     }
 
+    /// The number of bytes used to store the entries of a vector of length `len`.
+    pub fn num_bytes(p: ValidPrime, len: usize) -> usize {
+        Self::num_limbs(p, len) * size_of::<Limb>()
+    }
+
     #[allow(dead_code)]
     pub(crate) fn padded_len(p: ValidPrime, len: usize) -> usize {
         Self::num_limbs(p, len) * entries_per_limb_const::<2>()
@@ -19,7 +19,7 @@ use itertools::Itertools;
 ///
 /// Interally, it packs entries of the vectors into limbs. However, this is an abstraction that
 /// must not leave the `fp` library.
-#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+#[derive(Debug, Clone)]
 pub struct FpVectorP<const P: u32> {
     len: usize,
     limbs: Vec<Limb>,
@@ -128,6 +128,10 @@ impl<const P: u32> FpVectorP<P> {
         self.as_slice().iter_nonzero()
     }
 
+    pub fn iter_nonzero_rev(&self) -> FpVectorNonZeroRevIterator<'_> {
+        self.as_slice().iter_nonzero_rev()
+    }
+
     pub fn set_to_zero(&mut self) {
         for limb in &mut self.limbs {
             *limb = 0;
@@ -220,6 +224,17 @@ impl<const P: u32> FpVectorP<P> {
         self.limbs.iter().all(|&x| x == 0)
     }
 
+    /// The number of nonzero entries of the vector.
+    pub fn hamming_weight(&self) -> usize {
+        self.as_slice().hamming_weight()
+    }
+
+    /// Reduce every entry of the vector modulo p. This is a no-op if the entries are already
+    /// reduced, which is always the case at p = 2.
+    pub fn reduce(&mut self) {
+        self.as_slice_mut().reduce_limbs();
+    }
+
     pub(crate) fn limbs(&self) -> &[Limb] {
         &self.limbs
     }
@@ -270,6 +285,22 @@ impl<const P: u32> FpVectorP<P> {
         self.len -= n;
     }
 
+    /// The dot product of `self` and `other`. This works a limb at a time instead of reading the
+    /// entries one by one.
+    pub fn dot(&self, other: &Self) -> u32 {
+        assert_eq!(self.len, other.len);
+        let limbs = self.used_limbs().zip_eq(other.used_limbs());
+        if P == 2 {
+            limbs.map(|(x, y)| (x & y).count_ones()).sum::<u32>() % 2
+        } else {
+            limbs.fold(0, |result, (x, y)| {
+                limb::unpack::<P>(x)
+                    .zip(limb::unpack::<P>(y))
+                    .fold(result, |result, (a, b)| (result + a * b) % P)
+            })
+        }
+    }
+
     pub fn sign_rule(&self, other: &Self) -> bool {
         assert_eq!(P, 2);
         let mut result = 0;
@@ -355,6 +386,47 @@ impl<const P: u32> FpVectorP<P> {
         None
     }
 
+    /// Set all entries with index at least `start` to zero. This masks out whole limbs at a time,
+    /// so it is much faster than setting the entries individually. If `start` is at least the
+    /// length of the vector, this does nothing.
+    pub fn zero_entries_from(&mut self, start: usize) {
+        let len = self.len;
+        self.slice_mut(std::cmp::min(start, len), len).set_to_zero();
+    }
+
+    /// Swap the `i`th and `j`th entries of the vector.
+    pub fn swap_entries(&mut self, i: usize, j: usize) {
+        assert!(
+            i < self.len && j < self.len,
+            "Indices ({i}, {j}) out of range for vector of length {}",
+            self.len
+        );
+        let x = self.entry(i);
+        let y = self.entry(j);
+        self.set_entry(i, y);
+        self.set_entry(j, x);
+    }
+
+    /// Reindex the entries of the vector, producing a vector whose `perm[i]`th entry is the `i`th
+    /// entry of `self`. This panics if `perm` is not a permutation of `0..self.len()`.
+    #[must_use]
+    pub fn permute(&self, perm: &[usize]) -> Self {
+        assert_eq!(perm.len(), self.len, "Permutation has the wrong length");
+        let mut seen = vec![false; self.len];
+        for &i in perm {
+            assert!(
+                i < self.len && !std::mem::replace(&mut seen[i], true),
+                "{perm:?} is not a permutation"
+            );
+        }
+
+        let mut result = Self::new_(self.len);
+        for (i, c) in self.iter_nonzero() {
+            result.set_entry(perm[i], c);
+        }
+        result
+    }
+
     pub fn density(&self) -> f32 {
         let num_nonzero = if P == 2 {
             self.limbs
@@ -482,6 +554,16 @@ impl<'a, const P: u32> SliceP<'a, P> {
         FpVectorNonZeroIteratorP::new(self)
     }
 
+    /// Iterate over the non-zero entries of the slice, from the highest index to the lowest.
+    pub fn iter_nonzero_rev(self) -> FpVectorNonZeroRevIterator<'a> {
+        FpVectorNonZeroRevIterator::new(self)
+    }
+
+    /// The number of nonzero entries of the slice.
+    pub fn hamming_weight(&self) -> usize {
+        self.iter_nonzero().count()
+    }
+
     pub fn is_zero(&self) -> bool {
         let limb_range = self.limb_range();
         if limb_range.is_empty() {
@@ -1015,6 +1097,62 @@ impl AddShiftRightData {
     }
 }
 
+impl<const P: u32> FpVectorP<P> {
+    /// The limbs that hold the entries of the vector. The bits of the last limb that lie beyond
+    /// `len` are masked out, and any further limbs are ignored.
+    fn used_limbs(&self) -> impl Iterator<Item = Limb> + '_ {
+        let num_limbs = limb::number::<P>(self.len);
+        let last_mask = if num_limbs == 0 {
+            0
+        } else {
+            let num_entries = self.len - (num_limbs - 1) * limb::entries_per_limb_const::<P>();
+            (!0) >> (BITS_PER_LIMB - num_entries * limb::bit_length_const::<P>())
+        };
+        self.limbs[..num_limbs]
+            .iter()
+            .enumerate()
+            .map(move |(i, &limb)| {
+                if i + 1 == num_limbs {
+                    limb & last_mask
+                } else {
+                    limb
+                }
+            })
+    }
+}
+
+/// Two vectors are equal if they have the same length and the same entries, regardless of how
+/// many limbs they have allocated.
+impl<const P: u32> PartialEq for FpVectorP<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.used_limbs().eq(other.used_limbs())
+    }
+}
+
+impl<const P: u32> Eq for FpVectorP<P> {}
+
+impl<const P: u32> std::hash::Hash for FpVectorP<P> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        for limb in self.used_limbs() {
+            limb.hash(state);
+        }
+    }
+}
+
+impl<const P: u32> std::ops::Index<usize> for FpVectorP<P> {
+    type Output = u32;
+
+    fn index(&self, index: usize) -> &u32 {
+        assert!(
+            index < self.len(),
+            "Index {index} too large, length of vector is only {}.",
+            self.len()
+        );
+        &crate::constants::ENTRY_VALUES[self.entry(index) as usize]
+    }
+}
+
 impl<T: AsRef<[u32]>, const P: u32> From<&T> for FpVectorP<P> {
     fn from(slice: &T) -> Self {
         let mut v = Self::new_(slice.as_ref().len());
@@ -1219,3 +1357,66 @@ impl<'a, const P: u32> Iterator for FpVectorNonZeroIteratorP<'a, P> {
         Some(result)
     }
 }
+
+/// Iterator over non-zero entries of an FpVector in decreasing order of index. This scans the limbs
+/// from the top and skips over zero limbs entirely.
+pub struct FpVectorNonZeroRevIterator<'a> {
+    limbs: &'a [Limb],
+    bit_length: usize,
+    bit_mask: Limb,
+    entries_per_limb: usize,
+    start: usize,
+    /// The number of entries that have not been examined yet. The next entry to be examined has
+    /// index `remaining - 1` relative to `start`.
+    remaining: usize,
+}
+
+impl<'a> FpVectorNonZeroRevIterator<'a> {
+    fn new<const P: u32>(vec: SliceP<'a, P>) -> Self {
+        Self {
+            limbs: vec.limbs,
+            bit_length: limb::bit_length_const::<P>(),
+            bit_mask: limb::bitmask::<P>(),
+            entries_per_limb: limb::entries_per_limb_const::<P>(),
+            start: vec.start,
+            remaining: vec.len(),
+        }
+    }
+}
+
+impl<'a> Iterator for FpVectorNonZeroRevIterator<'a> {
+    type Item = (usize, u32);
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            let top = self.start + self.remaining - 1;
+            let limb_index = top / self.entries_per_limb;
+            let top_entry = top % self.entries_per_limb;
+            let bottom_entry = if limb_index == self.start / self.entries_per_limb {
+                self.start % self.entries_per_limb
+            } else {
+                0
+            };
+
+            // Mask out the entries that lie outside of bottom_entry..=top_entry
+            let high_bits = (top_entry + 1) * self.bit_length;
+            let mut mask = (1 as Limb)
+                .checked_shl(high_bits as u32)
+                .unwrap_or(0)
+                .wrapping_sub(1);
+            mask &= !(((1 as Limb) << (bottom_entry * self.bit_length)) - 1);
+
+            let limb = self.limbs[limb_index] & mask;
+            if limb == 0 {
+                self.remaining -= top_entry + 1 - bottom_entry;
+                continue;
+            }
+            let top_bit = (BITS_PER_LIMB - 1) - limb.leading_zeros() as usize;
+            let entry = top_bit / self.bit_length;
+            let value = (limb >> (entry * self.bit_length)) & self.bit_mask;
+
+            self.remaining = limb_index * self.entries_per_limb + entry - self.start;
+            return Some((self.remaining, value as u32));
+        }
+        None
+    }
+}
@@ -399,6 +399,35 @@ impl<T> OnceVec<T> {
     /// ```
     pub fn push(&self, value: T) -> usize {
         let ooo = self.lock();
+        self.push_locked(&ooo, value)
+    }
+
+    /// Push an element into the `index` position if this is the next position to be filled.
+    /// Otherwise, the vector is left untouched and the value is returned. Unlike
+    /// [`OnceVec::push_checked`], the comparison and the push happen under the same lock, so at
+    /// most one of several threads racing to fill the same position will succeed.
+    ///
+    /// # Example
+    /// ```
+    /// # use once::OnceVec;
+    /// let v = OnceVec::<u32>::new();
+    /// assert_eq!(v.try_push(1, 0), Ok(()));
+    /// assert_eq!(v.try_push(2, 0), Err(2));
+    /// assert_eq!(v.try_push(2, 2), Err(2));
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    pub fn try_push(&self, value: T, index: usize) -> Result<(), T> {
+        let ooo = self.lock();
+        if self.len.load(Ordering::Acquire) != index {
+            return Err(value);
+        }
+        self.push_locked(&ooo, value);
+        Ok(())
+    }
+
+    /// The implementation of [`OnceVec::push`]. The caller must hold the lock, which is witnessed
+    /// by the `ooo` argument.
+    fn push_locked(&self, ooo: &MutexGuard<OooTracker>, value: T) -> usize {
         assert!(ooo.0.is_empty());
         let old_len = self.len.load(Ordering::Acquire);
         let (page, index) = inner_index(old_len);
@@ -642,7 +671,9 @@ impl<T> IndexMut<u32> for OnceVec<T> {
 }
 
 unsafe impl<T: Send> Send for OnceVec<T> {}
-unsafe impl<T: Sync> Sync for OnceVec<T> {}
+// Values can be pushed through a shared reference, so sharing a `OnceVec` across threads moves
+// `T` across threads as well.
+unsafe impl<T: Send + Sync> Sync for OnceVec<T> {}
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct OnceBiVec<T> {
@@ -718,6 +749,14 @@ impl<T> OnceBiVec<T> {
         self.data.push(value) as i32 + self.min_degree
     }
 
+    /// See [`OnceVec::try_push`].
+    pub fn try_push(&self, value: T, index: i32) -> Result<(), T> {
+        match usize::try_from(index - self.min_degree) {
+            Ok(index) => self.data.try_push(value, index),
+            Err(_) => Err(value),
+        }
+    }
+
     /// See [`OnceVec::push_ooo`].
     pub fn push_ooo(&self, value: T, index: i32) -> std::ops::Range<i32> {
         let result = self
@@ -888,6 +927,80 @@ mod tests {
         }
     }
 
+    /// Push from multiple threads while other threads read the newly pushed values. Each value is a
+    /// vector whose entries are all equal, so a read of partially written data shows up as a
+    /// vector with distinct entries (or as a crash).
+    #[test]
+    fn test_concurrent_push() {
+        const NUM_WRITERS: usize = 4;
+        const NUM_READERS: usize = 4;
+        const NUM_PUSHES: usize = 1 << 12;
+        const TOTAL: usize = NUM_WRITERS * NUM_PUSHES;
+
+        let v: OnceVec<Vec<usize>> = OnceVec::new();
+
+        std::thread::scope(|scope| {
+            for writer in 0..NUM_WRITERS {
+                let v = &v;
+                scope.spawn(move || {
+                    for i in 0..NUM_PUSHES {
+                        v.push(vec![writer * NUM_PUSHES + i; 16]);
+                    }
+                });
+            }
+            for _ in 0..NUM_READERS {
+                scope.spawn(|| {
+                    let mut checked = 0;
+                    while checked < TOTAL {
+                        let len = v.len();
+                        for i in checked..len {
+                            let entry = &v[i];
+                            assert_eq!(entry.len(), 16);
+                            assert!(entry.iter().all(|&x| x == entry[0]), "Torn read at {i}");
+                        }
+                        checked = len;
+                    }
+                });
+            }
+        });
+
+        assert_eq!(v.len(), TOTAL);
+        let mut seen = vec![false; TOTAL];
+        for entry in v.iter() {
+            assert!(!std::mem::replace(&mut seen[entry[0]], true));
+        }
+    }
+
+    /// Two threads race to fill the same position. Exactly one of them succeeds, and the other
+    /// gets its value back.
+    #[test]
+    fn test_concurrent_try_push() {
+        for _ in 0..100 {
+            let v: OnceVec<usize> = OnceVec::new();
+            let barrier = std::sync::Barrier::new(2);
+
+            let results: Vec<Result<(), usize>> = std::thread::scope(|scope| {
+                let handles: Vec<_> = (0..2)
+                    .map(|i| {
+                        let (v, barrier) = (&v, &barrier);
+                        scope.spawn(move || {
+                            barrier.wait();
+                            v.try_push(i, 0)
+                        })
+                    })
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            assert_eq!(v.len(), 1);
+            match results[..] {
+                [Ok(()), Err(1)] => assert_eq!(v[0usize], 0),
+                [Err(0), Ok(())] => assert_eq!(v[0usize], 1),
+                _ => panic!("Unexpected results {results:?}"),
+            }
+        }
+    }
+
     #[test]
     fn test_drop_ooo() {
         let v: OnceVec<u32> = OnceVec::new();
@@ -31,6 +31,23 @@ impl SseqProfile for Adams {
     }
 }
 
+/// The Adams profile with the $x$-axis reversed. This is the profile of the $E_2$ page of a
+/// cohomologically graded complex, where $x = s - t$.
+pub struct CohomologicalAdams;
+
+impl SseqProfile for CohomologicalAdams {
+    const MIN_R: i32 = 2;
+    fn profile(r: i32, x: i32, y: i32) -> (i32, i32) {
+        (x + 1, y + r)
+    }
+    fn profile_inverse(r: i32, x: i32, y: i32) -> (i32, i32) {
+        (x - 1, y - r)
+    }
+    fn differential_length(_diff_x: i32, diff_y: i32) -> i32 {
+        diff_y
+    }
+}
+
 pub struct Product {
     pub x: i32,
     pub y: i32,
@@ -490,6 +507,17 @@ impl<P: SseqProfile> Sseq<P> {
         g.init(max_x - min_x, max_y)?;
         header(&mut g)?;
 
+        // Draw all the nodes first, since the lines may point in either direction depending on
+        // the profile, and the backends need both endpoints to exist.
+        for x in min_x..=max_x {
+            for y in self.range(x) {
+                let data = self.page_data(x, y).get_max(r);
+                if !data.is_empty() {
+                    g.node(x - min_x, y, data.dimension())?;
+                }
+            }
+        }
+
         for x in min_x..=max_x {
             for y in self.range(x) {
                 let data = self.page_data(x, y).get_max(r);
@@ -497,9 +525,7 @@ impl<P: SseqProfile> Sseq<P> {
                     continue;
                 }
 
-                g.node(x - min_x, y, data.dimension())?;
-
-                // Now add the products hitting this bidegree
+                // Add the products hitting this bidegree
                 for (name, prod) in products.clone() {
                     let source_x = x - prod.x;
                     let source_y = y - prod.y;
@@ -528,7 +554,7 @@ impl<P: SseqProfile> Sseq<P> {
                 // Finally add the differentials
                 if differentials {
                     let (tx, ty) = P::profile(r, x, y);
-                    if tx < 0 {
+                    if !self.defined(tx, ty) {
                         continue;
                     }
                     let d = self.differentials(x, y);
@@ -245,10 +245,14 @@ fn main() {
     let resolution = Arc::new(resolution);
 
     // Create a ResolutionHomomorphism object
-    let hom = ResolutionHomomorphism::new(String::new(), cc, resolution, 0, 0);
-
     // We have to explicitly tell it what to do at (0, 0)
-    hom.extend_step(0, 0, Some(&Matrix::from_vec(TWO, &[vec![1]])));
+    let hom = ResolutionHomomorphism::new_from_matrix_on_s0(
+        String::new(),
+        cc,
+        resolution,
+        0,
+        &Matrix::from_vec(TWO, &[vec![1]]),
+    );
     hom.extend_all();
 
     // Now print the results
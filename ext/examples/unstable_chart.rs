@@ -62,13 +62,7 @@ fn main() -> anyhow::Result<()> {
 
         println!("\\begin{{figure}}[p]\\centering");
 
-        let sseq = res.to_sseq();
-        let products = products
-            .iter()
-            .map(|(name, op_deg, op_idx)| {
-                (name.clone(), res.filtration_one_products(*op_deg, *op_idx))
-            })
-            .collect::<Vec<_>>();
+        let (sseq, products) = res.to_sseq_with_products(&products);
 
         sseq.write_to_graph(
             TikzBackend::new(std::io::stdout()),
@@ -1,10 +1,10 @@
-use crate::chain_complex::{ChainComplex, FreeChainComplex};
+use crate::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
 use crate::resolution_homomorphism::ResolutionHomomorphism;
 use crate::save::SaveKind;
 use algebra::module::homomorphism::{FreeModuleHomomorphism, ModuleHomomorphism};
 use algebra::module::Module;
 use fp::prime::ValidPrime;
-use fp::vector::FpVector;
+use fp::vector::{FpVector, SliceMut};
 use once::OnceBiVec;
 
 use std::path::{Path, PathBuf};
@@ -14,19 +14,40 @@ use std::sync::Mutex;
 #[cfg(feature = "concurrent")]
 use rayon::prelude::*;
 
+/// The chain map a [`ChainHomotopy`] is a null-homotopy of.
+enum HomotopyMaps<
+    S: FreeChainComplex,
+    T: FreeChainComplex<Algebra = S::Algebra> + Sync,
+    U: ChainComplex<Algebra = S::Algebra> + Sync,
+> {
+    /// The composite `right ∘ left`, which is assumed to be null-homotopic.
+    Composite {
+        left: Arc<ResolutionHomomorphism<S, T>>,
+        right: Arc<ResolutionHomomorphism<T, U>>,
+    },
+    /// The difference `f - g` of two homotopic chain maps, where `g` is `None` for a
+    /// null-homotopy of `f`.
+    Difference {
+        f: Arc<ResolutionHomomorphism<S, U>>,
+        g: Option<Arc<ResolutionHomomorphism<S, U>>>,
+    },
+}
+
 // Another instance of https://github.com/rust-lang/rust/issues/91380
 /// A chain homotopy from $f to g$, or equivalently a null-homotopy of $h = f - g$. A chain map is
 /// a priori a collection of free module homomorphisms. However, instead of providing
 /// FreeModuleHomomorphism objects, the user is expected to give a function that computes the value
 /// of $h$ on each generator.
+///
+/// This is either a null-homotopy of a composite of two chain maps (see [`ChainHomotopy::new`]),
+/// or a homotopy between two chain maps (see [`ChainHomotopy::from_maps`]).
 #[doc(hidden)]
 pub struct ChainHomotopy<
     S: FreeChainComplex,
     T: FreeChainComplex<Algebra = S::Algebra> + Sync,
     U: ChainComplex<Algebra = S::Algebra> + Sync,
 > {
-    left: Arc<ResolutionHomomorphism<S, T>>,
-    right: Arc<ResolutionHomomorphism<T, U>>,
+    maps: HomotopyMaps<S, T, U>,
     lock: Mutex<()>,
     /// Homotopies, indexed by the filtration of the target of f - g.
     homotopies: OnceBiVec<Arc<FreeModuleHomomorphism<U::Module>>>,
@@ -60,52 +81,126 @@ impl<
         assert!(Arc::ptr_eq(&left.target, &right.source));
         Self {
             homotopies: OnceBiVec::new((left.shift_s + right.shift_s) as i32 - 1),
-            left,
-            right,
+            maps: HomotopyMaps::Composite { left, right },
             lock: Mutex::new(()),
             save_dir,
         }
     }
 
     pub fn prime(&self) -> ValidPrime {
-        self.left.source.prime()
+        self.source().prime()
     }
 
     pub fn shift_s(&self) -> u32 {
-        self.left.shift_s + self.right.shift_s
+        match &self.maps {
+            HomotopyMaps::Composite { left, right } => left.shift_s + right.shift_s,
+            HomotopyMaps::Difference { f, .. } => f.shift_s,
+        }
     }
 
     pub fn shift_t(&self) -> i32 {
-        self.left.shift_t + self.right.shift_t
+        match &self.maps {
+            HomotopyMaps::Composite { left, right } => left.shift_t + right.shift_t,
+            HomotopyMaps::Difference { f, .. } => f.shift_t,
+        }
     }
 
+    /// The first chain map of the composite. This panics if the homotopy was constructed by
+    /// [`ChainHomotopy::from_maps`].
     pub fn left(&self) -> Arc<ResolutionHomomorphism<S, T>> {
-        Arc::clone(&self.left)
+        match &self.maps {
+            HomotopyMaps::Composite { left, .. } => Arc::clone(left),
+            HomotopyMaps::Difference { .. } => panic!("Homotopy is not of a composite"),
+        }
     }
 
+    /// The second chain map of the composite. This panics if the homotopy was constructed by
+    /// [`ChainHomotopy::from_maps`].
     pub fn right(&self) -> Arc<ResolutionHomomorphism<T, U>> {
-        Arc::clone(&self.right)
+        match &self.maps {
+            HomotopyMaps::Composite { right, .. } => Arc::clone(right),
+            HomotopyMaps::Difference { .. } => panic!("Homotopy is not of a composite"),
+        }
+    }
+
+    fn source(&self) -> &Arc<S> {
+        match &self.maps {
+            HomotopyMaps::Composite { left, .. } => &left.source,
+            HomotopyMaps::Difference { f, .. } => &f.source,
+        }
     }
 
-    /// Lift maps so that the chain *homotopy* is defined on `(max_source_s, max_source_t)`.
+    fn target(&self) -> &Arc<U> {
+        match &self.maps {
+            HomotopyMaps::Composite { right, .. } => &right.target,
+            HomotopyMaps::Difference { f, .. } => &f.target,
+        }
+    }
+
+    /// The smallest `s` on which the homotopy is defined.
+    fn min_source_s(&self) -> u32 {
+        self.shift_s().saturating_sub(1)
+    }
+
+    /// Add the value of the chain map we are null-homotoping on the `idx`th generator of the
+    /// source in bidegree `(source_s, source_t)` to `result`.
+    fn apply_map(&self, mut result: SliceMut, source_s: u32, source_t: i32, idx: usize) {
+        let p = self.prime();
+        match &self.maps {
+            HomotopyMaps::Composite { left, right } => {
+                right.get_map(source_s - left.shift_s).apply(
+                    result,
+                    1,
+                    source_t - left.shift_t,
+                    left.get_map(source_s).output(source_t, idx).as_slice(),
+                );
+            }
+            HomotopyMaps::Difference { f, g } => {
+                for (map, c) in std::iter::once((f, 1)).chain(g.iter().map(|g| (g, *p - 1))) {
+                    let map = map.get_map(source_s);
+                    let v = map.output(source_t, idx);
+                    result.slice_mut(0, v.len()).add(v.as_slice(), c);
+                }
+            }
+        }
+    }
+
+    /// Lift maps so that the chain *homotopy* is defined on `(max_source_s, max_source_t)`. This
+    /// panics if the chain maps of a homotopy constructed by [`ChainHomotopy::from_maps`] turn out
+    /// not to be homotopic. See [`ChainHomotopy::try_extend`] for a fallible version.
     pub fn extend(&self, max_source_s: u32, max_source_t: i32) {
+        self.try_extend(max_source_s, max_source_t)
+            .unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// Lift maps so that the chain homotopy is defined on as many bidegrees as possible. This
+    /// panics if the chain maps of a homotopy constructed by [`ChainHomotopy::from_maps`] turn out
+    /// not to be homotopic. See [`ChainHomotopy::try_extend_all`] for a fallible version.
+    pub fn extend_all(&self) {
+        self.try_extend_all().unwrap_or_else(|e| panic!("{e}"));
+    }
+
+    /// As [`ChainHomotopy::extend`], but returns an error if the chain maps of a homotopy
+    /// constructed by [`ChainHomotopy::from_maps`] differ after composing with the augmentation on
+    /// one of the new bidegrees. The bidegrees lifted before the error is found are kept.
+    pub fn try_extend(&self, max_source_s: u32, max_source_t: i32) -> anyhow::Result<()> {
         self.extend_profile(max_source_s + 1, &|s| {
             max_source_t - (max_source_s - s) as i32 + 1
-        });
+        })
     }
 
-    /// Lift maps so that the chain homotopy is defined on as many bidegrees as possible
-    pub fn extend_all(&self) {
+    /// As [`ChainHomotopy::extend_all`], but returns an error in the same cases as
+    /// [`ChainHomotopy::try_extend`].
+    pub fn try_extend_all(&self) -> anyhow::Result<()> {
         let max_source_s = std::cmp::min(
-            self.left.source.next_homological_degree(),
-            self.right.target.next_homological_degree() + self.shift_s(),
+            self.source().next_homological_degree(),
+            self.target().next_homological_degree() + self.shift_s(),
         );
 
         let max_source_t = |s| {
             std::cmp::min(
-                self.left.source.module(s).max_computed_degree() + 1,
-                self.right
-                    .target
+                self.source().module(s).max_computed_degree() + 1,
+                self.target()
                     .module(s + 1 - self.shift_s())
                     .max_computed_degree()
                     + self.shift_t()
@@ -113,7 +208,7 @@ impl<
             )
         };
 
-        self.extend_profile(max_source_s, &max_source_t);
+        self.extend_profile(max_source_s, &max_source_t)
     }
 
     /// Initialize self.homotopies to contain [`FreeModuleHomomorphisms`]s up to but excluding
@@ -123,19 +218,23 @@ impl<
         self.homotopies.extend(max_source_s as i32 - 1, |s| {
             let s = s as u32;
             Arc::new(FreeModuleHomomorphism::new(
-                self.left.source.module(s),
-                self.right.target.module(s + 1 - self.shift_s()),
+                self.source().module(s),
+                self.target().module(s + 1 - self.shift_s()),
                 self.shift_t(),
             ))
         });
     }
 
     /// Exclusive bounds
-    fn extend_profile(&self, max_source_s: u32, max_source_t: &(impl Fn(u32) -> i32 + Sync)) {
-        let shift_s = self.shift_s();
-
-        if max_source_s == shift_s - 1 {
-            return;
+    fn extend_profile(
+        &self,
+        max_source_s: u32,
+        max_source_t: &(impl Fn(u32) -> i32 + Sync),
+    ) -> anyhow::Result<()> {
+        let min_source_s = self.min_source_s();
+
+        if max_source_s <= min_source_s {
+            return Ok(());
         }
 
         let _lock = self.lock.lock();
@@ -144,33 +243,46 @@ impl<
 
         #[cfg(not(feature = "concurrent"))]
         {
-            for source_s in shift_s - 1..max_source_s {
+            for source_s in min_source_s..max_source_s {
                 for source_t in
                     self.homotopies[source_s as i32].next_degree()..max_source_t(source_s)
                 {
-                    self.extend_step(source_s, source_t);
+                    self.extend_step(source_s, source_t)?;
                 }
             }
+            Ok(())
         }
 
         #[cfg(feature = "concurrent")]
         {
             let min_source_t = std::cmp::min(
-                self.left.source.min_degree(),
-                self.right.target.min_degree() + self.shift_t(),
+                self.source().min_degree(),
+                self.target().min_degree() + self.shift_t(),
             );
 
+            // Bidegrees that depend on a failed one are not lifted, since the failed one returns an
+            // empty range.
+            let error = Mutex::new(None);
             crate::utils::iter_s_t(
-                &|s, t| self.extend_step(s, t),
-                shift_s - 1,
+                &|s, t| {
+                    self.extend_step(s, t).unwrap_or_else(|e| {
+                        error.lock().unwrap().get_or_insert(e);
+                        t..t
+                    })
+                },
+                min_source_s,
                 min_source_t,
                 max_source_s,
                 max_source_t,
             );
+            match error.into_inner().unwrap() {
+                Some(e) => Err(e),
+                None => Ok(()),
+            }
         }
     }
 
-    fn extend_step(&self, source_s: u32, source_t: i32) -> std::ops::Range<i32> {
+    fn extend_step(&self, source_s: u32, source_t: i32) -> anyhow::Result<std::ops::Range<i32>> {
         let p = self.prime();
         let shift_s = self.shift_s();
         let shift_t = self.shift_t();
@@ -178,16 +290,54 @@ impl<
         let target_t = source_t - shift_t;
 
         if self.homotopies[source_s as i32].next_degree() > source_t {
-            return source_t..source_t + 1;
+            return Ok(source_t..source_t + 1);
         }
 
         let num_gens = self
-            .left
-            .source
+            .source()
             .module(source_s)
             .number_of_gens_in_degree(source_t);
 
-        let target_dim = self.right.target.module(target_s).dimension(target_t);
+        let target_dim = self.target().module(target_s).dimension(target_t);
+
+        let f = |i| {
+            let mut scratch =
+                FpVector::new(p, self.target().module(target_s - 1).dimension(target_t));
+            self.apply_map(scratch.as_slice_mut(), source_s, source_t, i);
+
+            // If the shift is zero, there is no homotopy in source degree -1.
+            if source_s > 0 {
+                self.homotopies[source_s as i32 - 1].apply(
+                    scratch.as_slice_mut(),
+                    *p - 1,
+                    source_t,
+                    self.source()
+                        .differential(source_s)
+                        .output(source_t, i)
+                        .as_slice(),
+                );
+            }
+
+            #[cfg(debug_assertions)]
+            if target_s > 1 && self.target().has_computed_bidegree(target_s - 2, target_t) {
+                let mut r =
+                    FpVector::new(p, self.target().module(target_s - 2).dimension(target_t));
+                self.target().differential(target_s - 1).apply(
+                    r.as_slice_mut(),
+                    1,
+                    target_t,
+                    scratch.as_slice(),
+                );
+                assert!(
+                    r.is_zero(),
+                    "Failed to lift at (target_s, target_t) = ({}, {})",
+                    target_s - 1,
+                    target_t
+                );
+            }
+
+            scratch
+        };
 
         // Default to the zero homotopy for the bottom-most homotopy. For computing normal Massey
         // products, any choice works, and it is conventional to choose zero. For secondary Massey
@@ -195,14 +345,18 @@ impl<
         // these values.
         if target_s == 0 || target_dim == 0 || num_gens == 0 {
             let outputs = vec![FpVector::new(p, target_dim); num_gens];
-            return self.homotopies[source_s as i32]
-                .add_generators_from_rows_ooo(source_t, outputs);
+            if target_s == 1 && matches!(self.maps, HomotopyMaps::Difference { .. }) {
+                let scratches: Vec<FpVector> = (0..num_gens).map(f).collect();
+                self.check_difference(source_s, source_t, &outputs, &scratches)?;
+            }
+            return Ok(
+                self.homotopies[source_s as i32].add_generators_from_rows_ooo(source_t, outputs)
+            );
         }
 
         if let Some(dir) = &self.save_dir {
             if let Some(mut f) = self
-                .left
-                .source
+                .source()
                 .save_file(SaveKind::ChainHomotopy, source_s, source_t)
                 .open_file(dir.to_owned())
             {
@@ -210,64 +364,13 @@ impl<
                 for _ in 0..num_gens {
                     outputs.push(FpVector::from_bytes(p, target_dim, &mut f).unwrap());
                 }
-                return self.homotopies[source_s as i32]
-                    .add_generators_from_rows_ooo(source_t, outputs);
+                return Ok(self.homotopies[source_s as i32]
+                    .add_generators_from_rows_ooo(source_t, outputs));
             }
         }
 
         let mut outputs = vec![FpVector::new(p, target_dim); num_gens];
 
-        let f = |i| {
-            let mut scratch = FpVector::new(
-                p,
-                self.right.target.module(target_s - 1).dimension(target_t),
-            );
-            self.right.get_map(source_s - self.left.shift_s).apply(
-                scratch.as_slice_mut(),
-                1,
-                source_t - self.left.shift_t,
-                self.left.get_map(source_s).output(source_t, i).as_slice(),
-            );
-
-            self.homotopies[source_s as i32 - 1].apply(
-                scratch.as_slice_mut(),
-                *p - 1,
-                source_t,
-                self.left
-                    .source
-                    .differential(source_s)
-                    .output(source_t, i)
-                    .as_slice(),
-            );
-
-            #[cfg(debug_assertions)]
-            if target_s > 1
-                && self
-                    .right
-                    .target
-                    .has_computed_bidegree(target_s - 2, target_t)
-            {
-                let mut r = FpVector::new(
-                    p,
-                    self.right.target.module(target_s - 2).dimension(target_t),
-                );
-                self.right.target.differential(target_s - 1).apply(
-                    r.as_slice_mut(),
-                    1,
-                    target_t,
-                    scratch.as_slice(),
-                );
-                assert!(
-                    r.is_zero(),
-                    "Failed to lift at (target_s, target_t) = ({}, {})",
-                    target_s - 1,
-                    target_t
-                );
-            }
-
-            scratch
-        };
-
         #[cfg(not(feature = "concurrent"))]
         let scratches: Vec<FpVector> = (0..num_gens).map(f).collect();
 
@@ -275,24 +378,52 @@ impl<
         let scratches: Vec<FpVector> = (0..num_gens).into_par_iter().map(f).collect();
 
         assert!(U::apply_quasi_inverse(
-            &*self.right.target,
+            &**self.target(),
             &mut outputs,
             target_s,
             target_t,
             &scratches,
         ));
 
+        self.check_difference(source_s, source_t, &outputs, &scratches)?;
+
         if let Some(dir) = &self.save_dir {
             let mut f = self
-                .left
-                .source
+                .source()
                 .save_file(SaveKind::ChainHomotopy, source_s, source_t)
                 .create_file(dir.to_owned(), false);
             for row in &outputs {
                 row.to_bytes(&mut f).unwrap();
             }
         }
-        self.homotopies[source_s as i32].add_generators_from_rows_ooo(source_t, outputs)
+        Ok(self.homotopies[source_s as i32].add_generators_from_rows_ooo(source_t, outputs))
+    }
+
+    /// Check that the homotopy we lifted in target filtration 1 is a homotopy from `f` to `g`.
+    /// [`ChainHomotopy::from_maps`] can only check this on the bidegrees where `f` and `g` were
+    /// defined at the time, so the remaining ones are checked as they are lifted.
+    fn check_difference(
+        &self,
+        source_s: u32,
+        source_t: i32,
+        outputs: &[FpVector],
+        scratches: &[FpVector],
+    ) -> anyhow::Result<()> {
+        if source_s != self.shift_s() || !matches!(self.maps, HomotopyMaps::Difference { .. }) {
+            return Ok(());
+        }
+        let p = self.prime();
+        let target_t = source_t - self.shift_t();
+        let d = self.target().differential(1);
+        for (i, (output, scratch)) in outputs.iter().zip(scratches).enumerate() {
+            let mut r = FpVector::new(p, scratch.len());
+            d.apply(r.as_slice_mut(), 1, target_t, output.as_slice());
+            anyhow::ensure!(
+                r == *scratch,
+                "Chain maps are not homotopic: they differ on generator {i} in (s, t) = ({source_s}, {source_t})"
+            );
+        }
+        Ok(())
     }
 
     pub fn homotopy(&self, source_s: u32) -> Arc<FreeModuleHomomorphism<U::Module>> {
@@ -303,3 +434,323 @@ impl<
         self.save_dir.as_deref()
     }
 }
+
+impl<S, U> ChainHomotopy<S, U, U>
+where
+    S: FreeChainComplex,
+    U: FreeChainComplex<Algebra = S::Algebra> + AugmentedChainComplex + Sync,
+{
+    /// Construct a chain homotopy from `f` to `g`, i.e. a map $H$ with $dH + Hd = f - g$. The
+    /// homotopy is computed degree by degree using the quasi-inverses of the target, as with
+    /// [`ChainHomotopy::new`], when [`ChainHomotopy::extend`] or [`ChainHomotopy::extend_all`] is
+    /// called.
+    ///
+    /// As for [`ChainHomotopy::new`], the bottom-most homotopy is chosen to be zero. Thus this
+    /// finds a homotopy if and only if `f` and `g` agree after composing with the augmentation of
+    /// the target, which is checked on the bidegrees where both maps are currently defined. If
+    /// they don't, this returns an error. Bidegrees on which `f` and `g` are only defined later are
+    /// checked as the homotopy is extended, and [`ChainHomotopy::try_extend`] returns an error if
+    /// they disagree there. When the source
+    /// is a minimal resolution and the target resolves $\mathbb{F}_p$, this is the same as `f` and
+    /// `g` being homotopic.
+    pub fn from_maps(
+        f: Arc<ResolutionHomomorphism<S, U>>,
+        g: Arc<ResolutionHomomorphism<S, U>>,
+    ) -> anyhow::Result<Self> {
+        assert!(Arc::ptr_eq(&f.source, &g.source));
+        assert!(Arc::ptr_eq(&f.target, &g.target));
+        assert_eq!((f.shift_s, f.shift_t), (g.shift_s, g.shift_t));
+
+        Self::from_difference(f, Some(g))
+    }
+
+    /// See [`ChainHomotopy::from_maps`]. If `g` is `None`, this is a null-homotopy of `f`.
+    fn from_difference(
+        f: Arc<ResolutionHomomorphism<S, U>>,
+        g: Option<Arc<ResolutionHomomorphism<S, U>>>,
+    ) -> anyhow::Result<Self> {
+        let p = f.source.prime();
+        let (shift_s, shift_t) = (f.shift_s, f.shift_t);
+
+        let maps: Vec<_> = std::iter::once((&f, 1))
+            .chain(g.iter().map(|g| (g, *p - 1)))
+            .collect();
+
+        if maps
+            .iter()
+            .all(|(map, _)| map.next_homological_degree() > shift_s as i32)
+        {
+            let maps: Vec<_> = maps
+                .into_iter()
+                .map(|(map, c)| (map.get_map(shift_s), c))
+                .collect();
+            let augmentation = f.target.chain_map(0);
+            let source = f.source.module(shift_s);
+            let max_t = maps.iter().map(|(map, _)| map.next_degree()).min().unwrap();
+
+            for t in maps[0].0.min_degree()..max_t {
+                let target_t = t - shift_t;
+                let mut diff = FpVector::new(p, f.target.module(0).dimension(target_t));
+                let mut result = FpVector::new(p, augmentation.target().dimension(target_t));
+                for i in 0..source.number_of_gens_in_degree(t) {
+                    diff.set_to_zero();
+                    for (map, c) in &maps {
+                        let v = map.output(t, i);
+                        diff.slice_mut(0, v.len()).add(v.as_slice(), *c);
+                    }
+                    result.set_to_zero();
+                    augmentation.apply(result.as_slice_mut(), 1, target_t, diff.as_slice());
+                    if !result.is_zero() {
+                        return Err(anyhow::anyhow!(
+                            "Chain maps are not homotopic: they differ on generator {i} in (s, t) = ({shift_s}, {t})"
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            homotopies: OnceBiVec::new(shift_s.saturating_sub(1) as i32),
+            maps: HomotopyMaps::Difference { f, g },
+            lock: Mutex::new(()),
+            save_dir: None,
+        })
+    }
+
+    /// Construct a null-homotopy of `f`, i.e. a chain homotopy from `f` to the zero map, and
+    /// extend it to all bidegrees where it can be defined. This returns `None` if `f` is not
+    /// null-homotopic on these bidegrees. If `f` is extended further afterwards, use
+    /// [`ChainHomotopy::try_extend`] to extend the homotopy, which detects whether `f` is
+    /// null-homotopic on the new bidegrees.
+    ///
+    /// This is the building block for Massey products, where `f` is a lift of a product that
+    /// vanishes in homology.
+    pub fn nullhomotopy(f: Arc<ResolutionHomomorphism<S, U>>) -> Option<Self> {
+        let homotopy = Self::from_difference(f, None).ok()?;
+        homotopy.try_extend_all().ok()?;
+        Some(homotopy)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::resolution::Resolution;
+    use crate::utils::construct_standard;
+    use fp::vector::FpVector;
+
+    type Res = Resolution<crate::CCC>;
+
+    /// Check that $dH + Hd = f - g$ wherever the homotopy is defined.
+    fn check_homotopy(
+        h: &ChainHomotopy<Res, Res, Res>,
+        f: &ResolutionHomomorphism<Res, Res>,
+        g: &ResolutionHomomorphism<Res, Res>,
+    ) {
+        let p = h.prime();
+        let res = h.source();
+        let (shift_s, shift_t) = (f.shift_s, f.shift_t);
+        for s in h.homotopies.min_degree() as u32 + 1..h.homotopies.len() as u32 {
+            let hom = h.homotopy(s);
+            let prev_hom = h.homotopy(s - 1);
+            for t in hom.min_degree()..std::cmp::min(hom.next_degree(), prev_hom.next_degree()) {
+                let target_t = t - shift_t;
+                let dim = res.module(s - shift_s).dimension(target_t);
+                for i in 0..res.module(s).number_of_gens_in_degree(t) {
+                    let mut lhs = FpVector::new(p, dim);
+                    res.differential(s + 1 - shift_s).apply(
+                        lhs.as_slice_mut(),
+                        1,
+                        target_t,
+                        hom.output(t, i).as_slice(),
+                    );
+                    prev_hom.apply(
+                        lhs.as_slice_mut(),
+                        1,
+                        t,
+                        res.differential(s).output(t, i).as_slice(),
+                    );
+
+                    let mut rhs = FpVector::new(p, dim);
+                    rhs.slice_mut(0, f.get_map(s).output(t, i).len())
+                        .add(f.get_map(s).output(t, i).as_slice(), 1);
+                    rhs.slice_mut(0, g.get_map(s).output(t, i).len())
+                        .add(g.get_map(s).output(t, i).as_slice(), *p - 1);
+
+                    assert_eq!(lhs, rhs, "(s, t, i) = ({s}, {t}, {i})");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_maps() {
+        let res: Arc<Res> = Arc::new(construct_standard("S_2", None).unwrap());
+        res.compute_through_stem(6, 12);
+
+        let h0 = |class: &[u32]| {
+            let f = ResolutionHomomorphism::from_class(
+                String::new(),
+                Arc::clone(&res),
+                Arc::clone(&res),
+                1,
+                1,
+                class,
+            );
+            f.extend_all();
+            Arc::new(f)
+        };
+
+        let f = h0(&[1]);
+
+        // The homotopy from f to itself is zero.
+        let h = ChainHomotopy::from_maps(Arc::clone(&f), Arc::clone(&f)).unwrap();
+        h.extend_all();
+        check_homotopy(&h, &f, &f);
+        for s in 0..h.homotopies.len() as u32 {
+            let hom = h.homotopy(s);
+            for t in hom.min_degree()..hom.next_degree() {
+                for i in 0..res.module(s).number_of_gens_in_degree(t) {
+                    assert!(hom.output(t, i).is_zero());
+                }
+            }
+        }
+
+        // Modify f by a boundary on the generators of filtration 1.
+        let p = res.prime();
+        let g =
+            ResolutionHomomorphism::new(String::new(), Arc::clone(&res), Arc::clone(&res), 1, 1);
+        for t in 1..res.module(1).max_computed_degree() + 1 {
+            let outputs = (0..res.module(1).number_of_gens_in_degree(t))
+                .map(|i| {
+                    let mut v = f.get_map(1).output(t, i).clone();
+                    if res.module(1).dimension(t - 1) > 0 {
+                        let mut y = FpVector::new(p, res.module(1).dimension(t - 1));
+                        y.set_entry(0, 1);
+                        res.differential(1)
+                            .apply(v.as_slice_mut(), 1, t - 1, y.as_slice());
+                    }
+                    v
+                })
+                .collect();
+            g.extend_step_raw(1, t, Some(outputs));
+        }
+        g.extend_all();
+        let g = Arc::new(g);
+
+        let h = ChainHomotopy::from_maps(Arc::clone(&f), Arc::clone(&g)).unwrap();
+        h.extend_all();
+        check_homotopy(&h, &f, &g);
+        let hom = h.homotopy(1);
+        assert!((hom.min_degree()..hom.next_degree())
+            .any(|t| (0..res.module(1).number_of_gens_in_degree(t))
+                .any(|i| !hom.output(t, i).is_zero())));
+
+        // f is not homotopic to the zero map
+        let zero = h0(&[0]);
+        let err = ChainHomotopy::from_maps(Arc::clone(&f), zero)
+            .err()
+            .unwrap()
+            .to_string();
+        assert_eq!(
+            err,
+            "Chain maps are not homotopic: they differ on generator 0 in (s, t) = (1, 1)"
+        );
+    }
+
+    #[test]
+    fn test_from_maps_extended() {
+        let res: Arc<Res> = Arc::new(construct_standard("S_2", None).unwrap());
+        res.compute_through_stem(6, 12);
+
+        let f = ResolutionHomomorphism::from_class(
+            String::new(),
+            Arc::clone(&res),
+            Arc::clone(&res),
+            1,
+            1,
+            &[1],
+        );
+        f.extend_all();
+        let zero = Arc::new(ResolutionHomomorphism::new(
+            String::new(),
+            Arc::clone(&res),
+            Arc::clone(&res),
+            1,
+            1,
+        ));
+
+        // The zero map is not defined yet, so from_maps cannot tell that f is not null-homotopic.
+        let h = ChainHomotopy::from_maps(Arc::new(f), Arc::clone(&zero)).unwrap();
+        zero.extend_all();
+        assert_eq!(
+            h.try_extend_all().unwrap_err().to_string(),
+            "Chain maps are not homotopic: they differ on generator 0 in (s, t) = (1, 1)"
+        );
+    }
+
+    #[test]
+    fn test_nullhomotopy() {
+        let res: Arc<Res> = Arc::new(construct_standard("S_2", None).unwrap());
+        res.compute_through_stem(6, 12);
+        let p = res.prime();
+
+        let map = |shift_t: i32| {
+            let f = ResolutionHomomorphism::from_class(
+                String::new(),
+                Arc::clone(&res),
+                Arc::clone(&res),
+                1,
+                shift_t,
+                &[1],
+            );
+            f.extend_all();
+            f
+        };
+        let h0 = map(1);
+        let h1 = map(2);
+
+        // A chain map lifting the composite `right ∘ left` on the bottom filtration.
+        let product = |left: &ResolutionHomomorphism<Res, Res>,
+                       right: &ResolutionHomomorphism<Res, Res>| {
+            let shift_t = left.shift_t + right.shift_t;
+            let f = ResolutionHomomorphism::new(
+                String::new(),
+                Arc::clone(&res),
+                Arc::clone(&res),
+                2,
+                shift_t,
+            );
+            let left_map = left.get_map(2);
+            let right_map = right.get_map(1);
+            for t in shift_t..left_map.next_degree() {
+                let dim = res.module(0).dimension(t - shift_t);
+                let outputs = (0..res.module(2).number_of_gens_in_degree(t))
+                    .map(|i| {
+                        let mut v = FpVector::new(p, dim);
+                        right_map.apply(
+                            v.as_slice_mut(),
+                            1,
+                            t - left.shift_t,
+                            left_map.output(t, i).as_slice(),
+                        );
+                        v
+                    })
+                    .collect();
+                f.extend_step_raw(2, t, Some(outputs));
+            }
+            f.extend_all();
+            Arc::new(f)
+        };
+
+        let h0h1 = product(&h0, &h1);
+        let h = res.nullhomotopy(&h0h1).unwrap();
+        let zero =
+            ResolutionHomomorphism::new(String::new(), Arc::clone(&res), Arc::clone(&res), 2, 3);
+        zero.extend_all();
+        check_homotopy(&h, &h0h1, &zero);
+
+        // h_0^2 is nonzero
+        assert!(res.nullhomotopy(&product(&h0, &h0)).is_none());
+    }
+}
@@ -1,6 +1,12 @@
-use crate::chain_complex::{AugmentedChainComplex, BoundedChainComplex, ChainComplex};
-use algebra::module::homomorphism::{FullModuleHomomorphism, ModuleHomomorphism, ZeroHomomorphism};
-use algebra::module::{Module, ZeroModule};
+use crate::chain_complex::{AugmentedChainComplex, BoundedChainComplex, ChainComplex, ChainMap};
+use algebra::module::homomorphism::{
+    FullModuleHomomorphism, IdentityHomomorphism, ModuleHomomorphism, ZeroHomomorphism,
+};
+use algebra::module::{FDModule, Module, ZeroModule};
+use algebra::Bialgebra;
+use bivec::BiVec;
+use fp::matrix::{Matrix, Subquotient};
+use fp::vector::FpVector;
 use std::sync::Arc;
 
 pub struct FiniteChainComplex<M, F = FullModuleHomomorphism<M>>
@@ -44,9 +50,22 @@ where
         }
     }
 
+    /// The chain complex with `module` in degree 0 and the zero module in all other degrees.
     pub fn ccdz(module: Arc<M>) -> Self {
         Self::new(vec![module], vec![])
     }
+
+    /// The chain complex [`Self::ccdz`] of `module`, augmented over itself by the identity map.
+    /// This is the module viewed as a complex together with its augmentation, so a resolution of
+    /// it is a resolution of `module`.
+    pub fn from_module(module: Arc<M>) -> FiniteAugmentedChainComplex<M, F, F, Self>
+    where
+        F: IdentityHomomorphism<M>,
+    {
+        let target = Arc::new(Self::ccdz(Arc::clone(&module)));
+        let augmentation = Arc::new(F::identity_homomorphism(Arc::clone(&module)));
+        Self::ccdz(module).augment(target, vec![augmentation])
+    }
 }
 
 impl<M, F> FiniteChainComplex<M, F>
@@ -76,6 +95,63 @@ where
             )));
         }
     }
+
+    /// Splice `top` on top of `bottom`. If `bottom` is $B_0 \leftarrow \cdots \leftarrow B_{m -
+    /// 1}$ and `top` is $T_0 \leftarrow \cdots \leftarrow T_{n - 1}$, then the result is
+    /// $$ B_0 \leftarrow \cdots \leftarrow B_{m - 1} \leftarrow T_0 \leftarrow \cdots \leftarrow
+    /// T_{n - 1}, $$
+    /// where the differential $T_0 \to B_{m - 1}$ is the single map in `connecting`. Since it
+    /// becomes a differential, `connecting` must lower the homological degree by one, i.e. have
+    /// `s_shift = 1`, and preserve the internal degree. It is up to the caller to ensure that its
+    /// composites with the neighbouring differentials vanish.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either complex is empty, if `connecting` is not a single map from the bottom
+    /// module of `top` to the top module of `bottom`, or if its shifts are not as above.
+    pub fn splice(top: &Self, bottom: &Self, connecting: &ChainMap<F>) -> Self
+    where
+        F: Clone,
+    {
+        assert!(!top.modules.is_empty() && !bottom.modules.is_empty());
+        assert_eq!(
+            connecting.s_shift, 1,
+            "Connecting map must lower the homological degree by one"
+        );
+        let [d] = &connecting.chain_maps[..] else {
+            panic!("Connecting map must consist of a single homomorphism");
+        };
+        assert_eq!(
+            d.degree_shift(),
+            0,
+            "Connecting map must preserve the internal degree"
+        );
+        assert!(Arc::ptr_eq(&d.source(), &top.modules[0]));
+        assert!(Arc::ptr_eq(
+            &d.target(),
+            &bottom.modules[bottom.modules.len() - 1]
+        ));
+
+        let zero_module = Arc::clone(&bottom.zero_module);
+        let modules: Vec<Arc<M>> = bottom.modules.iter().chain(&top.modules).cloned().collect();
+
+        // Drop the zero differential out of the top of `bottom` and the one into the bottom of
+        // `top`.
+        let mut differentials = bottom.differentials[..bottom.modules.len()].to_vec();
+        differentials.push(Arc::new(d.clone()));
+        differentials.extend_from_slice(&top.differentials[1..top.modules.len()]);
+        differentials.push(Arc::new(F::zero_homomorphism(
+            Arc::clone(&zero_module),
+            Arc::clone(&modules[modules.len() - 1]),
+            0,
+        )));
+
+        Self {
+            modules,
+            zero_module,
+            differentials,
+        }
+    }
 }
 
 impl<M: Module> FiniteChainComplex<M, FullModuleHomomorphism<M>> {
@@ -115,6 +191,166 @@ impl<M: Module> FiniteChainComplex<M, FullModuleHomomorphism<M>> {
     }
 }
 
+impl<A: Bialgebra> FiniteChainComplex<FDModule<A>> {
+    /// The dual complex. If the complex is $C_0 \leftarrow C_1 \leftarrow \cdots \leftarrow
+    /// C_{n - 1}$, then the dual complex has $C_{n - 1 - s}^*$ in homological degree $s$, and its
+    /// differentials are the transposes of the original ones. The internal degrees are negated.
+    /// See [`FDModule::dual`] for the module structure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the differentials do not preserve the internal degree.
+    pub fn dual(&self) -> Self {
+        let p = self.prime();
+        let n = self.modules.len();
+        let modules: Vec<Arc<FDModule<A>>> = self
+            .modules
+            .iter()
+            .rev()
+            .map(|m| Arc::new(m.dual()))
+            .collect();
+
+        let differentials = (1..n)
+            .map(|s| {
+                // This is the transpose of d: C_{n - s} -> C_{n - s - 1}
+                let d = &self.differentials[n - s];
+                assert_eq!(d.degree_shift(), 0);
+                let source = &modules[s];
+                let target = &modules[s - 1];
+
+                let min_degree = std::cmp::min(source.min_degree(), target.min_degree());
+                let max_degree =
+                    std::cmp::max(source.max_degree().unwrap(), target.max_degree().unwrap());
+                let mut matrices = BiVec::with_capacity(min_degree, max_degree + 1);
+                let mut image = FpVector::new(p, 0);
+                for t in min_degree..=max_degree {
+                    let mut matrix = Matrix::new(p, source.dimension(t), target.dimension(t));
+                    for j in 0..target.dimension(t) {
+                        image.set_scratch_vector_size(source.dimension(t));
+                        d.apply_to_basis_element(image.as_slice_mut(), 1, -t, j);
+                        for (i, c) in image.iter_nonzero() {
+                            matrix[i].set_entry(j, c);
+                        }
+                    }
+                    matrices.push(matrix);
+                }
+                Arc::new(FullModuleHomomorphism::from_matrices(
+                    Arc::clone(source),
+                    Arc::clone(target),
+                    0,
+                    matrices,
+                ))
+            })
+            .collect();
+
+        Self::new(modules, differentials)
+    }
+
+    /// The mapping cone of the chain map `map` from `source` to `target`. In homological degree
+    /// $s$, the cone is $D_s \oplus C_{s - 1}$, where $C$ is the source and $D$ is the target,
+    /// and the differential is
+    /// $$ d(y, x) = (dy + f(x), -dx). $$
+    /// The chain map must preserve both degrees, and the $s$th map of `map` must go from
+    /// `source.module(s)` to `target.module(s)`. Missing maps at the end of `map` are taken to be
+    /// zero.
+    ///
+    /// The complexes and the chain map may use any homomorphism type, but the modules must be
+    /// finite dimensional, since the cone is built out of [`FDModule::direct_sum`]. In particular,
+    /// this does not construct cofibers of self-maps of free complexes such as resolutions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `map` does not satisfy the above conditions.
+    pub fn mapping_cone<C, D, F>(source: &C, target: &D, map: &ChainMap<F>) -> Self
+    where
+        C: BoundedChainComplex<Algebra = A, Module = FDModule<A>>,
+        D: BoundedChainComplex<Algebra = A, Module = FDModule<A>>,
+        F: ModuleHomomorphism<Source = FDModule<A>, Target = FDModule<A>>,
+    {
+        assert_eq!(
+            map.s_shift, 0,
+            "Chain map must preserve the homological degree"
+        );
+        for (s, f) in map.chain_maps.iter().enumerate() {
+            assert_eq!(
+                f.degree_shift(),
+                0,
+                "Chain map must preserve the internal degree"
+            );
+            assert!(Arc::ptr_eq(&f.source(), &source.module(s as u32)));
+            assert!(Arc::ptr_eq(&f.target(), &target.module(s as u32)));
+        }
+
+        let p = source.prime();
+        let num_modules = std::cmp::max(target.max_s(), source.max_s() + 1) as usize;
+        let source_module = |s: usize| {
+            if s == 0 {
+                source.zero_module()
+            } else {
+                source.module(s as u32 - 1)
+            }
+        };
+        let modules: Vec<Arc<FDModule<A>>> = (0..num_modules)
+            .map(|s| Arc::new(target.module(s as u32).direct_sum(&source_module(s))))
+            .collect();
+
+        let differentials = (1..num_modules)
+            .map(|s| {
+                let cone_source = &modules[s];
+                let cone_target = &modules[s - 1];
+                let d_target = target.differential(s as u32);
+                let d_source = source.differential(s as u32 - 1);
+                let f = map.chain_maps.get(s - 1);
+
+                let min_degree = std::cmp::min(cone_source.min_degree(), cone_target.min_degree());
+                let max_degree = std::cmp::max(
+                    cone_source.max_degree().unwrap(),
+                    cone_target.max_degree().unwrap(),
+                );
+                let mut matrices = BiVec::with_capacity(min_degree, max_degree + 1);
+                for t in min_degree..=max_degree {
+                    let target_dim = target.module(s as u32).dimension(t);
+                    let prev_target_dim = target.module(s as u32 - 1).dimension(t);
+                    let mut matrix =
+                        Matrix::new(p, cone_source.dimension(t), cone_target.dimension(t));
+                    for i in 0..target_dim {
+                        d_target.apply_to_basis_element(
+                            matrix[i].slice_mut(0, prev_target_dim),
+                            1,
+                            t,
+                            i,
+                        );
+                    }
+                    for i in 0..source_module(s).dimension(t) {
+                        let row = &mut matrix[target_dim + i];
+                        if let Some(f) = f {
+                            f.apply_to_basis_element(row.slice_mut(0, prev_target_dim), 1, t, i);
+                        }
+                        if s > 1 {
+                            let len = row.len();
+                            d_source.apply_to_basis_element(
+                                row.slice_mut(prev_target_dim, len),
+                                *p - 1,
+                                t,
+                                i,
+                            );
+                        }
+                    }
+                    matrices.push(matrix);
+                }
+                Arc::new(FullModuleHomomorphism::from_matrices(
+                    Arc::clone(cone_source),
+                    Arc::clone(cone_target),
+                    0,
+                    matrices,
+                ))
+            })
+            .collect();
+
+        Self::new(modules, differentials)
+    }
+}
+
 impl<M, F> ChainComplex for FiniteChainComplex<M, F>
 where
     M: Module,
@@ -165,6 +401,35 @@ where
     }
 }
 
+impl<M, F> FiniteChainComplex<M, F>
+where
+    M: Module,
+    F: ModuleHomomorphism<Source = M, Target = M>,
+{
+    /// Compute a basis of the homology of the complex at homological degree `s` and internal
+    /// degree `t`. Each element of the result is a cycle in `self.module(s)` in degree `t`, and
+    /// their classes form a basis of the homology.
+    pub fn homology_basis(&self, s: u32, t: i32) -> Vec<FpVector> {
+        let d_out = self.differential(s);
+        let d_in = self.differential(s + 1);
+        let t_in = t + d_in.degree_shift();
+
+        let kernel = d_out
+            .kernel(t)
+            .cloned()
+            .unwrap_or_else(|| d_out.auxiliary_data(t).1);
+        let image = d_in
+            .image(t_in)
+            .cloned()
+            .unwrap_or_else(|| d_in.auxiliary_data(t_in).0);
+
+        Subquotient::from_parts(kernel, image)
+            .gens()
+            .map(|v| v.to_owned())
+            .collect()
+    }
+}
+
 impl<M, F> BoundedChainComplex for FiniteChainComplex<M, F>
 where
     M: Module,
@@ -327,3 +592,282 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::chain_complex::FreeChainComplex;
+    use algebra::module::homomorphism::{
+        FreeModuleHomomorphism, GenericZeroHomomorphism, IdentityHomomorphism,
+    };
+    use algebra::module::FreeModule;
+    use algebra::{AdemAlgebra, Algebra};
+
+    #[test]
+    fn test_homology_basis() {
+        let p = fp::prime::TWO;
+        let max_degree = 4;
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(max_degree);
+
+        // The complex F(x) -> F(y) with |x| = 1, |y| = 0 and d(x) = Sq^1 y.
+        let c0 = Arc::new(FreeModule::new(Arc::clone(&algebra), "C0".to_string(), 0));
+        c0.add_generators(0, 1, Some(vec!["y".to_string()]));
+        c0.extend_by_zero(max_degree);
+
+        let c1 = Arc::new(FreeModule::new(Arc::clone(&algebra), "C1".to_string(), 0));
+        c1.add_generators(0, 0, None);
+        c1.add_generators(1, 1, Some(vec!["x".to_string()]));
+        c1.extend_by_zero(max_degree);
+
+        let d = FreeModuleHomomorphism::new(Arc::clone(&c1), Arc::clone(&c0), 0);
+        d.add_generators_from_rows(0, vec![]);
+        d.add_generators_from_rows(1, vec![FpVector::from_slice(p, &[1])]);
+        d.extend_by_zero(max_degree);
+
+        let cc = FiniteChainComplex::new(vec![c0, c1], vec![Arc::new(d)]);
+
+        let dims = |s: u32| -> Vec<usize> {
+            (0..=max_degree)
+                .map(|t| cc.homology_basis(s, t).len())
+                .collect()
+        };
+        // H_0 = A/A Sq^1, which is spanned by 1, Sq^2, Sq^3, Sq^4 in low degrees.
+        assert_eq!(dims(0), [1, 0, 1, 1, 1]);
+        // H_1 is spanned by Sq^1 x and Sq^2 Sq^1 x.
+        assert_eq!(dims(1), [0, 0, 1, 0, 1]);
+        assert_eq!(dims(2), [0; 5]);
+
+        // The Euler characteristic is the alternating sum of the homology dimensions.
+        let euler = cc.euler_characteristic_range(-2, max_degree);
+        assert_eq!(euler.min_degree(), -2);
+        assert_eq!(euler.into_vec(), [0, 0, 1, 0, 0, 1, 0]);
+        // C_0 = A and C_1 = \Sigma A.
+        let poincare = cc.poincare_series(max_degree);
+        assert_eq!(poincare.min_degree(), 0);
+        assert_eq!(poincare.into_vec(), [1, 2, 2, 3, 4]);
+
+        assert_eq!(cc.homology_basis(0, 0), [FpVector::from_slice(p, &[1])]);
+        assert_eq!(cc.homology_basis(1, 2), [FpVector::from_slice(p, &[1])]);
+
+        // Representatives are cycles that are not boundaries. The cycle condition is automatic
+        // for s = 0.
+        let d = cc.differential(1);
+        for t in 0..=max_degree {
+            for v in cc.homology_basis(1, t) {
+                let mut dv = FpVector::new(p, cc.module(0).dimension(t));
+                d.apply(dv.as_slice_mut(), 1, t, v.as_slice());
+                assert!(dv.is_zero());
+            }
+            for v in cc.homology_basis(0, t) {
+                assert!(!d.auxiliary_data(t).0.contains(v.as_slice()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_splice() {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(0);
+
+        let module = |name: &str, dim: usize| {
+            Arc::new(FDModule::new(
+                Arc::clone(&algebra),
+                name.to_string(),
+                BiVec::from_vec(0, vec![dim]),
+            ))
+        };
+        // The rows are the images of the basis elements of the source.
+        let hom = |source: &Arc<FDModule<AdemAlgebra>>,
+                   target: &Arc<FDModule<AdemAlgebra>>,
+                   rows: &[Vec<u32>]| {
+            let mut matrix = Matrix::new(p, source.dimension(0), target.dimension(0));
+            for (row, v) in matrix.iter_mut().zip(rows) {
+                row.copy_from_slice(v);
+            }
+            FullModuleHomomorphism::from_matrices(
+                Arc::clone(source),
+                Arc::clone(target),
+                0,
+                BiVec::from_vec(0, vec![matrix]),
+            )
+        };
+
+        // bottom: <x> <- <a, b> with a -> x, b -> 0
+        let b0 = module("B0", 1);
+        let b1 = module("B1", 2);
+        let d = hom(&b1, &b0, &[vec![1], vec![0]]);
+        let bottom =
+            FiniteChainComplex::new(vec![Arc::clone(&b0), Arc::clone(&b1)], vec![Arc::new(d)]);
+
+        // top: <y> <- <z> with z -> 0
+        let t0 = module("T0", 1);
+        let t1 = module("T1", 1);
+        let d = hom(&t1, &t0, &[vec![0]]);
+        let top =
+            FiniteChainComplex::new(vec![Arc::clone(&t0), Arc::clone(&t1)], vec![Arc::new(d)]);
+
+        // y -> b
+        let connecting = ChainMap {
+            s_shift: 1,
+            chain_maps: vec![hom(&t0, &b1, &[vec![0, 1]])],
+        };
+        let cc = FiniteChainComplex::splice(&top, &bottom, &connecting);
+
+        assert_eq!(cc.max_s(), 4);
+        for (s, m) in [b0, b1, t0, t1].iter().enumerate() {
+            assert!(Arc::ptr_eq(&cc.module(s as u32), m));
+        }
+
+        let mut image = FpVector::new(p, 2);
+        cc.differential(2)
+            .apply_to_basis_element(image.as_slice_mut(), 1, 0, 0);
+        assert_eq!(image, FpVector::from_slice(p, &[0, 1]));
+
+        for s in 2..=cc.max_s() {
+            for i in 0..cc.module(s).dimension(0) {
+                let mut dx = FpVector::new(p, cc.module(s - 1).dimension(0));
+                cc.differential(s)
+                    .apply_to_basis_element(dx.as_slice_mut(), 1, 0, i);
+                let mut ddx = FpVector::new(p, cc.module(s - 2).dimension(0));
+                cc.differential(s - 1)
+                    .apply(ddx.as_slice_mut(), 1, 0, dx.as_slice());
+                assert!(ddx.is_zero(), "d^2 != 0 in s = {s}");
+            }
+        }
+    }
+
+    /// The complex C2 <- S^1 over the Adem algebra, sending the generator to the top cell of C2.
+    fn c2_s1_complex() -> FiniteChainComplex<FDModule<AdemAlgebra>> {
+        let p = fp::prime::TWO;
+        let algebra = Arc::new(AdemAlgebra::new(p, false));
+        algebra.compute_basis(10);
+
+        let mut c2 = FDModule::new(
+            Arc::clone(&algebra),
+            "C2".to_string(),
+            BiVec::from_vec(0, vec![1, 1]),
+        );
+        c2.set_action(1, 0, 0, 0, &[1]);
+        let c2 = Arc::new(c2);
+        let s1 = Arc::new(FDModule::new(
+            Arc::clone(&algebra),
+            "S1".to_string(),
+            BiVec::from_vec(1, vec![1]),
+        ));
+
+        let mut matrix = Matrix::new(p, 1, 1);
+        matrix[0].set_entry(0, 1);
+        let d = FullModuleHomomorphism::from_matrices(
+            Arc::clone(&s1),
+            Arc::clone(&c2),
+            0,
+            BiVec::from_vec(1, vec![matrix]),
+        );
+        FiniteChainComplex::new(vec![c2, s1], vec![Arc::new(d)])
+    }
+
+    #[test]
+    fn test_dual() {
+        // The homology of C2 <- S^1 is S^0
+        let cc = c2_s1_complex();
+        let dual = cc.dual();
+
+        assert_eq!(dual.max_s(), 2);
+        assert_eq!(dual.module(0).name, "S1*");
+        assert_eq!(dual.module(1).name, "C2*");
+
+        // The homology of the dual is the dual of the homology
+        for s in 0..2 {
+            for t in -2..=2 {
+                assert_eq!(
+                    dual.homology_basis(s, t).len(),
+                    cc.homology_basis(1 - s, -t).len(),
+                    "(s, t) = ({s}, {t})"
+                );
+            }
+        }
+        assert_eq!(dual.homology_basis(1, 0).len(), 1);
+
+        // The homology of the dual is S^0 in homological degree 1, so resolving it is the same as
+        // resolving the sphere, shifted by one in s, up to a contractible summand.
+        let res = crate::resolution::Resolution::new(Arc::new(dual));
+        res.compute_through_bidegree(6, 10);
+        let sphere = crate::utils::construct_standard::<false, _, _>("S_2@adem", None).unwrap();
+        sphere.compute_through_bidegree(6, 10);
+        // The acyclic summand x1* -> y* in degree -1 contributes a pair of generators that cancel
+        let gens = |t: i32| -> Vec<usize> {
+            (0..6)
+                .map(|s| res.number_of_gens_in_bidegree(s, t))
+                .collect()
+        };
+        assert_eq!(gens(-1), [1, 1, 0, 0, 0, 0]);
+        for t in 0..=10 {
+            assert_eq!(res.number_of_gens_in_bidegree(0, t), 0);
+        }
+        for s in 0..5 {
+            for t in 0..=10 {
+                assert_eq!(
+                    res.number_of_gens_in_bidegree(s + 1, t),
+                    sphere.number_of_gens_in_bidegree(s, t),
+                    "(s, t) = ({s}, {t})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mapping_cone() {
+        let p = fp::prime::TWO;
+        let cc = c2_s1_complex();
+        let c2 = cc.module(0);
+        let s1 = cc.module(1);
+
+        let homology_dims = |cc: &FiniteChainComplex<FDModule<AdemAlgebra>>| -> Vec<Vec<usize>> {
+            (0..cc.max_s())
+                .map(|s| (-1..=3).map(|t| cc.homology_basis(s, t).len()).collect())
+                .collect()
+        };
+
+        // The cone of the identity is acyclic
+        let identity = ChainMap {
+            s_shift: 0,
+            chain_maps: vec![
+                FullModuleHomomorphism::identity_homomorphism(Arc::clone(&c2)),
+                FullModuleHomomorphism::identity_homomorphism(Arc::clone(&s1)),
+            ],
+        };
+        let cone = FiniteChainComplex::mapping_cone(&cc, &cc, &identity);
+        assert_eq!(cone.max_s(), 3);
+        assert_eq!(cone.module(1).name, "S1 ⊕ C2");
+        assert_eq!(homology_dims(&cone), vec![vec![0; 5]; 3]);
+
+        // The cone of the zero map is the sum of the target and the shifted source. The map need
+        // not be a FullModuleHomomorphism.
+        let zero = ChainMap {
+            s_shift: 0,
+            chain_maps: vec![GenericZeroHomomorphism::new(Arc::clone(&c2), c2, 0)],
+        };
+        let cone = FiniteChainComplex::mapping_cone(&cc, &cc, &zero);
+        assert_eq!(
+            homology_dims(&cone),
+            [[0, 1, 0, 0, 0], [0, 1, 0, 0, 0], [0; 5]]
+        );
+
+        // d^2 = 0
+        for s in 2..cone.max_s() {
+            for t in 0..=2 {
+                for i in 0..cone.module(s).dimension(t) {
+                    let mut dx = FpVector::new(p, cone.module(s - 1).dimension(t));
+                    cone.differential(s)
+                        .apply_to_basis_element(dx.as_slice_mut(), 1, t, i);
+                    let mut ddx = FpVector::new(p, cone.module(s - 2).dimension(t));
+                    cone.differential(s - 1)
+                        .apply(ddx.as_slice_mut(), 1, t, dx.as_slice());
+                    assert!(ddx.is_zero());
+                }
+            }
+        }
+    }
+}
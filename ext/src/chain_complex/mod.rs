@@ -13,15 +13,32 @@ use std::sync::Arc;
 
 use itertools::Itertools;
 
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+
 // pub use hom_complex::HomComplex;
 pub use chain_homotopy::ChainHomotopy;
 pub use finite_chain_complex::{FiniteAugmentedChainComplex, FiniteChainComplex};
 
+/// Whether the homological degree of a chain complex counts up or down along the differential. For
+/// a homologically graded complex such as a resolution, the stem of $(s, t)$ is $t - s$. For a
+/// cohomologically graded complex such as the cobar complex of a comodule, it is $s - t$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChainComplexGrading {
     Homological,
     Cohomological,
 }
 
+impl ChainComplexGrading {
+    /// The stem of the bidegree $(s, t)$ in this grading.
+    pub fn stem(self, s: u32, t: i32) -> i32 {
+        match self {
+            Self::Homological => t - s as i32,
+            Self::Cohomological => s as i32 - t,
+        }
+    }
+}
+
 pub trait FreeChainComplex<const U: bool = false>:
     ChainComplex<
     Module = MuFreeModule<U, <Self as ChainComplex>::Algebra>,
@@ -49,40 +66,88 @@ where
         result
     }
 
+    /// Constructs the $E_2$ page of a homologically graded complex, placing the bidegree $(s, t)$
+    /// at the stem $t - s$. Use [`FreeChainComplex::to_cohomological_sseq`] for cohomologically
+    /// graded complexes.
+    ///
+    /// # Panics
+    /// If the complex is not homologically graded, see [`ChainComplex::grading`].
     fn to_sseq(&self) -> sseq::Sseq<sseq::Adams> {
-        let p = self.prime();
-        let mut sseq = sseq::Sseq::new(p, self.min_degree(), 0);
-        for (s, n, t) in self.iter_stem() {
-            sseq.set_dimension(n, s as i32, self.number_of_gens_in_bidegree(s, t));
-        }
-        sseq
+        to_sseq_inner(self, ChainComplexGrading::Homological)
     }
 
-    fn filtration_one_products(&self, op_deg: i32, op_idx: usize) -> sseq::Product {
-        let p = self.prime();
-        let mut matrices = BiVec::new(self.min_degree());
-        let max_y = self.next_homological_degree() as i32 - 1;
-        matrices.extend_with(self.module(0).max_computed_degree() - op_deg + 2, |x| {
-            let mut entries = BiVec::with_capacity(0, max_y);
-            let mut y = 0;
-            while self.has_computed_bidegree(y as u32 + 1, x + y + op_deg) {
-                entries.push(
-                    self.filtration_one_product(op_deg, op_idx, y as u32, x + y)
-                        .map(|m| Matrix::from_vec(p, &m)),
-                );
-                y += 1;
+    /// Constructs the $E_2$ page of a cohomologically graded complex, placing the bidegree
+    /// $(s, t)$ at the stem $s - t$.
+    ///
+    /// # Panics
+    /// If the complex is not cohomologically graded, see [`ChainComplex::grading`].
+    fn to_cohomological_sseq(&self) -> sseq::Sseq<sseq::CohomologicalAdams> {
+        to_sseq_inner(self, ChainComplexGrading::Cohomological)
+    }
+
+    /// Constructs the $E_2$ page as in [`FreeChainComplex::to_sseq`], together with the
+    /// multiplicative structure given by the filtration one products `products`. Each product is
+    /// specified by its name and the corresponding element of the algebra, in the format returned
+    /// by [`Algebra::default_filtration_one_products`]. The products can be passed directly to
+    /// [`Sseq::write_to_graph`](sseq::Sseq::write_to_graph).
+    fn to_sseq_with_products(
+        &self,
+        products: &[(String, i32, usize)],
+    ) -> (sseq::Sseq<sseq::Adams>, Vec<(String, sseq::Product)>) {
+        (self.to_sseq(), named_products(self, products))
+    }
+
+    /// The cohomological analogue of [`FreeChainComplex::to_sseq_with_products`].
+    fn to_cohomological_sseq_with_products(
+        &self,
+        products: &[(String, i32, usize)],
+    ) -> (
+        sseq::Sseq<sseq::CohomologicalAdams>,
+        Vec<(String, sseq::Product)>,
+    ) {
+        (self.to_cohomological_sseq(), named_products(self, products))
+    }
+
+    /// Draws the $E_2$ page of the Adams chart as an SVG image, with a dot for every generator and
+    /// lines for the filtration one products `products`. See
+    /// [`FreeChainComplex::to_sseq_with_products`] for the format of `products`. The profile of
+    /// the chart is determined by [`ChainComplex::grading`].
+    fn to_svg(&self, products: &[(String, i32, usize)]) -> String {
+        fn write<P: sseq::SseqProfile>(
+            sseq: sseq::Sseq<P>,
+            products: Vec<(String, sseq::Product)>,
+        ) -> String {
+            let mut out = Vec::new();
+            sseq.write_to_graph(
+                chart::SvgBackend::new(&mut out),
+                2,
+                false,
+                products.iter(),
+                |_| Ok(()),
+            )
+            .expect("Writing to a Vec cannot fail");
+            String::from_utf8(out).expect("SvgBackend produces valid UTF-8")
+        }
+
+        match self.grading() {
+            ChainComplexGrading::Homological => {
+                let (sseq, products) = self.to_sseq_with_products(products);
+                write(sseq, products)
+            }
+            ChainComplexGrading::Cohomological => {
+                let (sseq, products) = self.to_cohomological_sseq_with_products(products);
+                write(sseq, products)
             }
-            entries
-        });
-
-        sseq::Product {
-            left: true,
-            x: op_deg - 1,
-            y: 1,
-            matrices,
         }
     }
 
+    /// Computes the matrices of the product by the filtration one element `(op_deg, op_idx)` in
+    /// every computed bidegree, indexed by the stems of [`ChainComplex::grading`]. The stems are
+    /// computed in parallel if the `concurrent` feature is enabled.
+    fn filtration_one_products(&self, op_deg: i32, op_idx: usize) -> sseq::Product {
+        filtration_one_products_inner(self, op_deg, op_idx, cfg!(feature = "concurrent"))
+    }
+
     /// Computes the filtration one product.
     ///
     /// # Returns
@@ -130,6 +195,25 @@ where
         self.module(s).number_of_gens_in_degree(t)
     }
 
+    /// Whether the complex is minimal through `(max_s, max_t)`, i.e. whether the differential of
+    /// every generator lies in the augmentation ideal times the previous module. Equivalently, no
+    /// differential hits a generator with a non-zero coefficient. Bidegrees that have not been
+    /// computed are skipped, so `max_s` and `max_t` may exceed the computed range.
+    fn is_minimal(&self, max_s: u32, max_t: i32) -> bool {
+        let max_s = std::cmp::min(max_s, self.next_homological_degree().saturating_sub(1));
+        (1..=max_s).all(|s| {
+            let target = self.module(s - 1);
+            let d = self.differential(s);
+            (self.min_degree()..=std::cmp::min(max_t, d.next_degree() - 1)).all(|t| {
+                (0..self.number_of_gens_in_bidegree(s, t)).all(|i| {
+                    let dx = d.output(t, i);
+                    (0..target.number_of_gens_in_degree(t))
+                        .all(|j| dx.entry(target.operation_generator_to_index(0, 0, t, j)) == 0)
+                })
+            })
+        })
+    }
+
     fn cocycle_string(&self, s: u32, t: i32, idx: usize) -> String {
         let d = self.differential(s);
         let target = d.target();
@@ -139,6 +223,101 @@ where
     }
 }
 
+/// See [`FreeChainComplex::filtration_one_products`]. The stems are computed in parallel if
+/// `parallel` is set, which has no effect without the `concurrent` feature.
+#[cfg_attr(not(feature = "concurrent"), allow(unused_variables))]
+/// See [`FreeChainComplex::to_sseq`]. The profile `P` must be the one matching `grading`.
+fn to_sseq_inner<const U: bool, CC, P>(cc: &CC, grading: ChainComplexGrading) -> sseq::Sseq<P>
+where
+    CC: FreeChainComplex<U> + ?Sized,
+    CC::Algebra: MuAlgebra<U>,
+    P: sseq::SseqProfile,
+{
+    assert_eq!(
+        cc.grading(),
+        grading,
+        "Chain complex has the wrong grading for this spectral sequence"
+    );
+    let mut iter = cc.iter_stem().peekable();
+    let min_x = iter.peek().map_or(cc.min_degree(), |&(_, n, _)| n);
+    let mut sseq = sseq::Sseq::new(cc.prime(), min_x, 0);
+    for (s, n, t) in iter {
+        sseq.set_dimension(n, s as i32, cc.number_of_gens_in_bidegree(s, t));
+    }
+    sseq
+}
+
+/// The filtration one products `products` of `cc`, in the format of
+/// [`FreeChainComplex::to_sseq_with_products`].
+fn named_products<const U: bool, CC>(
+    cc: &CC,
+    products: &[(String, i32, usize)],
+) -> Vec<(String, sseq::Product)>
+where
+    CC: FreeChainComplex<U> + ?Sized,
+    CC::Algebra: MuAlgebra<U>,
+{
+    products
+        .iter()
+        .map(|(name, op_deg, op_idx)| (name.clone(), cc.filtration_one_products(*op_deg, *op_idx)))
+        .collect()
+}
+
+pub(crate) fn filtration_one_products_inner<const U: bool, CC>(
+    cc: &CC,
+    op_deg: i32,
+    op_idx: usize,
+    #[cfg_attr(not(feature = "concurrent"), allow(unused_variables))] parallel: bool,
+) -> sseq::Product
+where
+    CC: FreeChainComplex<U> + ?Sized,
+    CC::Algebra: MuAlgebra<U>,
+{
+    let p = cc.prime();
+    let min_degree = cc.min_degree();
+    let max_x = cc.module(0).max_computed_degree() - op_deg + 2;
+    let max_y = cc.next_homological_degree() as i32 - 1;
+
+    let f = |x| {
+        let mut entries = BiVec::with_capacity(0, max_y);
+        let mut y = 0;
+        while cc.has_computed_bidegree(y as u32 + 1, x + y + op_deg) {
+            entries.push(
+                cc.filtration_one_product(op_deg, op_idx, y as u32, x + y)
+                    .map(|m| Matrix::from_vec(p, &m)),
+            );
+            y += 1;
+        }
+        entries
+    };
+
+    #[cfg(feature = "concurrent")]
+    let mut matrices: Vec<_> = if parallel {
+        (min_degree..=max_x).into_par_iter().map(f).collect()
+    } else {
+        (min_degree..=max_x).map(f).collect()
+    };
+
+    #[cfg(not(feature = "concurrent"))]
+    let mut matrices: Vec<_> = (min_degree..=max_x).map(f).collect();
+
+    let grading = cc.grading();
+    let min_x = match grading {
+        ChainComplexGrading::Homological => min_degree,
+        ChainComplexGrading::Cohomological => {
+            matrices.reverse();
+            -max_x
+        }
+    };
+
+    sseq::Product {
+        left: true,
+        x: grading.stem(1, op_deg),
+        y: 1,
+        matrices: BiVec::from_vec(min_x, matrices),
+    }
+}
+
 impl<const U: bool, CC> FreeChainComplex<U> for CC
 where
     CC: ChainComplex<
@@ -180,14 +359,30 @@ pub trait ChainComplex: Send + Sync {
     /// The first s such that `self.module(s)` is not defined.
     fn next_homological_degree(&self) -> u32;
 
+    /// The grading of the complex, which determines the stem reported by
+    /// [`ChainComplex::iter_stem`]. This defaults to [`ChainComplexGrading::Homological`].
+    fn grading(&self) -> ChainComplexGrading {
+        ChainComplexGrading::Homological
+    }
+
     /// Iterate through all defined bidegrees in increasing order of stem. The return values are of
-    /// the form `(s, n, t)`.
+    /// the form `(s, n, t)`, where `n` is the stem of `(s, t)` in [`ChainComplex::grading`].
     fn iter_stem(&self) -> StemIterator<'_, Self> {
+        let max_s = self.next_homological_degree();
+        let grading = self.grading();
+        // The cohomological stems increase as t - s decreases, so we start from the largest
+        // computed t - s, which is attained at s = 0.
+        let n = match grading {
+            ChainComplexGrading::Homological => self.min_degree(),
+            ChainComplexGrading::Cohomological if max_s > 0 => self.module(0).max_computed_degree(),
+            ChainComplexGrading::Cohomological => self.min_degree() - 1,
+        };
         StemIterator {
             cc: self,
-            n: self.min_degree(),
+            grading,
+            n,
             s: 0,
-            max_s: self.next_homological_degree(),
+            max_s,
         }
     }
 
@@ -245,11 +440,23 @@ pub trait ChainComplex: Send + Sync {
 /// An iterator returned by [`ChainComplex::iter_stem`]
 pub struct StemIterator<'a, CC: ?Sized> {
     cc: &'a CC,
+    grading: ChainComplexGrading,
+    /// The value of t - s, which is the stem in the homological grading
     n: i32,
     s: u32,
     max_s: u32,
 }
 
+impl<'a, CC: ?Sized> StemIterator<'a, CC> {
+    fn next_stem(&mut self) {
+        match self.grading {
+            ChainComplexGrading::Homological => self.n += 1,
+            ChainComplexGrading::Cohomological => self.n -= 1,
+        }
+        self.s = 0;
+    }
+}
+
 impl<'a, CC: ChainComplex + ?Sized> Iterator for StemIterator<'a, CC> {
     // (s, n, t)
     type Item = (u32, i32, i32);
@@ -258,25 +465,25 @@ impl<'a, CC: ChainComplex + ?Sized> Iterator for StemIterator<'a, CC> {
             return None;
         }
         let s = self.s;
-        let n = self.n;
         let t = self.n + self.s as i32;
 
+        if self.grading == ChainComplexGrading::Cohomological && self.n < self.cc.min_degree() {
+            return None;
+        }
         if s == self.max_s {
-            self.n += 1;
-            self.s = 0;
+            self.next_stem();
             return self.next();
         }
         if t > self.cc.module(s).max_computed_degree() {
             if s == 0 {
                 return None;
             } else {
-                self.n += 1;
-                self.s = 0;
+                self.next_stem();
                 return self.next();
             }
         }
         self.s += 1;
-        Some((s, n, t))
+        Some((s, self.grading.stem(s, t), t))
     }
 }
 
@@ -302,6 +509,37 @@ pub trait BoundedChainComplex: ChainComplex {
             .map(|s| (if s % 2 == 0 { 1 } else { -1 }) * self.module(s).dimension(t) as isize)
             .sum()
     }
+
+    /// The Euler characteristic in each internal degree from `t_min` to `t_max` inclusive. The
+    /// result is indexed by `t`, and degrees in which all modules vanish have an entry of 0.
+    fn euler_characteristic_range(&self, t_min: i32, t_max: i32) -> BiVec<isize> {
+        BiVec::from_vec(
+            t_min,
+            (t_min..=t_max)
+                .map(|t| self.euler_characteristic(t))
+                .collect(),
+        )
+    }
+
+    /// The Poincaré series of the complex from `self.min_degree()` through `t_max`, ignoring the
+    /// homological degree. The result is indexed by `t`, like [`Self::euler_characteristic_range`],
+    /// and the entry at `t` is the sum of the dimensions of the modules in internal degree `t`.
+    fn poincare_series(&self, t_max: i32) -> BiVec<isize> {
+        for s in 0..self.max_s() {
+            self.module(s).compute_basis(t_max);
+        }
+        let t_min = self.min_degree();
+        BiVec::from_vec(
+            t_min,
+            (t_min..=t_max)
+                .map(|t| {
+                    (0..self.max_s())
+                        .map(|s| self.module(s).dimension(t) as isize)
+                        .sum()
+                })
+                .collect(),
+        )
+    }
 }
 
 /// `chain_maps` is required to be non-empty
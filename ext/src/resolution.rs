@@ -1,18 +1,22 @@
 //! This module exports the [`Resolution`] object, which is a chain complex resolving a module. In
 //! particular, this contains the core logic that compute minimal resolutions.
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
-use crate::chain_complex::{AugmentedChainComplex, ChainComplex};
+use crate::chain_complex::{AugmentedChainComplex, ChainComplex, ChainHomotopy};
+use crate::resolution_homomorphism::ResolutionHomomorphism;
 use crate::save::SaveKind;
 use crate::utils::Timer;
 
 use algebra::module::homomorphism::{ModuleHomomorphism, MuFreeModuleHomomorphism};
 use algebra::module::{Module, MuFreeModule};
 use algebra::{Algebra, MuAlgebra};
-use fp::matrix::{AugmentedMatrix, QuasiInverse, Subspace};
+use bivec::BiVec;
+use fp::matrix::{AugmentedMatrix, Matrix, QuasiInverse, Subspace};
 use fp::vector::{FpVector, Slice, SliceMut};
 use once::OnceVec;
 
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
 
 use anyhow::Context;
@@ -22,7 +26,7 @@ use dashmap::DashMap;
 use itertools::Itertools;
 
 #[cfg(feature = "concurrent")]
-use std::sync::mpsc;
+use std::{collections::BTreeSet, sync::mpsc, time::Duration};
 
 /// In [`MuResolution::compute_through_stem`] and [`MuResolution::compute_through_bidegree`], we pass
 /// this struct around to inform the supervisor what bidegrees have been computed. We use an
@@ -60,6 +64,12 @@ impl SenderData {
 /// number if needs be, but up to the 140th stem we only see at most 8 new generators.
 const MAX_NEW_GENS: usize = 10;
 
+thread_local! {
+    /// The pivot buffer used by [`MuResolution::step_resolution`], which is reused across
+    /// bidegrees computed on the same thread.
+    static PIVOTS: std::cell::Cell<Vec<isize>> = const { std::cell::Cell::new(Vec::new()) };
+}
+
 pub type Resolution<CC> = MuResolution<false, CC>;
 pub type UnstableResolution<CC> = MuResolution<true, CC>;
 
@@ -81,6 +91,9 @@ where
     ///  returned by `generate_old_kernel_and_compute_new_kernel`, to be used if we run
     ///  compute_through_degree again.
     kernels: DashMap<(u32, i32), Subspace>,
+    /// Whether we keep the kernels at the edge of the computed region after a `compute_through_*`
+    /// call returns. See [`MuResolution::new_without_kernel_storage`].
+    store_kernels: bool,
     save_dir: Option<PathBuf>,
 
     /// Whether we should save newly computed data to the disk. This has no effect if there is no
@@ -98,6 +111,13 @@ where
     /// augmentation map are useful when the target chain complex is not concentrated in one
     /// degree, and they tend to be quite small anyway.
     pub load_quasi_inverse: bool,
+
+    /// If set, the concurrent `compute_through_*` functions panic if no bidegree finishes within
+    /// this duration, listing the bidegrees that are still pending. This turns a scheduling bug
+    /// that would otherwise hang silently into a diagnosable failure. Defaults to `None`, since a
+    /// single large bidegree can legitimately take a long time.
+    #[cfg(feature = "concurrent")]
+    pub watchdog_timeout: Option<Duration>,
 }
 
 impl<const U: bool, CC: ChainComplex> MuResolution<U, CC>
@@ -109,6 +129,20 @@ where
         Self::new_with_save(complex, None).unwrap()
     }
 
+    /// Create a resolution that does not keep kernels around once a `compute_through_*` call
+    /// returns. A kernel computed in $(s, t)$ is still used to compute $(s + 1, t)$ within the same
+    /// call, but kernels at the edge of the resolved region are dropped instead of being kept for
+    /// a future extension. This saves memory when we only care about the generators in a fixed
+    /// range.
+    ///
+    /// The resolution can still be extended further, but the dropped kernels have to be
+    /// recomputed when resuming, unless they can be read from a save file.
+    pub fn new_without_kernel_storage(complex: Arc<CC>) -> Self {
+        let mut result = Self::new(complex);
+        result.store_kernels = false;
+        result
+    }
+
     pub fn new_with_save(complex: Arc<CC>, save_dir: Option<PathBuf>) -> anyhow::Result<Self> {
         let algebra = complex.algebra();
         let min_degree = complex.min_degree();
@@ -132,7 +166,10 @@ where
             modules: OnceVec::new(),
             differentials: OnceVec::new(),
             kernels: DashMap::new(),
+            store_kernels: true,
             load_quasi_inverse: true,
+            #[cfg(feature = "concurrent")]
+            watchdog_timeout: None,
         })
     }
 
@@ -144,6 +181,248 @@ where
         &self.name
     }
 
+    /// The number of generators in each bidegree of the computed region, indexed by `[s][t]`.
+    /// This is a structured version of
+    /// [`FreeChainComplex::graded_dimension_string`](crate::chain_complex::FreeChainComplex::graded_dimension_string).
+    pub fn rank_table(&self) -> BiVec<BiVec<usize>> {
+        let min_degree = self.min_degree();
+        let mut result = BiVec::with_capacity(0, self.modules.len() as i32);
+        for module in self.modules.iter() {
+            let mut ranks = BiVec::new(min_degree);
+            ranks.extend_with(module.max_computed_degree(), |t| {
+                module.number_of_gens_in_degree(t)
+            });
+            result.push(ranks);
+        }
+        result
+    }
+
+    /// The number of generators in stem `n`, indexed by `s`. Bidegrees that have not been computed
+    /// are reported as having no generators. This is a column of [`Self::rank_table`].
+    pub fn gens_in_stem(&self, n: i32) -> BiVec<usize> {
+        let mut result = BiVec::with_capacity(0, self.modules.len() as i32);
+        for (s, module) in self.modules.iter().enumerate() {
+            let t = n + s as i32;
+            if t > module.max_computed_degree() {
+                result.push(0);
+            } else {
+                result.push(module.number_of_gens_in_degree(t));
+            }
+        }
+        result
+    }
+
+    /// The bidegrees $(s, t)$ with $s \leq \mathtt{max\_s}$ and $t \leq \mathtt{max\_t}$ that have
+    /// been computed and contain generators, together with the number of generators. The result is
+    /// sorted by `s` and then by `t`. This is faster than calling
+    /// [`number_of_gens_in_bidegree`](crate::chain_complex::FreeChainComplex::number_of_gens_in_bidegree)
+    /// on every bidegree.
+    pub fn gens_in_region(&self, max_s: u32, max_t: i32) -> Vec<(u32, i32, usize)> {
+        let mut result = Vec::new();
+        for (s, module) in self.modules.iter().enumerate().take(max_s as usize + 1) {
+            let max_t = std::cmp::min(max_t, module.max_computed_degree());
+            for t in module.min_degree()..=max_t {
+                let num_gens = module.number_of_gens_in_degree(t);
+                if num_gens > 0 {
+                    result.push((s as u32, t, num_gens));
+                }
+            }
+        }
+        result
+    }
+
+    /// The number of generators in homological degree `s` over all computed internal degrees.
+    /// This is zero if `s` has not been reached yet.
+    pub fn rank_in_filtration(&self, s: u32) -> usize {
+        let Some(module) = self.modules.get(s as usize) else {
+            return 0;
+        };
+        (module.min_degree()..=module.max_computed_degree())
+            .map(|t| module.number_of_gens_in_degree(t))
+            .sum()
+    }
+
+    /// The total number of generators in the computed region. This is the sum of the entries of
+    /// [`Self::rank_table`].
+    pub fn total_rank(&self) -> usize {
+        (0..self.modules.len() as u32)
+            .map(|s| self.rank_in_filtration(s))
+            .sum()
+    }
+
+    /// Compare the number of generators in each bidegree against `reference`, which is indexed by
+    /// `[s][t]` as in [`Self::rank_table`]. Only the bidegrees present in `reference` are checked,
+    /// and they must all have been computed. The first mismatch is reported as an error.
+    pub fn assert_ranks_eq(&self, reference: &BiVec<BiVec<usize>>) -> Result<(), String> {
+        for (s, row) in reference.iter_enum() {
+            let s = s as u32;
+            for (t, &expected) in row.iter_enum() {
+                if !self.has_computed_bidegree(s, t) {
+                    return Err(format!(
+                        "Bidegree (s, t) = ({s}, {t}) has not been computed"
+                    ));
+                }
+                let actual = self.modules[s as usize].number_of_gens_in_degree(t);
+                if actual != expected {
+                    return Err(format!(
+                        "Rank mismatch in (s, t) = ({s}, {t}): expected {expected}, got {actual}"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// A rough estimate of the number of bytes used by the computed part of the resolution. This
+    /// counts the values of the differentials and augmentation maps on generators, their
+    /// quasi-inverses, images and kernels, and the kernels kept for extending the resolution. It
+    /// ignores bookkeeping data such as the basis tables of the free modules.
+    pub fn memory_estimate(&self) -> usize {
+        fn subspace_bytes(subspace: &Subspace) -> usize {
+            subspace.dimension()
+                * FpVector::num_bytes(subspace.prime(), subspace.ambient_dimension())
+                + std::mem::size_of_val(subspace.pivots())
+        }
+
+        fn hom_bytes<const U: bool, M: Module>(hom: &MuFreeModuleHomomorphism<U, M>) -> usize
+        where
+            M::Algebra: MuAlgebra<U>,
+        {
+            let p = hom.prime();
+            let mut result = 0;
+            for t in hom.min_degree()..hom.next_degree() {
+                let target_dim = hom.target().dimension(t);
+                result +=
+                    hom.source().number_of_gens_in_degree(t) * FpVector::num_bytes(p, target_dim);
+                if let Some(qi) = hom.quasi_inverse(t) {
+                    let preimage = qi.preimage();
+                    result += preimage.rows() * FpVector::num_bytes(p, preimage.columns())
+                        + qi.pivots().map_or(0, std::mem::size_of_val);
+                }
+                result += hom.kernel(t).map_or(0, subspace_bytes);
+                result += hom.image(t).map_or(0, subspace_bytes);
+            }
+            result
+        }
+
+        self.differentials
+            .iter()
+            .map(|d| hom_bytes(d))
+            .sum::<usize>()
+            + self.chain_maps.iter().map(|f| hom_bytes(f)).sum::<usize>()
+            + self
+                .kernels
+                .iter()
+                .map(|kernel| subspace_bytes(kernel.value()))
+                .sum::<usize>()
+    }
+
+    /// A global index of the `idx`th generator of the bidegree $(s, t)$ among all generators in
+    /// homological degree `s`. Generators are ordered by `t` and then by `idx`.
+    pub fn global_generator_index(&self, s: u32, t: i32, idx: usize) -> usize {
+        self.modules[s as usize].internal_generator_index(t, idx)
+    }
+
+    /// The `(t, idx)` of the generator in homological degree `s` with the given global index. This
+    /// is the inverse of [`Self::global_generator_index`].
+    pub fn generator_from_global_index(&self, s: u32, global_idx: usize) -> (i32, usize) {
+        self.modules[s as usize].generator_from_internal_index(global_idx)
+    }
+
+    /// Whether `vec`, an element of the module in homological degree `s` and degree `t`, lies in
+    /// the image of $d_{s + 1}$. This applies the quasi-inverse of $d_{s + 1}$, which may be read
+    /// from the save file, and checks that applying $d_{s + 1}$ recovers `vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if $(s + 1, t)$ has not been computed or the quasi-inverse is not available.
+    pub fn is_boundary(&self, s: u32, t: i32, vec: &FpVector) -> bool {
+        assert!(
+            self.has_computed_bidegree(s + 1, t),
+            "Bidegree (s, t) = ({}, {t}) has not been computed",
+            s + 1
+        );
+        let p = self.prime();
+        let d = self.differential(s + 1);
+
+        let mut preimage = FpVector::new(p, self.module(s + 1).dimension(t));
+        assert!(
+            self.apply_quasi_inverse(&mut [preimage.as_slice_mut()], s + 1, t, &[vec.as_slice()]),
+            "Quasi-inverse of d_{} in degree {t} is not available",
+            s + 1
+        );
+
+        let mut image = FpVector::new(p, vec.len());
+        d.apply(image.as_slice_mut(), 1, t, preimage.as_slice());
+        image == *vec
+    }
+
+    /// The value of the differential on the `idx`th generator of the bidegree $(s, t)$, as an
+    /// element of the module in homological degree $s - 1$.
+    pub fn differential_output(&self, s: u32, t: i32, idx: usize) -> &FpVector {
+        self.differentials[s as usize].output(t, idx)
+    }
+
+    /// The total number of nonzero entries in the values of the differential on the generators
+    /// of the bidegree $(s, t)$.
+    pub fn nonzero_differential_entries(&self, s: u32, t: i32) -> usize {
+        (0..self.modules[s as usize].number_of_gens_in_degree(t))
+            .map(|idx| self.differential_output(s, t, idx).hamming_weight())
+            .sum()
+    }
+
+    /// The matrix of the augmentation map $F_s \to C_s$ in internal degree $t$, where $C$ is the
+    /// complex being resolved. The rows are indexed by the basis of the free module.
+    pub fn chain_map_matrix(&self, s: u32, t: i32) -> Matrix {
+        let f = &self.chain_maps[s as usize];
+        let mut matrix = Matrix::new(
+            self.prime(),
+            f.source().dimension(t),
+            f.target().dimension(t),
+        );
+        f.get_matrix(matrix.as_slice_mut(), t);
+        matrix
+    }
+
+    /// Check that the augmentation map is a chain map that is surjective onto the module in
+    /// homological degree 0. This checks every computed bidegree with internal degree at most
+    /// `max_t`, and is intended to catch bugs in custom chain complexes.
+    pub fn verify_augmentation(&self, max_t: i32) -> Result<(), String> {
+        let p = self.prime();
+        for (s, f) in self.chain_maps.iter().enumerate() {
+            let s = s as u32;
+            for t in self.min_degree()..=max_t {
+                if !self.has_computed_bidegree(s, t) {
+                    break;
+                }
+                if s == 0 {
+                    if self.chain_map_matrix(0, t).row_reduce() != f.target().dimension(t) {
+                        return Err(format!("Augmentation map is not surjective in degree {t}"));
+                    }
+                    continue;
+                }
+
+                let d = &self.differentials[s as usize];
+                let complex_d = self.complex.differential(s);
+                let prev_f = &self.chain_maps[s as usize - 1];
+                let dim = prev_f.target().dimension(t);
+                for idx in 0..f.source().number_of_gens_in_degree(t) {
+                    let mut fd = FpVector::new(p, dim);
+                    let mut df = FpVector::new(p, dim);
+                    prev_f.apply(fd.as_slice_mut(), 1, t, d.output(t, idx).as_slice());
+                    complex_d.apply(df.as_slice_mut(), 1, t, f.output(t, idx).as_slice());
+                    if fd != df {
+                        return Err(format!(
+                            "Augmentation is not a chain map on generator {idx} in (s, t) = \
+                             ({s}, {t}): f(d(x)) = {fd}, but d(f(x)) = {df}"
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// This function prepares the Resolution object to perform computations up to the
     /// specified s degree. It does *not* perform any computations by itself. It simply lengthens
     /// the `OnceVec`s `modules`, `chain_maps`, etc. to the right length.
@@ -235,8 +514,13 @@ where
         current_chain_map.get_matrix(matrix.segment(0, 0), t);
         current_differential.get_matrix(matrix.segment(1, 1), t);
         matrix.segment(2, 2).add_identity();
+
+        #[cfg(not(feature = "concurrent"))]
         matrix.row_reduce();
 
+        #[cfg(feature = "concurrent")]
+        matrix.par_row_reduce();
+
         let kernel = matrix.compute_kernel();
 
         timer.end(format_args!(
@@ -320,6 +604,13 @@ where
     /// the kernel which is what the function returns at the end. This computation helps us perform
     /// the future steps since we need to know about the cokernel of this map.
     ///
+    /// The new generators are added in order of increasing pivot column, first those hitting the
+    /// cokernel of the augmentation and then those hitting the missing cycles (see
+    /// [`Matrix::extend_to_surjection`](fp::matrix::Matrix::extend_to_surjection) and
+    /// [`Matrix::extend_image`](fp::matrix::Matrix::extend_image)). Since the row reduced
+    /// matrices are unique, the generators and their differentials do not depend on the order in
+    /// which bidegrees are computed or on whether the computation is concurrent.
+    ///
     /// # Arguments
     ///  * `s` - The s degree to calculate
     ///  * `t` - The t degree to calculate
@@ -328,6 +619,14 @@ where
     /// and `step_resolution(s - 1, t - 1)`. It is more efficient if we have in fact run
     /// `step_resolution(s - 1, t)`, so try your best to arrange calls to be run in this order.
     fn step_resolution(&self, s: u32, t: i32) {
+        self.step_resolution_with_result(s, t).unwrap_or_else(|e| {
+            panic!("Error computing bidegree ({n}, {s}): {e}", n = t - s as i32)
+        });
+    }
+
+    /// See [`MuResolution::step_resolution`]. This returns an error if reading a saved bidegree
+    /// fails.
+    fn step_resolution_with_result(&self, s: u32, t: i32) -> anyhow::Result<()> {
         if s == 0 {
             self.zero_module.extend_by_zero(t);
         }
@@ -351,7 +650,7 @@ where
         match current_differential.next_degree().cmp(&t) {
             std::cmp::Ordering::Greater => {
                 // Already computed this degree.
-                return;
+                return Ok(());
             }
             std::cmp::Ordering::Less => {
                 // Haven't computed far enough yet
@@ -377,19 +676,16 @@ where
         let target_res_dimension = target_res.dimension(t);
 
         if let Some(dir) = &self.save_dir {
-            if let Some(mut f) = self
-                .save_file(SaveKind::Differential, s, t)
-                .open_file(dir.clone())
-            {
-                let num_new_gens = f.read_u64::<LittleEndian>().unwrap() as usize;
+            if let Some(mut f) = self.open_differential(dir, s, t)? {
+                let num_new_gens = f.read_u64::<LittleEndian>()? as usize;
                 // This need not be equal to `target_res_dimension`. If we saved a big resolution
                 // and now only want to load up to a small stem, then `target_res_dimension` will
                 // be smaller. If we have previously saved a small resolution up to a stem and now
                 // want to resolve further, it will be bigger.
-                let saved_target_res_dimension = f.read_u64::<LittleEndian>().unwrap() as usize;
+                let saved_target_res_dimension = f.read_u64::<LittleEndian>()? as usize;
                 assert_eq!(
                     target_cc_dimension,
-                    f.read_u64::<LittleEndian>().unwrap() as usize,
+                    f.read_u64::<LittleEndian>()? as usize,
                     "Malformed data: mismatched augmentation target dimension"
                 );
 
@@ -399,11 +695,10 @@ where
                 let mut a_targets = Vec::with_capacity(num_new_gens);
 
                 for _ in 0..num_new_gens {
-                    d_targets
-                        .push(FpVector::from_bytes(p, saved_target_res_dimension, &mut f).unwrap());
+                    d_targets.push(FpVector::from_bytes(p, saved_target_res_dimension, &mut f)?);
                 }
                 for _ in 0..num_new_gens {
-                    a_targets.push(FpVector::from_bytes(p, target_cc_dimension, &mut f).unwrap());
+                    a_targets.push(FpVector::from_bytes(p, target_cc_dimension, &mut f)?);
                 }
                 drop(f);
                 current_differential.add_generators_from_rows(t, d_targets);
@@ -414,7 +709,7 @@ where
                     if let Some(mut f) =
                         self.save_file(SaveKind::ResQi, s, t).open_file(dir.clone())
                     {
-                        let res_qi = QuasiInverse::from_bytes(p, &mut f).unwrap();
+                        let res_qi = QuasiInverse::from_bytes(p, &mut f)?;
 
                         assert_eq!(
                             res_qi.source_dimension(),
@@ -434,7 +729,7 @@ where
                     .save_file(SaveKind::AugmentationQi, s, t)
                     .open_file(dir.clone())
                 {
-                    let cm_qi = QuasiInverse::from_bytes(p, &mut f).unwrap();
+                    let cm_qi = QuasiInverse::from_bytes(p, &mut f)?;
 
                     assert_eq!(
                         cm_qi.target_dimension(),
@@ -457,7 +752,7 @@ where
 
                 current_chain_map.set_kernel(t, None);
                 current_chain_map.set_image(t, None);
-                return;
+                return Ok(());
             }
         }
 
@@ -476,7 +771,13 @@ where
         current_differential.get_matrix(matrix.segment(1, 1), t);
         matrix.segment(2, 2).add_identity();
 
-        matrix.row_reduce();
+        let mut pivots = PIVOTS.take();
+
+        #[cfg(not(feature = "concurrent"))]
+        matrix.row_reduce_reuse(&mut pivots);
+
+        #[cfg(feature = "concurrent")]
+        matrix.par_row_reduce_reuse(&mut pivots);
 
         if !self.has_computed_bidegree(s + 1, t) {
             let kernel = matrix.compute_kernel();
@@ -631,7 +932,8 @@ where
                 }
             }
         }
-        let (cm_qi, res_qi) = matrix.compute_quasi_inverses();
+        let (cm_qi, res_qi) = matrix.compute_quasi_inverses_reuse(&mut pivots);
+        PIVOTS.set(pivots);
 
         timer.end(format_args!(
             "Computed bidegree ({n}, {s}), num new gens = {num_new_gens}, density = {density:.2}%",
@@ -641,48 +943,9 @@ where
 
         if self.should_save {
             if let Some(dir) = &self.save_dir {
-                // Write differentials last, because if we were terminated halfway, we want the
-                // differentials to exist iff everything has been written. However, we start by
-                // opening the differentials first to make sure we are not overwriting anything.
-
-                // Open differentials file
-                let mut f = self
-                    .save_file(SaveKind::Differential, s, t)
-                    .create_file(dir.clone(), false);
-
-                // Write resolution qi
-                res_qi
-                    .to_bytes(
-                        &mut self
-                            .save_file(SaveKind::ResQi, s, t)
-                            .create_file(dir.clone(), true),
-                    )
-                    .unwrap();
-
-                // Write augmentation qi
-                cm_qi
-                    .to_bytes(
-                        &mut self
-                            .save_file(SaveKind::AugmentationQi, s, t)
-                            .create_file(dir.clone(), true),
-                    )
+                self.write_bidegree(dir, s, t, Some(&res_qi), Some(&cm_qi))
                     .unwrap();
 
-                // Write differentials
-                f.write_u64::<LittleEndian>(num_new_gens as u64).unwrap();
-                f.write_u64::<LittleEndian>(target_res_dimension as u64)
-                    .unwrap();
-                f.write_u64::<LittleEndian>(target_cc_dimension as u64)
-                    .unwrap();
-
-                for n in 0..num_new_gens {
-                    current_differential.output(t, n).to_bytes(&mut f).unwrap();
-                }
-                for n in 0..num_new_gens {
-                    current_chain_map.output(t, n).to_bytes(&mut f).unwrap();
-                }
-                drop(f);
-
                 // Delete kernel
                 if s > 0 {
                     self.save_file(SaveKind::Kernel, s - 1, t)
@@ -706,14 +969,284 @@ where
 
         current_differential.set_kernel(t, None);
         current_differential.set_image(t, None);
+        Ok(())
+    }
+
+    /// Prepare the resolution for a computation up to `(max_s, max_t)`. This allocates the
+    /// modules and homomorphisms up to homological degree `max_s` and computes the basis of the
+    /// algebra and the target complex in the range, but does *not* resolve anything. This is
+    /// useful for excluding these setup costs when timing a subsequent `compute_through_*` call.
+    pub fn preallocate(&self, max_s: u32, max_t: i32) {
+        let _lock = self.lock.lock();
+
+        self.target().compute_through_bidegree(max_s, max_t);
+        self.extend_through_degree(max_s);
+        self.algebra().compute_basis(max_t - self.min_degree());
+    }
+
+    /// Write the data of the bidegree `(s, t)` to `dir`. The quasi-inverses are only written if
+    /// they are supplied.
+    fn write_bidegree(
+        &self,
+        dir: &Path,
+        s: u32,
+        t: i32,
+        res_qi: Option<&QuasiInverse>,
+        cm_qi: Option<&QuasiInverse>,
+    ) -> std::io::Result<()> {
+        let dir = dir.to_path_buf();
+        let d = self.differential(s);
+        let c = self.chain_map(s);
+
+        // Write differentials last, because if we were terminated halfway, we want the
+        // differentials to exist iff everything has been written. However, we start by
+        // opening the differentials first to make sure we are not overwriting anything.
+
+        // Open differentials file
+        let mut f = self
+            .save_file(SaveKind::Differential, s, t)
+            .create_file(dir.clone(), false);
+
+        // Write resolution qi
+        if let Some(qi) = res_qi {
+            qi.to_bytes(
+                &mut self
+                    .save_file(SaveKind::ResQi, s, t)
+                    .create_file(dir.clone(), true),
+            )?;
+        }
+
+        // Write augmentation qi
+        if let Some(qi) = cm_qi {
+            qi.to_bytes(
+                &mut self
+                    .save_file(SaveKind::AugmentationQi, s, t)
+                    .create_file(dir.clone(), true),
+            )?;
+        }
+
+        // Write differentials
+        let num_new_gens = d.source().number_of_gens_in_degree(t);
+        // If we loaded from a larger save, the outputs may be longer than the current dimension of
+        // the target.
+        let target_res_dimension = if num_new_gens > 0 {
+            d.output(t, 0).len()
+        } else {
+            d.target().dimension(t)
+        };
+        f.write_u64::<LittleEndian>(num_new_gens as u64)?;
+        f.write_u64::<LittleEndian>(target_res_dimension as u64)?;
+        f.write_u64::<LittleEndian>(c.target().dimension(t) as u64)?;
+
+        for n in 0..num_new_gens {
+            d.output(t, n).to_bytes(&mut f)?;
+        }
+        for n in 0..num_new_gens {
+            c.output(t, n).to_bytes(&mut f)?;
+        }
+        Ok(())
+    }
+
+    /// Open the saved differential of `(s, t)` in `dir`, if there is one. A partially written
+    /// file, see [`MuResolution::is_partially_saved`], is treated as missing so that the bidegree
+    /// is recomputed. It is deleted if `should_save` is set, since it is then going to be
+    /// rewritten, and left alone otherwise.
+    fn open_differential(
+        &self,
+        dir: &Path,
+        s: u32,
+        t: i32,
+    ) -> anyhow::Result<Option<Box<dyn Read>>> {
+        let differential_file = self.save_file(SaveKind::Differential, s, t);
+        if !differential_file.exists(dir.to_path_buf()) {
+            return Ok(None);
+        }
+        if self.is_partially_saved(dir, s, t)? {
+            if self.should_save {
+                differential_file
+                    .delete_file(dir.to_path_buf())
+                    .with_context(|| {
+                        format!("Failed to delete partially saved differential at ({s}, {t})")
+                    })?;
+            }
+            return Ok(None);
+        }
+        Ok(differential_file.open_file(dir.to_path_buf()))
+    }
+
+    /// Whether the differential of `(s, t)` was only partially written to `dir`, which happens if
+    /// we were terminated halfway. This compares the length of the file with the length implied
+    /// by the dimensions at the start of the file. Complete files that are corrupted are instead
+    /// caught by the checksum when loading.
+    fn is_partially_saved(&self, dir: &Path, s: u32, t: i32) -> std::io::Result<bool> {
+        // Compressed files are never written by us directly
+        let path = self
+            .save_file(SaveKind::Differential, s, t)
+            .get_save_path(dir.to_path_buf());
+        let Ok(mut f) = std::fs::File::open(path) else {
+            return Ok(false);
+        };
+        let file_len = f.metadata()?.len() as usize;
+
+        // The header consists of four u32's
+        const HEADER_LEN: usize = 16;
+        let mut dims = [0; 3];
+        if f.seek(SeekFrom::Start(HEADER_LEN as u64)).is_err()
+            || f.read_u64_into::<LittleEndian>(&mut dims).is_err()
+        {
+            return Ok(true);
+        }
+        let [num_new_gens, target_res_dimension, target_cc_dimension] = dims.map(|x| x as usize);
+
+        let p = self.prime();
+        let vector_len = FpVector::num_bytes(p, target_res_dimension)
+            + FpVector::num_bytes(p, target_cc_dimension);
+        // The trailing u32 is the checksum
+        Ok(file_len < HEADER_LEN + 3 * 8 + num_new_gens * vector_len + 4)
+    }
+
+    /// Write the bidegree `(s, t)` to the save directory, unless it is already there. This is
+    /// only needed if `should_save` was unset when the bidegree was computed. A partially written
+    /// save file is replaced. The quasi-inverses are only written if they are held in memory.
+    pub fn save_bidegree(&self, s: u32, t: i32) -> anyhow::Result<()> {
+        let dir = self
+            .save_dir
+            .as_deref()
+            .context("Resolution has no save directory")?;
+        assert!(
+            self.has_computed_bidegree(s, t),
+            "Bidegree ({s}, {t}) has not been computed"
+        );
+        let differential_file = self.save_file(SaveKind::Differential, s, t);
+        if differential_file.exists(dir.to_path_buf()) && self.is_partially_saved(dir, s, t)? {
+            differential_file.delete_file(dir.to_path_buf())?;
+        }
+        if !differential_file.exists(dir.to_path_buf()) {
+            self.write_bidegree(
+                dir,
+                s,
+                t,
+                self.differential(s).quasi_inverse(t),
+                self.chain_map(s).quasi_inverse(t),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Write the computed part of the resolution to `save_dir`, in the same format as a resolution
+    /// constructed with a save directory. Bidegrees whose differential is already present in
+    /// `save_dir` are skipped, so repeatedly checkpointing a growing resolution only writes the
+    /// new bidegrees. Returns the number of bidegrees written.
+    ///
+    /// Quasi-inverses are only written if they are held in memory.
+    pub fn save_incremental(&self, save_dir: &Path) -> anyhow::Result<usize> {
+        for kind in SaveKind::resolution_data() {
+            kind.create_dir(save_dir)?;
+        }
+        let mut written = 0;
+
+        for (s, d) in self.differentials.iter().enumerate() {
+            let s = s as u32;
+            let c = &self.chain_maps[s as usize];
+            for t in self.min_degree()..d.next_degree() {
+                if self
+                    .save_file(SaveKind::Differential, s, t)
+                    .exists(save_dir.to_path_buf())
+                {
+                    continue;
+                }
+                self.write_bidegree(save_dir, s, t, d.quasi_inverse(t), c.quasi_inverse(t))?;
+                written += 1;
+            }
+        }
+        Ok(written)
+    }
+
+    /// Load all bidegrees that are present in the save directory, without computing anything.
+    /// Bidegrees whose save file was only partially written, e.g. because the program was killed
+    /// halfway, are discarded. The computation can then be resumed with the `compute_through_*`
+    /// functions, which only compute the missing bidegrees. Returns the number of bidegrees
+    /// loaded, or an error if reading the save files fails.
+    pub fn load_partial(&self) -> anyhow::Result<usize> {
+        let Some(dir) = &self.save_dir else {
+            return Ok(0);
+        };
+        let min_degree = self.min_degree();
+        let _lock = self.lock.lock();
+
+        let mut loaded = 0;
+        // Loading (s, t) requires (s, t - 1) and (s - 1, t - 1) to be known, so we proceed by t.
+        // In degree `min_degree` there are no dependencies, but every s is loaded if present.
+        for t in min_degree.. {
+            let mut any_computed = false;
+            let max_s = if t == min_degree {
+                u32::MAX
+            } else {
+                self.modules.len() as u32
+            };
+            for s in 0..max_s {
+                if !self.has_computed_bidegree(s, t) {
+                    let dependencies_known = t == min_degree
+                        || (self.has_computed_bidegree(s, t - 1)
+                            && (s == 0 || self.has_computed_bidegree(s - 1, t - 1)));
+                    if !dependencies_known
+                        || !self
+                            .save_file(SaveKind::Differential, s, t)
+                            .exists(dir.clone())
+                        || self.is_partially_saved(dir, s, t)?
+                    {
+                        if t == min_degree {
+                            break;
+                        }
+                        continue;
+                    }
+                    self.target().compute_through_bidegree(s, t);
+                    self.extend_through_degree(s);
+                    self.algebra().compute_basis(t - min_degree);
+                    self.step_resolution_with_result(s, t)?;
+                    loaded += 1;
+                }
+                any_computed = true;
+            }
+            if !any_computed {
+                break;
+            }
+        }
+        Ok(loaded)
     }
 
     pub fn compute_through_bidegree_with_callback(
         &self,
         max_s: u32,
         max_t: i32,
-        mut cb: impl FnMut(u32, i32),
+        cb: impl FnMut(u32, i32),
     ) {
+        self.compute_through_bidegree_inner(max_s, max_t, &AtomicBool::new(false), cb);
+    }
+
+    /// Resolve through `(max_s, max_t)`, but stop early once `cancel` is set. The flag is checked
+    /// before each bidegree is computed, and bidegrees that are being computed when the flag is
+    /// set are allowed to finish. Thus a cancelled computation still leaves behind a valid partial
+    /// resolution, which can be extended further by calling this or any other `compute_through_*`
+    /// function again.
+    ///
+    /// Returns `true` if the computation ran to completion and `false` if it was cancelled.
+    pub fn compute_through_bidegree_cancellable(
+        &self,
+        max_s: u32,
+        max_t: i32,
+        cancel: &AtomicBool,
+    ) -> bool {
+        self.compute_through_bidegree_inner(max_s, max_t, cancel, |_, _| ())
+    }
+
+    fn compute_through_bidegree_inner(
+        &self,
+        max_s: u32,
+        max_t: i32,
+        cancel: &AtomicBool,
+        mut cb: impl FnMut(u32, i32),
+    ) -> bool {
         let min_degree = self.min_degree();
         let _lock = self.lock.lock();
 
@@ -721,12 +1254,18 @@ where
         self.extend_through_degree(max_s);
         self.algebra().compute_basis(max_t - min_degree);
 
+        let mut cancelled = false;
+
         #[cfg(not(feature = "concurrent"))]
-        for t in min_degree..=max_t {
+        'outer: for t in min_degree..=max_t {
             for s in 0..=max_s {
                 if self.has_computed_bidegree(s, t) {
                     continue;
                 }
+                if cancel.load(Ordering::Relaxed) {
+                    cancelled = true;
+                    break 'outer;
+                }
                 self.step_resolution(s, t);
                 cb(s, t);
             }
@@ -742,10 +1281,15 @@ where
 
             let (sender, receiver) = mpsc::channel();
             SenderData::send(0, min_degree - 1, false, sender);
+            let mut pending = BTreeSet::from([(0, min_degree - 1)]);
 
-            let f = |s, t, sender| {
+            let mut f = |s, t, sender| {
                 if self.has_computed_bidegree(s, t) {
                     SenderData::send(s, t, false, sender);
+                } else if cancel.load(Ordering::Relaxed) {
+                    // Drop the sender without scheduling anything. Once the running steps finish,
+                    // all senders are dropped and the loop below terminates.
+                    cancelled = true;
                 } else {
                     scope.spawn(move |_| {
                         self.step_resolution(s, t);
@@ -754,15 +1298,20 @@ where
                 }
             };
 
-            while let Ok(SenderData { s, t, new, sender }) = receiver.recv() {
+            while let Some(SenderData { s, t, new, sender }) =
+                self.recv_progress(&receiver, &pending)
+            {
                 assert!(progress[s as usize] == t - 1);
                 progress[s as usize] = t;
+                pending.remove(&(s, t));
 
                 if t < max_t && (s == 0 || progress[s as usize - 1] > t) {
                     // We are computing a normal step
+                    pending.insert((s, t + 1));
                     f(s, t + 1, sender.clone());
                 }
                 if s < max_s && progress[s as usize + 1] == t - 1 {
+                    pending.insert((s + 1, t));
                     f(s + 1, t, sender);
                 }
                 if new {
@@ -770,9 +1319,55 @@ where
                 }
             }
         });
+
+        self.drop_unstored_kernels();
+        !cancelled
     }
 
-    /// This function resolves up till a fixed stem instead of a fixed t.
+    /// Wait for the workers to report the next finished bidegree, returning `None` once all of them
+    /// are done. This enforces [`MuResolution::watchdog_timeout`], in which case `pending` is the
+    /// set of bidegrees that have been scheduled but have not yet finished.
+    #[cfg(feature = "concurrent")]
+    fn recv_progress(
+        &self,
+        receiver: &mpsc::Receiver<SenderData>,
+        pending: &BTreeSet<(u32, i32)>,
+    ) -> Option<SenderData> {
+        let Some(timeout) = self.watchdog_timeout else {
+            return receiver.recv().ok();
+        };
+        match receiver.recv_timeout(timeout) {
+            Ok(data) => Some(data),
+            Err(mpsc::RecvTimeoutError::Disconnected) => None,
+            Err(mpsc::RecvTimeoutError::Timeout) => panic!(
+                "Resolution made no progress in {timeout:?}. Pending bidegrees (s, t): {pending:?}"
+            ),
+        }
+    }
+
+    /// Resolve through each `(max_s, max_t)` in `stages` in turn, reusing the work of the previous
+    /// stages. A typical use is to first resolve a wide range of degrees in low filtration, and
+    /// then increase the filtration. The end result is the same as resolving through the last
+    /// stage directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stages are not monotone, i.e. if `max_s` or `max_t` ever decreases.
+    pub fn resolve_staged(&self, stages: &[(u32, i32)]) {
+        for ((s1, t1), (s2, t2)) in stages.iter().tuple_windows() {
+            assert!(
+                s1 <= s2 && t1 <= t2,
+                "Stage ({s2}, {t2}) does not contain the previous stage ({s1}, {t1})"
+            );
+        }
+        for &(max_s, max_t) in stages {
+            self.compute_through_bidegree(max_s, max_t);
+        }
+    }
+
+    /// This function resolves up till a fixed stem instead of a fixed t. More precisely, it
+    /// resolves every bidegree $(s, t)$ with $s \leq \mathtt{max\_s}$ and $t - s \leq
+    /// \mathtt{max\_n}$, which is the triangular region displayed in an Adams chart.
     pub fn compute_through_stem(&self, max_s: u32, max_n: i32) {
         self.compute_through_stem_with_callback(max_s, max_n, |_, _| ());
     }
@@ -813,6 +1408,7 @@ where
 
             let (sender, receiver) = mpsc::channel();
             SenderData::send(0, min_degree - 1, false, sender);
+            let mut pending = BTreeSet::from([(0, min_degree - 1)]);
 
             let f = |s, t, sender| {
                 if self.has_computed_bidegree(s, t) {
@@ -825,23 +1421,30 @@ where
                 }
             };
 
-            while let Ok(SenderData { s, t, new, sender }) = receiver.recv() {
+            while let Some(SenderData { s, t, new, sender }) =
+                self.recv_progress(&receiver, &pending)
+            {
                 assert!(progress[s as usize] == t - 1);
                 progress[s as usize] = t;
+                pending.remove(&(s, t));
 
                 // How far we are from the last one for this s.
                 let distance = max_n + 1 - (t - s as i32);
 
                 if s < max_s && progress[s as usize + 1] == t - 1 {
+                    pending.insert((s + 1, t));
                     f(s + 1, t, sender.clone());
                 }
 
                 if distance > 1 && (s == 0 || progress[s as usize - 1] > t) {
                     // We are computing a normal step
+                    pending.insert((s, t + 1));
                     f(s, t + 1, sender);
                 } else if distance == 1 && s < max_s {
+                    pending.insert((s, t + 1));
                     // We compute the kernel at the edge if necessary
-                    if !self.has_computed_bidegree(s + 1, t + 1)
+                    if self.store_kernels
+                        && !self.has_computed_bidegree(s + 1, t + 1)
                         && (self.save_dir.is_none()
                             || !self
                                 .save_file(SaveKind::Differential, s + 1, t + 1)
@@ -860,6 +1463,30 @@ where
                 }
             }
         });
+
+        self.drop_unstored_kernels();
+    }
+
+    /// Drop the cached kernels if we were asked not to store them.
+    fn drop_unstored_kernels(&self) {
+        if !self.store_kernels {
+            self.kernels.clear();
+        }
+    }
+}
+
+impl<CC: ChainComplex> Resolution<CC> {
+    /// Construct a null-homotopy of `product`, which is a chain map from this resolution to
+    /// itself, e.g. the lift of a product of two classes. This returns `None` if `product` is not
+    /// null-homotopic on the bidegrees where it is currently defined, i.e. if the product is
+    /// nonzero in homology. See [`ChainHomotopy::nullhomotopy`] for details.
+    pub fn nullhomotopy(
+        &self,
+        product: &Arc<ResolutionHomomorphism<Self, Self>>,
+    ) -> Option<ChainHomotopy<Self, Self, Self>> {
+        assert!(std::ptr::eq(&*product.source, self));
+        assert!(std::ptr::eq(&*product.target, self));
+        ChainHomotopy::nullhomotopy(Arc::clone(product))
     }
 }
 
@@ -948,12 +1575,57 @@ where
     }
 }
 
+/// List the bidegrees in which `a` and `b` have different numbers of generators, as tuples
+/// `(s, t, count_a, count_b)` sorted by `s` and then by `t`. A bidegree that has not been computed
+/// in one of the resolutions counts as having no generators there. This is useful for comparing
+/// the results of two algorithms.
+pub fn resolution_diff<const U: bool, CC1: ChainComplex, CC2: ChainComplex>(
+    a: &MuResolution<U, CC1>,
+    b: &MuResolution<U, CC2>,
+) -> Vec<(u32, i32, usize, usize)>
+where
+    CC1::Algebra: MuAlgebra<U>,
+    CC2::Algebra: MuAlgebra<U>,
+{
+    let mut counts: std::collections::BTreeMap<(u32, i32), (usize, usize)> = Default::default();
+    for (s, t, n) in a.gens_in_region(u32::MAX, i32::MAX) {
+        counts.entry((s, t)).or_default().0 = n;
+    }
+    for (s, t, n) in b.gens_in_region(u32::MAX, i32::MAX) {
+        counts.entry((s, t)).or_default().1 = n;
+    }
+    counts
+        .into_iter()
+        .filter(|(_, (count_a, count_b))| count_a != count_b)
+        .map(|((s, t), (count_a, count_b))| (s, t, count_a, count_b))
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{chain_complex::FreeChainComplex, utils::construct_standard};
     use expect_test::expect;
 
+    #[cfg(feature = "concurrent")]
+    #[test]
+    fn test_watchdog() {
+        let mut res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.watchdog_timeout = Some(Duration::from_millis(100));
+
+        // The supervisor occupies the only thread of this pool, so the steps it spawns never run.
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let err = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            pool.install(|| res.compute_through_bidegree(2, 2))
+        }))
+        .unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(msg.ends_with("Pending bidegrees (s, t): {(0, 0)}"), "{msg}");
+    }
+
     #[test]
     fn test_restart_stem() {
         let res = construct_standard::<false, _, _>("S_2", None).unwrap();
@@ -974,6 +1646,665 @@ mod test {
         .assert_eq(&res.graded_dimension_string());
     }
 
+    #[test]
+    fn test_compute_through_stem_region() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        let (max_s, max_n) = (6, 10);
+
+        let mut computed = Vec::new();
+        res.compute_through_stem_with_callback(max_s, max_n, |s, t| computed.push((s, t)));
+        computed.sort_unstable();
+
+        let mut expected = Vec::new();
+        for s in 0..=max_s {
+            for t in res.min_degree()..=s as i32 + max_n {
+                expected.push((s, t));
+            }
+        }
+        assert_eq!(computed, expected);
+
+        for s in 0..=max_s {
+            assert!(!res.has_computed_bidegree(s, s as i32 + max_n + 1));
+        }
+        assert!(!res.has_computed_bidegree(max_s + 1, max_s as i32 + 1));
+    }
+
+    #[test]
+    fn test_without_kernel_storage() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(10, 20);
+
+        let complex = res.target();
+        let res_no_kernel = Resolution::new_without_kernel_storage(complex);
+        res_no_kernel.compute_through_stem(6, 12);
+        assert!(res_no_kernel.kernels.is_empty());
+        res_no_kernel.compute_through_bidegree(4, 18);
+        assert!(res_no_kernel.kernels.is_empty());
+        res_no_kernel.compute_through_stem(10, 20);
+
+        assert_eq!(res.rank_table(), res_no_kernel.rank_table());
+    }
+
+    #[test]
+    fn test_nonzero_differential_entries() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(4, 8);
+
+        // The differential out of homological degree 0 lands in the zero module
+        assert_eq!(res.nonzero_differential_entries(0, 0), 0);
+        // There are no generators in (s, t) = (1, 3)
+        assert_eq!(res.nonzero_differential_entries(1, 3), 0);
+        // h_0 and h_1 map to Sq1 x_0 and Sq2 x_0 respectively
+        assert_eq!(res.nonzero_differential_entries(1, 1), 1);
+        assert_eq!(res.nonzero_differential_entries(1, 2), 1);
+        assert!(res.nonzero_differential_entries(2, 4) > 0);
+    }
+
+    #[test]
+    fn test_chain_map_matrix() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(2, 4);
+
+        let matrix = res.chain_map_matrix(0, 0);
+        assert_eq!(matrix.rows(), 1);
+        assert_eq!(matrix.columns(), 1);
+        assert_eq!(matrix.row(0).entry(0), 1);
+
+        // The sphere is zero above degree 0
+        let matrix = res.chain_map_matrix(0, 2);
+        assert_eq!(matrix.rows(), res.module(0).dimension(2));
+        assert_eq!(matrix.columns(), 0);
+    }
+
+    #[test]
+    fn test_verify_augmentation() {
+        let mut res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(6, 10);
+        assert_eq!(res.verify_augmentation(16), Ok(()));
+
+        // Replace the augmentation by the zero map
+        let f = res.chain_map(0);
+        let zero = MuFreeModuleHomomorphism::new(f.source(), f.target(), 0);
+        zero.extend_by_zero(f.next_degree() - 1);
+        *res.chain_maps.get_mut(0).unwrap() = Arc::new(zero);
+        assert_eq!(
+            res.verify_augmentation(16),
+            Err("Augmentation map is not surjective in degree 0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_verify_augmentation_not_chain_map() {
+        // C2 ⊕ S_2, so that the augmentation can be replaced by a surjection that does not
+        // commute with Sq^1.
+        let module = serde_json::json!({
+            "type": "finite dimensional module",
+            "p": 2,
+            "gens": { "x0": 0, "x1": 1, "y0": 0 },
+            "actions": ["Sq1 x0 = x1"]
+        });
+        let mut res =
+            construct_standard::<false, _, _>((module, algebra::AlgebraType::Milnor), None)
+                .unwrap();
+        res.compute_through_stem(2, 4);
+        assert_eq!(res.verify_augmentation(6), Ok(()));
+
+        // Swap the images of the two generators in degree 0
+        let f = res.chain_map(0);
+        let swapped = MuFreeModuleHomomorphism::new(f.source(), f.target(), 0);
+        for t in res.min_degree()..f.next_degree() {
+            let mut rows: Vec<FpVector> = (0..f.source().number_of_gens_in_degree(t))
+                .map(|idx| f.output(t, idx).clone())
+                .collect();
+            if t == 0 {
+                rows.reverse();
+            }
+            swapped.add_generators_from_rows(t, rows);
+        }
+        *res.chain_maps.get_mut(0).unwrap() = Arc::new(swapped);
+
+        let err = res.verify_augmentation(6).unwrap_err();
+        assert!(
+            err.starts_with("Augmentation is not a chain map on generator 0 in (s, t) = (1, 1)"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_rank_table() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(8, 14);
+
+        let table = res.rank_table();
+        assert_eq!(table.len(), 9);
+        for (s, row) in table.iter_enum() {
+            assert_eq!(row.min_degree(), res.min_degree());
+            for (t, &rank) in row.iter_enum() {
+                assert_eq!(rank, res.number_of_gens_in_bidegree(s as u32, t));
+            }
+        }
+    }
+
+    #[test]
+    fn test_assert_ranks_eq() {
+        let res = construct_standard::<false, _, _>("C2", None).unwrap();
+        res.compute_through_bidegree(3, 6);
+
+        // Generators of Ext(C2) by s, for t = 0..=6
+        let reference = [
+            vec![1, 0, 0, 0, 0, 0, 0],
+            vec![0, 0, 1, 1, 1, 0, 0],
+            vec![0, 0, 0, 0, 1, 1, 0],
+            vec![0, 0, 0, 0, 0, 0, 0],
+        ];
+        let mut reference = BiVec::from_vec(
+            0,
+            reference
+                .into_iter()
+                .map(|row| BiVec::from_vec(0, row))
+                .collect(),
+        );
+        assert_eq!(res.assert_ranks_eq(&reference), Ok(()));
+
+        reference[2][4] = 2;
+        assert_eq!(
+            res.assert_ranks_eq(&reference),
+            Err("Rank mismatch in (s, t) = (2, 4): expected 2, got 1".to_string())
+        );
+
+        reference[2][4] = 1;
+        reference[3].push(0);
+        assert_eq!(
+            res.assert_ranks_eq(&reference),
+            Err("Bidegree (s, t) = (3, 7) has not been computed".to_string())
+        );
+    }
+
+    #[test]
+    fn test_memory_estimate() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        assert_eq!(res.memory_estimate(), 0);
+
+        res.compute_through_stem(4, 8);
+        let small = res.memory_estimate();
+        assert!(small > 0);
+
+        res.compute_through_stem(8, 16);
+        let large = res.memory_estimate();
+        assert!(large > small, "{large} <= {small}");
+    }
+
+    #[test]
+    fn test_lift() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_bidegree(4, 12);
+        let p = res.prime();
+        let d1 = res.differential(1);
+
+        // A map lifts through a differential that it factors through
+        let h = d1.lift(&d1).unwrap();
+        assert_eq!(h.next_degree(), d1.next_degree());
+        for t in 0..=12 {
+            let dim = res.module(0).dimension(t);
+            for i in 0..res.module(1).number_of_gens_in_degree(t) {
+                let mut image = FpVector::new(p, dim);
+                d1.apply(image.as_slice_mut(), 1, t, h.output(t, i).as_slice());
+                assert_eq!(&image, d1.output(t, i));
+            }
+        }
+
+        // The identity of F_0 does not lift since the generator is not a boundary.
+        let module = res.module(0);
+        let id = MuFreeModuleHomomorphism::new(Arc::clone(&module), Arc::clone(&module), 0);
+        for t in 0..=12 {
+            let rows = (0..module.number_of_gens_in_degree(t))
+                .map(|i| {
+                    let mut v = FpVector::new(p, module.dimension(t));
+                    v.set_entry(module.operation_generator_to_index(0, 0, t, i), 1);
+                    v
+                })
+                .collect();
+            id.add_generators_from_rows(t, rows);
+        }
+        assert!(id.lift(&d1).is_none());
+    }
+
+    #[test]
+    fn test_total_rank() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        assert_eq!(res.total_rank(), 0);
+        assert_eq!(res.rank_in_filtration(1), 0);
+
+        res.compute_through_stem(4, 20);
+        // h_0, ..., h_4 in t = 1, 2, 4, 8, 16
+        assert_eq!(res.rank_in_filtration(1), 5);
+        assert_eq!(res.rank_in_filtration(0), 1);
+        assert_eq!(res.rank_in_filtration(5), 0);
+
+        let table = res.rank_table();
+        assert_eq!(
+            res.total_rank(),
+            table.iter().flat_map(|row| row.iter()).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn test_is_boundary() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_bidegree(3, 6);
+        let module = res.module(1);
+
+        // d(h_0^2) = Sq^1 h_0
+        let boundary = res.differential_output(2, 2, 0);
+        assert!(!boundary.is_zero());
+        assert!(res.is_boundary(1, 2, boundary));
+
+        // h_1 survives
+        let mut h1 = FpVector::new(res.prime(), module.dimension(2));
+        h1.set_entry(module.operation_generator_to_index(0, 0, 2, 0), 1);
+        assert!(!res.is_boundary(1, 2, &h1));
+
+        h1.add(boundary, 1);
+        assert!(!res.is_boundary(1, 2, &h1));
+    }
+
+    #[test]
+    fn test_preallocate() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.preallocate(5, 15);
+        assert_eq!(res.next_homological_degree(), 6);
+        assert!(!res.has_computed_bidegree(0, 0));
+        assert!(res.algebra().computed_through() >= 15);
+
+        res.compute_through_bidegree(5, 15);
+
+        let expected = construct_standard::<false, _, _>("S_2", None).unwrap();
+        expected.compute_through_bidegree(5, 15);
+        assert_eq!(
+            res.graded_dimension_string(),
+            expected.graded_dimension_string()
+        );
+    }
+
+    #[test]
+    fn test_resolution_diff() {
+        let a = construct_standard::<false, _, _>("S_2", None).unwrap();
+        a.compute_through_stem(6, 12);
+        let b = construct_standard::<false, _, _>("S_2", None).unwrap();
+        b.compute_through_stem(6, 12);
+        assert_eq!(resolution_diff(&a, &b), []);
+
+        let c = construct_standard::<false, _, _>("S_2", None).unwrap();
+        c.compute_through_stem(6, 8);
+        let diff = resolution_diff(&a, &c);
+        assert!(!diff.is_empty());
+        for &(s, t, count_a, count_c) in &diff {
+            assert_eq!(count_a, a.number_of_gens_in_bidegree(s, t));
+            assert_eq!(count_c, 0);
+            assert!(!c.has_computed_bidegree(s, t));
+        }
+        // h_1^2 h_3 and P h_1 in stem 9
+        assert!(diff.contains(&(3, 12, 1, 0)));
+        assert!(diff.contains(&(5, 14, 1, 0)));
+
+        let diff = resolution_diff(&c, &a);
+        assert!(diff.contains(&(3, 12, 0, 1)));
+    }
+
+    #[test]
+    fn test_gens_in_region() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(6, 12);
+
+        let mut expected = Vec::new();
+        for s in 0..=4 {
+            for t in 0..=15 {
+                if res.has_computed_bidegree(s, t) && res.number_of_gens_in_bidegree(s, t) > 0 {
+                    expected.push((s, t, res.number_of_gens_in_bidegree(s, t)));
+                }
+            }
+        }
+        assert_eq!(res.gens_in_region(4, 15), expected);
+        assert_eq!(res.gens_in_region(0, 15), [(0, 0, 1)]);
+    }
+
+    #[test]
+    fn test_global_generator_index() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_bidegree(6, 20);
+
+        for s in 0..=6 {
+            let mut expected = 0;
+            for t in 0..=20 {
+                for idx in 0..res.number_of_gens_in_bidegree(s, t) {
+                    let global = res.global_generator_index(s, t, idx);
+                    assert_eq!(global, expected);
+                    assert_eq!(res.generator_from_global_index(s, global), (t, idx));
+                    expected += 1;
+                }
+            }
+        }
+        // h_0^2 and h_1^2 are the first two generators in s = 2
+        assert_eq!(res.generator_from_global_index(2, 0), (2, 0));
+        assert_eq!(res.generator_from_global_index(2, 1), (4, 0));
+    }
+
+    #[test]
+    fn test_cancel() {
+        let expected = construct_standard::<false, _, _>("S_2", None).unwrap();
+        expected.compute_through_bidegree(10, 30);
+
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        let cancel = AtomicBool::new(false);
+        let mut count = 0;
+        let finished = res.compute_through_bidegree_inner(10, 30, &cancel, |_, _| {
+            count += 1;
+            if count == 50 {
+                cancel.store(true, Ordering::Relaxed);
+            }
+        });
+        assert!(!finished);
+        assert!(!res.has_computed_bidegree(10, 30));
+
+        // Cancelling again does nothing
+        assert!(!res.compute_through_bidegree_cancellable(10, 30, &cancel));
+
+        cancel.store(false, Ordering::Relaxed);
+        assert!(res.compute_through_bidegree_cancellable(10, 30, &cancel));
+        assert_eq!(
+            res.graded_dimension_string(),
+            expected.graded_dimension_string()
+        );
+        assert!(res.is_minimal(10, 30));
+    }
+
+    #[test]
+    fn test_gens_in_stem() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(8, 14);
+
+        let stem_0 = res.gens_in_stem(0);
+        assert_eq!(stem_0.len(), 9);
+        assert!(stem_0.iter().all(|&n| n == 1));
+
+        for n in 0..=14 {
+            for (s, &num_gens) in res.gens_in_stem(n).iter_enum() {
+                assert_eq!(num_gens, res.number_of_gens_in_bidegree(s as u32, n + s));
+            }
+        }
+    }
+
+    #[cfg(feature = "concurrent")]
+    #[test]
+    fn test_filtration_one_products() {
+        use crate::chain_complex::filtration_one_products_inner;
+
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_bidegree(20, 40);
+
+        for (_, op_deg, op_idx) in res.algebra().default_filtration_one_products() {
+            let parallel = filtration_one_products_inner(&res, op_deg, op_idx, true);
+            let sequential = filtration_one_products_inner(&res, op_deg, op_idx, false);
+            assert_eq!(parallel.matrices.min_degree(), res.min_degree());
+            assert_eq!(
+                parallel.matrices.max_degree(),
+                res.module(0).max_computed_degree() - op_deg + 2
+            );
+            assert_eq!(parallel.matrices, sequential.matrices);
+        }
+    }
+
+    #[test]
+    fn test_is_minimal() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_bidegree(10, 30);
+        assert!(res.is_minimal(10, 30));
+
+        let res = construct_standard::<false, _, _>("S_3", None).unwrap();
+        res.compute_through_bidegree(6, 50);
+        assert!(res.is_minimal(6, 50));
+
+        // Bidegrees beyond the computed range are skipped
+        assert!(res.is_minimal(20, 100));
+        assert!(res.is_minimal(u32::MAX, i32::MAX));
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        assert!(res.is_minimal(5, 5));
+    }
+
+    #[test]
+    fn test_is_not_minimal() {
+        use crate::chain_complex::FiniteChainComplex;
+        use algebra::module::homomorphism::FreeModuleHomomorphism;
+        use algebra::module::FreeModule;
+        use algebra::AdemAlgebra;
+
+        let max_degree = 5;
+        let algebra = Arc::new(AdemAlgebra::new(fp::prime::TWO, false));
+        algebra.compute_basis(max_degree);
+
+        let modules: Vec<Arc<FreeModule<AdemAlgebra>>> = (0..2)
+            .map(|_| {
+                let module = FreeModule::new(Arc::clone(&algebra), String::new(), 0);
+                module.add_generators(0, 1, None);
+                module.extend_by_zero(max_degree);
+                Arc::new(module)
+            })
+            .collect();
+
+        // The identity map on a free module is a (non-minimal) resolution of zero.
+        let d = FreeModuleHomomorphism::new(Arc::clone(&modules[1]), Arc::clone(&modules[0]), 0);
+        let mut output = FpVector::new(fp::prime::TWO, 1);
+        output.set_entry(0, 1);
+        d.add_generators_from_rows(0, vec![output]);
+        d.extend_by_zero(max_degree);
+
+        let cc = FiniteChainComplex::new(modules, vec![Arc::new(d)]);
+        assert!(!cc.is_minimal(1, max_degree));
+    }
+
+    #[test]
+    fn test_to_sseq_with_products() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(4, 4);
+
+        let (sseq, products) =
+            res.to_sseq_with_products(&res.algebra().default_filtration_one_products());
+        assert_eq!(sseq.dimension(3, 3), 1);
+
+        let names: Vec<&str> = products.iter().map(|(name, _)| &**name).collect();
+        assert_eq!(names, ["h_0", "h_1", "h_2", "h_3"]);
+
+        let matrix =
+            |prod: &sseq::Product, x: i32, y: i32| prod.matrices[x][y].as_ref().unwrap().to_vec();
+
+        let h0 = &products[0].1;
+        assert_eq!((h0.x, h0.y), (0, 1));
+        assert_eq!(matrix(h0, 0, 0), [[1]]);
+        assert_eq!(matrix(h0, 0, 1), [[1]]);
+        assert_eq!(matrix(h0, 1, 1), [Vec::<u32>::new()]);
+        assert_eq!(matrix(h0, 3, 1), [[1]]);
+
+        let h1 = &products[1].1;
+        assert_eq!((h1.x, h1.y), (1, 1));
+        assert_eq!(matrix(h1, 0, 0), [[1]]);
+        assert_eq!(matrix(h1, 0, 1), [Vec::<u32>::new()]);
+        assert_eq!(matrix(h1, 1, 1), [[1]]);
+        assert_eq!(matrix(h1, 2, 2), [[1]]);
+    }
+
+    #[test]
+    fn test_cohomological_to_sseq() {
+        use crate::chain_complex::ChainComplexGrading;
+
+        /// The same complex, but graded cohomologically
+        struct Cohomological<CC>(CC);
+
+        impl<CC: ChainComplex> ChainComplex for Cohomological<CC> {
+            type Algebra = CC::Algebra;
+            type Module = CC::Module;
+            type Homomorphism = CC::Homomorphism;
+
+            fn algebra(&self) -> Arc<Self::Algebra> {
+                self.0.algebra()
+            }
+            fn min_degree(&self) -> i32 {
+                self.0.min_degree()
+            }
+            fn zero_module(&self) -> Arc<Self::Module> {
+                self.0.zero_module()
+            }
+            fn module(&self, s: u32) -> Arc<Self::Module> {
+                self.0.module(s)
+            }
+            fn differential(&self, s: u32) -> Arc<Self::Homomorphism> {
+                self.0.differential(s)
+            }
+            fn has_computed_bidegree(&self, s: u32, t: i32) -> bool {
+                self.0.has_computed_bidegree(s, t)
+            }
+            fn compute_through_bidegree(&self, s: u32, t: i32) {
+                self.0.compute_through_bidegree(s, t)
+            }
+            fn next_homological_degree(&self) -> u32 {
+                self.0.next_homological_degree()
+            }
+            fn grading(&self) -> ChainComplexGrading {
+                ChainComplexGrading::Cohomological
+            }
+        }
+
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(4, 15);
+        let sseq = res.to_sseq();
+
+        let cc = Cohomological(res);
+        // iter_stem reports s - t, in increasing order of stem
+        let mut bidegrees: Vec<_> = cc.iter_stem().collect();
+        assert!(bidegrees
+            .iter()
+            .all(|&(s, n, t)| n == ChainComplexGrading::Cohomological.stem(s, t)));
+        assert!(bidegrees.windows(2).all(|w| w[0].1 <= w[1].1));
+        assert_eq!(bidegrees.first().map(|&(_, n, _)| n), Some(-15));
+        assert_eq!(bidegrees.last().map(|&(_, n, _)| n), Some(0));
+        bidegrees.sort_by_key(|&(s, _, t)| (t - s as i32, s));
+        assert!(bidegrees
+            .into_iter()
+            .map(|(s, _, t)| (s, t - s as i32, t))
+            .eq(cc.0.iter_stem()));
+
+        let mut cohomological_sseq = cc.to_cohomological_sseq();
+        assert_eq!(cohomological_sseq.min_x(), -15);
+        for (s, n, t) in cc.iter_stem() {
+            assert_eq!(
+                cohomological_sseq.dimension(n, s as i32),
+                sseq.dimension(-n, s as i32),
+                "Wrong dimension in bidegree ({s}, {t})"
+            );
+        }
+
+        // The Adams differential d_2(h_4) = h_0 h_3^2 goes from (15, 1) to (14, 3), which is
+        // (-15, 1) to (-14, 3) in the cohomological grading.
+        let p = cc.prime();
+        let one = FpVector::from_slice(p, &[1]);
+        assert_eq!(cohomological_sseq.dimension(-15, 1), 1);
+        assert_eq!(cohomological_sseq.dimension(-14, 3), 1);
+        cohomological_sseq.add_differential(2, -15, 1, one.as_slice(), one.as_slice());
+        cohomological_sseq.update();
+        assert!(cohomological_sseq.page_data(-15, 1)[3].is_empty());
+        assert!(cohomological_sseq.page_data(-14, 3)[3].is_empty());
+        assert_eq!(
+            cohomological_sseq
+                .differentials_hitting(-14, 3)
+                .map(|(r, _)| r)
+                .collect::<Vec<_>>(),
+            [2]
+        );
+
+        // The products are indexed by the cohomological stems as well
+        let (_, products) =
+            cc.to_cohomological_sseq_with_products(&cc.algebra().default_filtration_one_products());
+        let h1 = &products[1].1;
+        assert_eq!((h1.x, h1.y), (-1, 1));
+        assert_eq!(h1.matrices[-1][1].as_ref().unwrap().to_vec(), [[1]]);
+        assert!(cc
+            .to_svg(&cc.algebra().default_filtration_one_products())
+            .contains(r#"<line class="structline h_1""#));
+
+        // The Adams spectral sequence requires the homological grading
+        let cc = std::panic::AssertUnwindSafe(cc);
+        assert!(std::panic::catch_unwind(|| cc.to_sseq()).is_err());
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(8, 14);
+
+        let svg = res.to_svg(&res.algebra().default_filtration_one_products());
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+
+        let num_gens: usize = res
+            .iter_stem()
+            .map(|(s, _, t)| res.number_of_gens_in_bidegree(s, t))
+            .sum();
+        assert_eq!(svg.matches("<circle").count(), num_gens);
+        assert!(svg.contains(r#"<line class="structline h_0""#));
+    }
+
+    #[test]
+    fn test_deterministic_generators() {
+        for (module, max_s, max_n) in [("S_2", 8, 14), ("S_3", 20, 20)] {
+            let res1 = construct_standard::<false, _, _>(module, None).unwrap();
+            let res2 = construct_standard::<false, _, _>(module, None).unwrap();
+            // Compute the two resolutions in different orders
+            res1.compute_through_stem(max_s, max_n);
+            for (s, _, t) in res1.iter_stem() {
+                res2.compute_through_bidegree(s, t);
+            }
+
+            for (s, _, t) in res1.iter_stem() {
+                assert_eq!(
+                    res1.number_of_gens_in_bidegree(s, t),
+                    res2.number_of_gens_in_bidegree(s, t)
+                );
+                if s == 0 {
+                    continue;
+                }
+                for idx in 0..res1.number_of_gens_in_bidegree(s, t) {
+                    // The outputs may have different lengths since the target may have more
+                    // generators in res2, so we compare the strings instead.
+                    assert_eq!(
+                        res1.cocycle_string(s, t, idx),
+                        res2.cocycle_string(s, t, idx),
+                        "Differentials of {module} differ at ({s}, {t}, {idx})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_differential_output() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(8, 14);
+
+        for (s, _, t) in res.iter_stem() {
+            if s == 0 {
+                continue;
+            }
+            let target = res.module(s - 1);
+            for idx in 0..res.number_of_gens_in_bidegree(s, t) {
+                let output = res.differential_output(s, t, idx);
+                assert_eq!(output.len(), target.dimension(t));
+                assert_eq!(
+                    target.element_to_string_pretty(s, t, output.as_slice()),
+                    res.cocycle_string(s, t, idx)
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_apply_quasi_inverse() {
         let tempdir = tempfile::TempDir::new().unwrap();
@@ -992,4 +2323,150 @@ mod test {
         assert!(res.apply_quasi_inverse(&mut [w.as_slice_mut()], 8, 8, &[v.as_slice()]));
         assert!(w.is_zero());
     }
+
+    #[test]
+    fn test_load_partial() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+
+        let res = construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        res.compute_through_bidegree(3, 8);
+
+        let loaded = construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        assert_eq!(loaded.load_partial().unwrap(), 4 * 9);
+        assert_eq!(loaded.load_partial().unwrap(), 0);
+        assert_eq!(
+            loaded.graded_dimension_string(),
+            res.graded_dimension_string()
+        );
+
+        // Simulate being killed while writing (3, 8)
+        let path = tempdir.path().join("differentials/3_8_differential");
+        let data = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &data[..data.len() - 2]).unwrap();
+
+        let resumed =
+            construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        assert_eq!(resumed.load_partial().unwrap(), 4 * 9 - 1);
+        assert!(!resumed.has_computed_bidegree(3, 8));
+        resumed.compute_through_bidegree(3, 8);
+        assert_eq!(
+            resumed.graded_dimension_string(),
+            res.graded_dimension_string()
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), data);
+
+        // A resolution that does not save must leave the partial file alone
+        std::fs::write(&path, &data[..data.len() - 2]).unwrap();
+        let mut unsaved =
+            construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        unsaved.should_save = false;
+        unsaved.compute_through_bidegree(3, 8);
+        assert_eq!(
+            unsaved.graded_dimension_string(),
+            res.graded_dimension_string()
+        );
+        assert_eq!(std::fs::read(&path).unwrap(), &data[..data.len() - 2]);
+    }
+
+    #[test]
+    fn test_load_partial_stem() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+
+        let res = construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        res.compute_through_stem(4, 10);
+        let saved: Vec<(u32, i32)> = (0..=4)
+            .flat_map(|s| (0..=14).map(move |t| (s, t)))
+            .filter(|&(s, t)| {
+                res.save_file(SaveKind::Differential, s, t)
+                    .exists(tempdir.path().into())
+            })
+            .collect();
+        assert_eq!(saved.len(), 65);
+
+        let loaded = construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        assert_eq!(loaded.load_partial().unwrap(), saved.len());
+        for &(s, t) in &saved {
+            assert!(loaded.has_computed_bidegree(s, t), "({s}, {t})");
+        }
+        assert_eq!(
+            loaded.graded_dimension_string(),
+            res.graded_dimension_string()
+        );
+    }
+
+    #[test]
+    fn test_save_bidegree() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_bidegree(1, 2);
+        assert!(res.save_bidegree(1, 2).is_err());
+
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let mut res =
+            construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        res.should_save = false;
+        res.compute_through_bidegree(1, 2);
+
+        let path = tempdir.path().join("differentials/1_2_differential");
+        assert!(!path.exists());
+        res.save_bidegree(1, 2).unwrap();
+        assert!(path.exists());
+        // Saving again is a no-op
+        res.save_bidegree(1, 2).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_staged() {
+        let staged = construct_standard::<false, _, _>("S_2", None).unwrap();
+        staged.resolve_staged(&[(2, 30), (6, 30), (10, 36)]);
+
+        let direct = construct_standard::<false, _, _>("S_2", None).unwrap();
+        direct.compute_through_bidegree(10, 36);
+
+        assert_eq!(
+            staged.graded_dimension_string(),
+            direct.graded_dimension_string()
+        );
+        assert!(!staged.has_computed_bidegree(11, 36));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not contain the previous stage")]
+    fn test_resolve_staged_monotone() {
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.resolve_staged(&[(2, 30), (6, 20)]);
+    }
+
+    #[test]
+    fn test_save_incremental() {
+        let tempdir = tempfile::TempDir::new().unwrap();
+        let num_files = || {
+            std::fs::read_dir(tempdir.path().join("differentials"))
+                .unwrap()
+                .count()
+        };
+
+        let res = construct_standard::<false, _, _>("S_2", None).unwrap();
+        res.compute_through_stem(4, 6);
+
+        let written = res.save_incremental(tempdir.path()).unwrap();
+        assert!(written > 0);
+        assert_eq!(num_files(), written);
+
+        assert_eq!(res.save_incremental(tempdir.path()).unwrap(), 0);
+        assert_eq!(num_files(), written);
+
+        res.compute_through_stem(6, 10);
+        let new_bidegrees = res.save_incremental(tempdir.path()).unwrap();
+        assert!(new_bidegrees > 0);
+        assert_eq!(num_files(), written + new_bidegrees);
+
+        // The saved data can be loaded back.
+        let loaded = construct_standard::<false, _, _>("S_2", Some(tempdir.path().into())).unwrap();
+        loaded.compute_through_stem(6, 10);
+        assert_eq!(
+            loaded.graded_dimension_string(),
+            res.graded_dimension_string()
+        );
+        assert_eq!(num_files(), written + new_bidegrees);
+    }
 }
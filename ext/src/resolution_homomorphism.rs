@@ -380,6 +380,28 @@ where
         result
     }
 
+    /// Construct a chain map of homological degree zero whose value on the generators in bidegree
+    /// `(0, shift_t)` is given by `matrix`. The `k`th row of `matrix` is the image of the `k`th
+    /// generator in the augmentation of the target, as in
+    /// [`MuResolutionHomomorphism::extend_step`].
+    ///
+    /// The rest of the map is computed lazily by the `extend` family of functions.
+    pub fn new_from_matrix_on_s0(
+        name: String,
+        source: Arc<CC1>,
+        target: Arc<CC2>,
+        shift_t: i32,
+        matrix: &Matrix,
+    ) -> Self {
+        let result = Self::new(name, source, target, 0, shift_t);
+        assert_eq!(
+            result.source.module(0).number_of_gens_in_degree(shift_t),
+            matrix.rows()
+        );
+        result.extend_step(0, shift_t, Some(matrix));
+        result
+    }
+
     /// Extend the [`MuResolutionHomomorphism`] to be defined on `(input_s, input_t)`. The resulting
     /// homomorphism `f` is a chain map such that if `g` is the `k`th generator in the source such
     /// that `d(g) = 0`, then the image of `f(g)` in the augmentation of the target is the `k`th
@@ -507,3 +529,37 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::utils::construct_standard;
+
+    #[test]
+    fn test_new_from_matrix_on_s0() {
+        let res = Arc::new(construct_standard::<false, _, _>("S_2", None).unwrap());
+        res.compute_through_bidegree(6, 20);
+
+        let hom = ResolutionHomomorphism::new_from_matrix_on_s0(
+            String::new(),
+            Arc::clone(&res),
+            Arc::clone(&res),
+            0,
+            &Matrix::from_vec(res.prime(), &[vec![1]]),
+        );
+        hom.extend(6, 20);
+
+        for s in 0..=6 {
+            let map = hom.get_map(s);
+            let module = res.module(s);
+            for t in 0..=20 {
+                for i in 0..module.number_of_gens_in_degree(t) {
+                    let mut expected = FpVector::new(res.prime(), module.dimension(t));
+                    expected.set_entry(module.operation_generator_to_index(0, 0, t, i), 1);
+                    assert_eq!(map.output(t, i), &expected, "s = {s}, t = {t}, i = {i}");
+                }
+            }
+        }
+    }
+}
@@ -343,7 +343,7 @@ impl<A: Algebra> SaveFile<A> {
     }
 
     /// This panics if there is no save dir
-    fn get_save_path(&self, mut dir: PathBuf) -> PathBuf {
+    pub(crate) fn get_save_path(&self, mut dir: PathBuf) -> PathBuf {
         if let Some(idx) = self.idx {
             dir.push(format!(
                 "{name}s/{s}_{t}_{idx}_{name}",
@@ -130,7 +130,8 @@ impl<T: TryInto<AlgebraType>> TryFrom<(Value, T)> for Config {
 ///     - `module_spec`, a single `&str` of the form `module_name@algebra`, where `module_name` and
 ///       `algebra` are as above.
 ///  - `save_file`: The save file for the module. If it points to an invalid save file, an error is
-///    returned.
+///    returned. If the Milnor basis is used, the basis of the algebra is also cached in this
+///    directory (see [`MilnorAlgebra::set_basis_cache_dir`]). The Adem basis is not cached.
 ///
 /// This dispatches to either [`construct_nassau`] or [`construct_standard`] depending on whether
 /// the `nassau` feature is enabled.
@@ -184,7 +185,11 @@ where
         ));
     }
 
-    let algebra = Arc::new(MilnorAlgebra::new(fp::prime::TWO, false));
+    let mut algebra = MilnorAlgebra::new(fp::prime::TWO, false);
+    if let Some(dir) = &save_dir {
+        algebra.set_basis_cache_dir(&dir.join("basis"))?;
+    }
+    let algebra = Arc::new(algebra);
     let module = Arc::new(FDModule::from_json(Arc::clone(&algebra), &json)?);
 
     if !json["confiber"].is_null() {
@@ -208,7 +213,11 @@ where
         algebra,
     } = module_spec.try_into()?;
 
-    let algebra = Arc::new(SteenrodAlgebra::from_json(&json, algebra, U)?);
+    let mut algebra = SteenrodAlgebra::from_json(&json, algebra, U)?;
+    if let (Some(dir), SteenrodAlgebra::MilnorAlgebra(a)) = (&save_dir, &mut algebra) {
+        a.set_basis_cache_dir(&dir.join("basis"))?;
+    }
+    let algebra = Arc::new(algebra);
     let module = Arc::new(steenrod_module::from_json(Arc::clone(&algebra), &json)?);
     let mut chain_complex = Arc::new(FiniteChainComplex::ccdz(Arc::clone(&module)));
 
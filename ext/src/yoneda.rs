@@ -58,7 +58,11 @@ fn rate_milnor_operation(algebra: &MilnorAlgebra, deg: i32, idx: usize) -> i32 {
 }
 
 fn rate_adem_operation(algebra: &AdemAlgebra, deg: i32, idx: usize) -> i32 {
-    let elt = algebra.basis_element_from_index(deg, idx);
+    // Basis elements of A(n) that are not admissible monomials are products of several letters,
+    // so rate them as the most expensive operations of their degree.
+    let Some(elt) = algebra.try_subalgebra_basis_element(deg, idx) else {
+        return deg;
+    };
     elt.ps.iter().map(|&r| r.count_ones()).sum::<u32>() as i32
 }
 
@@ -67,7 +71,9 @@ fn operation_drop(algebra: &AdemAlgebra, deg: i32, idx: usize) -> i32 {
     if *algebra.prime() != 2 {
         return 1;
     }
-    let elt = algebra.basis_element_from_index(deg, idx);
+    let Some(elt) = algebra.try_subalgebra_basis_element(deg, idx) else {
+        return 1;
+    };
     if elt.ps.is_empty() {
         return 0;
     }
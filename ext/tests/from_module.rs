@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use algebra::module::homomorphism::ModuleHomomorphism;
+use algebra::module::{steenrod_module, Module};
+use algebra::{AlgebraType, SteenrodAlgebra};
+use ext::chain_complex::{
+    AugmentedChainComplex, BoundedChainComplex, ChainComplex, FreeChainComplex,
+};
+use ext::resolution::Resolution;
+use ext::utils::{construct, construct_standard, load_module_json};
+use ext::CCC;
+use fp::vector::FpVector;
+use rstest::rstest;
+
+#[rstest]
+#[trace]
+fn test_from_module(#[values("S_2", "S_3", "Ceta", "Joker")] module_name: &str) {
+    let json = load_module_json(module_name).unwrap();
+    let algebra = Arc::new(SteenrodAlgebra::from_json(&json, AlgebraType::Milnor, false).unwrap());
+    let module = Arc::new(steenrod_module::from_json(algebra, &json).unwrap());
+
+    let cc = Arc::new(CCC::from_module(Arc::clone(&module)));
+    assert_eq!(cc.max_s(), 1);
+    assert_eq!(cc.min_degree(), module.min_degree());
+    assert!(Arc::ptr_eq(&cc.module(0), &module));
+
+    // The augmentation is the identity of the module
+    let target = cc.target();
+    assert_eq!(target.max_s(), 1);
+    assert!(Arc::ptr_eq(&target.module(0), &module));
+    let augmentation = cc.chain_map(0);
+    assert_eq!(augmentation.degree_shift(), 0);
+    let max_degree = module.max_degree().unwrap();
+    module.compute_basis(max_degree);
+    let p = module.prime();
+    let mut result = FpVector::new(p, 0);
+    for t in module.min_degree()..=max_degree {
+        for idx in 0..module.dimension(t) {
+            result.set_scratch_vector_size(module.dimension(t));
+            augmentation.apply_to_basis_element(result.as_slice_mut(), 1, t, idx);
+            assert_eq!(result.iter_nonzero().collect::<Vec<_>>(), [(idx, 1)]);
+        }
+    }
+
+    let first = Resolution::new(cc);
+    let second = construct((json, AlgebraType::Milnor), None).unwrap();
+
+    first.compute_through_bidegree(10, 20);
+    second.compute_through_bidegree(10, 20);
+
+    assert_eq!(
+        first.graded_dimension_string(),
+        second.graded_dimension_string()
+    );
+}
+
+#[test]
+fn test_generated_rp4() {
+    let json = serde_json::json!({
+        "p": 2,
+        "type": "real projective space",
+        "min": 1,
+        "max": 4,
+    });
+    let generated = construct_standard::<false, _, _>((json, AlgebraType::Milnor), None).unwrap();
+    let fixed = construct_standard::<false, _, _>("RP4@milnor", None).unwrap();
+
+    generated.compute_through_bidegree(10, 20);
+    fixed.compute_through_bidegree(10, 20);
+
+    assert_eq!(
+        generated.graded_dimension_string(),
+        fixed.graded_dimension_string()
+    );
+}
+
+#[test]
+fn test_sphere_type() {
+    let json = serde_json::json!({
+        "p": 2,
+        "type": "sphere",
+        "degree": 3,
+    });
+    let sphere = construct_standard::<false, _, _>((json, AlgebraType::Milnor), None).unwrap();
+    let s_2 = construct_standard::<false, _, _>("S_2@milnor", None).unwrap();
+
+    sphere.compute_through_bidegree(10, 23);
+    s_2.compute_through_bidegree(10, 20);
+
+    assert_eq!(sphere.min_degree(), 3);
+    for s in 0..=10 {
+        for t in 0..=20 {
+            assert_eq!(
+                sphere.number_of_gens_in_bidegree(s, t + 3),
+                s_2.number_of_gens_in_bidegree(s, t)
+            );
+        }
+    }
+}
@@ -1,5 +1,6 @@
+use algebra::AlgebraType;
 use ext::chain_complex::{ChainComplex, FreeChainComplex};
-use ext::utils::{construct, construct_standard};
+use ext::utils::{construct, construct_standard, load_module_json};
 use rstest::rstest;
 
 #[rstest]
@@ -44,3 +45,30 @@ fn compare_unstable(#[case] module_name: &str, #[case] max_degree: i32) {
 
     assert_eq!(a.graded_dimension_string(), b.graded_dimension_string());
 }
+
+#[rstest]
+#[trace]
+#[case("S_2", 1, 30)]
+#[case("C2", 1, 30)]
+#[case("Joker", 2, 30)]
+#[case("S_3", 1, 60)]
+fn compare_an(#[case] module_name: &str, #[case] n: usize, #[case] max_degree: i32) {
+    let mut json = load_module_json(module_name).unwrap();
+    json["profile"] = serde_json::json!({
+        "truncated": true,
+        "q_part": (1 << (n + 2)) - 1,
+        "p_part": (0..=n).map(|i| n + 1 - i).collect::<Vec<_>>(),
+    });
+    let a = construct((json.clone(), AlgebraType::Adem), None).unwrap();
+    let b = construct((json, AlgebraType::Milnor), None).unwrap();
+
+    assert_eq!(
+        a.algebra().to_string(),
+        format!("AdemAlgebra(p={}, profile=A({n}))", a.prime())
+    );
+
+    a.compute_through_bidegree(max_degree as u32, max_degree);
+    b.compute_through_bidegree(max_degree as u32, max_degree);
+
+    assert_eq!(a.graded_dimension_string(), b.graded_dimension_string());
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "concurrent")]
+
+use ext::chain_complex::{ChainComplex, FreeChainComplex};
+use ext::utils::construct;
+use rstest::rstest;
+
+/// Resolve `module_name` using `num_workers` threads to run the individual steps.
+fn resolve_with_workers(module_name: &str, num_workers: usize, max_degree: i32) -> String {
+    // The thread that calls `compute_through_bidegree` blocks while waiting for the steps to
+    // finish, so inside the pool it does not count as a worker.
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(num_workers + 1)
+        .build()
+        .unwrap()
+        .install(|| {
+            let res = construct(module_name, None).unwrap();
+            res.compute_through_bidegree(max_degree as u32, max_degree);
+            res.graded_dimension_string()
+        })
+}
+
+#[rstest]
+#[trace]
+#[case("S_2", 40)]
+#[case("C2", 30)]
+#[case("S_3", 40)]
+fn single_vs_many_workers(#[case] module_name: &str, #[case] max_degree: i32) {
+    assert_eq!(
+        resolve_with_workers(module_name, 1, max_degree),
+        resolve_with_workers(module_name, 4, max_degree)
+    );
+}
@@ -3,6 +3,7 @@ pub mod sseq;
 pub mod actions;
 pub mod managers;
 pub mod resolution_wrapper;
+pub mod validation;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm_bindings;
 
@@ -41,6 +41,11 @@ impl ResolutionManager {
         }
     }
 
+    /// The resolution being managed, if it has been constructed.
+    pub fn resolution(&self) -> Option<&Resolution<CCC>> {
+        self.resolution.as_ref()
+    }
+
     /// Reads a message and performs the actions as instructed.
     pub fn process_message(&mut self, msg: Message) {
         if let Err(e) = self.process_message_inner(msg) {
@@ -10,6 +10,7 @@ use ext::chain_complex::{AugmentedChainComplex, ChainComplex, FreeChainComplex};
 use ext::resolution::Resolution as ResolutionInner;
 use fp::matrix::Matrix;
 use fp::prime::ValidPrime;
+use fp::vector::FpVector;
 use once::{OnceBiVec, OnceVec};
 
 use ext::resolution_homomorphism::ResolutionHomomorphism as ResolutionHomomorphism_;
@@ -34,6 +35,24 @@ pub struct SelfMap<CC: ChainComplex> {
     pub map: ResolutionHomomorphism<CC>,
 }
 
+/// The ranks of a resolution flattened into a single array, so that it can be handed to JavaScript
+/// in one go. The rank in bidegree `(s, t)` is stored at index `s * (max_t - min_t + 1) + (t -
+/// min_t)`, and bidegrees that have not been computed have rank zero. If nothing has been computed
+/// yet, `max_s` is `None` and `ranks` is empty.
+pub struct FlatRankTable {
+    pub ranks: Vec<u32>,
+    pub max_s: Option<u32>,
+    pub min_t: i32,
+    pub max_t: i32,
+}
+
+impl FlatRankTable {
+    pub fn rank(&self, s: u32, t: i32) -> u32 {
+        let width = (self.max_t - self.min_t + 1) as usize;
+        self.ranks[s as usize * width + (t - self.min_t) as usize]
+    }
+}
+
 enum UnitResolution<CC: ChainComplex> {
     None,
     Own,
@@ -389,7 +408,64 @@ impl<CC: ChainComplex> Resolution<CC> {
         }
     }
 
-    /// We compute the products by self maps where the result has degree (s, t).
+    /// Check that the self map `name` is non-nilpotent to the given order, i.e. that applying it
+    /// `iterations` times to each generator in the bottom bidegree `(0, min_degree)` gives a
+    /// non-zero class. This resolves as far as necessary. Returns `false` if there is no self map
+    /// called `name` or if its map data does not have the shape required by its degree.
+    pub fn check_self_map(&self, name: &str, iterations: usize) -> bool {
+        let f = match self.self_maps.iter().find(|f| f.name == name) {
+            Some(f) => f,
+            None => return false,
+        };
+        let p = self.prime();
+        let min_degree = self.min_degree();
+
+        self.compute_through_stem(f.s, min_degree + f.t - f.s as i32);
+        if !self.self_map_is_well_formed(f) {
+            return false;
+        }
+
+        let max_s = f.s * iterations as u32;
+        let max_t = min_degree + f.t * iterations as i32;
+        self.compute_through_stem(max_s, max_t - max_s as i32);
+
+        (0..self.inner.number_of_gens_in_bidegree(0, min_degree)).all(|idx| {
+            let mut s = 0;
+            let mut t = min_degree;
+            let mut class = FpVector::new(p, self.inner.number_of_gens_in_bidegree(s, t));
+            class.set_entry(idx, 1);
+
+            for _ in 0..iterations {
+                let mut result =
+                    FpVector::new(p, self.inner.number_of_gens_in_bidegree(s + f.s, t + f.t));
+                for (i, c) in class.iter_nonzero() {
+                    f.map.act(result.as_slice_mut(), c, s, t, i);
+                }
+                if result.is_zero() {
+                    return false;
+                }
+                class = result;
+                s += f.s;
+                t += f.t;
+            }
+            true
+        })
+    }
+
+    /// Whether the map data of `f` has one row for each generator in bidegree `(f.s, min_degree +
+    /// f.t)` and one column for each basis element of the bottom degree of the module. This
+    /// requires the former bidegree to be computed.
+    fn self_map_is_well_formed(&self, f: &SelfMap<CC>) -> bool {
+        let min_degree = self.min_degree();
+        let module = self.inner.target().module(0);
+        module.compute_basis(min_degree);
+
+        f.map_data.rows() == self.inner.number_of_gens_in_bidegree(f.s, min_degree + f.t)
+            && f.map_data.columns() == module.dimension(min_degree)
+    }
+
+    /// We compute the products by self maps where the result has degree (s, t). Self maps whose
+    /// map data is malformed are skipped.
     fn compute_self_maps(&self, target_s: u32, target_t: i32) {
         for f in &self.self_maps {
             if target_s < f.s {
@@ -401,6 +477,9 @@ impl<CC: ChainComplex> Resolution<CC> {
             if source_t - (source_s as i32) < self.min_degree() {
                 continue;
             }
+            if !self.self_map_is_well_formed(f) {
+                continue;
+            }
             if source_s == 0 && source_t == self.min_degree() {
                 f.map.extend_step(target_s, target_t, Some(&f.map_data));
             }
@@ -450,6 +529,31 @@ impl<CC: ChainComplex> Resolution<CC> {
         self.complex().min_degree()
     }
 
+    pub fn flat_rank_table(&self) -> FlatRankTable {
+        let table = self.inner.rank_table();
+        let min_t = self.min_degree();
+        let max_t = table
+            .iter()
+            .map(|row| row.max_degree())
+            .max()
+            .unwrap_or(min_t - 1);
+        let width = (max_t - min_t + 1) as usize;
+
+        let mut ranks = vec![0; table.len() as usize * width];
+        for (s, row) in table.iter_enum() {
+            for (t, &rank) in row.iter_enum() {
+                ranks[s as usize * width + (t - min_t) as usize] = rank as u32;
+            }
+        }
+
+        FlatRankTable {
+            ranks,
+            max_s: (table.len() as u32).checked_sub(1),
+            min_t,
+            max_t,
+        }
+    }
+
     pub fn differential(
         &self,
         s: u32,
@@ -457,3 +561,80 @@ impl<CC: ChainComplex> Resolution<CC> {
         self.inner.differential(s)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::actions::SseqChoice;
+
+    #[test]
+    fn test_check_self_map() {
+        let (sender, _receiver) = std::sync::mpsc::channel();
+        let json = ext::utils::load_module_json("C3").unwrap();
+        let mut resolution =
+            Resolution::new_from_json(json, "milnor", SseqChoice::Main, sender).unwrap();
+        resolution.set_unit_resolution_self();
+
+        assert!(resolution.check_self_map("v_1", 4));
+        assert!(!resolution.check_self_map("v_2", 1));
+    }
+
+    #[test]
+    fn test_check_self_map_invalid() {
+        let (sender, _receiver) = std::sync::mpsc::channel();
+        let json = ext::utils::load_module_json("C3").unwrap();
+        let mut resolution =
+            Resolution::new_from_json(json, "milnor", SseqChoice::Main, sender).unwrap();
+        resolution.set_unit_resolution_self();
+        let p = resolution.prime();
+
+        // The zero map is nilpotent
+        assert!(resolution.add_self_map(1, 5, "zero", Matrix::new(p, 1, 1)));
+        // The module is one-dimensional in degree 0
+        assert!(resolution.add_self_map(1, 5, "wide", Matrix::new(p, 1, 2)));
+        // There is no generator in bidegree (1, 6)
+        assert!(resolution.add_self_map(1, 6, "shifted", Matrix::from_vec(p, &[vec![1]])));
+
+        assert!(!resolution.check_self_map("zero", 1));
+        assert!(!resolution.check_self_map("wide", 1));
+        assert!(!resolution.check_self_map("shifted", 1));
+        assert!(resolution.check_self_map("v_1", 4));
+    }
+
+    #[test]
+    fn test_flat_rank_table_empty() {
+        let (sender, _receiver) = std::sync::mpsc::channel();
+        let json = ext::utils::load_module_json("S_2").unwrap();
+        let resolution =
+            Resolution::new_from_json(json, "milnor", SseqChoice::Main, sender).unwrap();
+
+        let table = resolution.flat_rank_table();
+        assert_eq!(table.max_s, None);
+        assert!(table.ranks.is_empty());
+    }
+
+    #[test]
+    fn test_flat_rank_table() {
+        let (sender, _receiver) = std::sync::mpsc::channel();
+        let json = ext::utils::load_module_json("S_2").unwrap();
+        let resolution =
+            Resolution::new_from_json(json, "milnor", SseqChoice::Main, sender).unwrap();
+        resolution.compute_through_stem(10, 20);
+
+        let table = resolution.flat_rank_table();
+        assert_eq!(table.max_s, Some(10));
+        assert_eq!(table.min_t, 0);
+        assert_eq!(
+            table.ranks.len(),
+            11 * (table.max_t - table.min_t + 1) as usize
+        );
+
+        for (s, n, t) in resolution.inner.iter_stem() {
+            assert_eq!(
+                table.rank(s, t) as usize,
+                resolution.inner.number_of_gens_in_bidegree(s, t),
+                "(n, s) = ({n}, {s})"
+            );
+        }
+    }
+}
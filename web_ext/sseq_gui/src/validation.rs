@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use algebra::module::{steenrod_module, Module};
+use algebra::{AlgebraType, SteenrodAlgebra};
+use serde::Serialize;
+use serde_json::Value;
+
+/// The result of [`validate_module`]. This is serialized and handed to JavaScript as is.
+#[derive(Serialize, Debug)]
+pub struct ModuleValidation {
+    pub valid: bool,
+    pub errors: Vec<String>,
+}
+
+impl ModuleValidation {
+    fn from_errors(errors: Vec<String>) -> Self {
+        Self {
+            valid: errors.is_empty(),
+            errors,
+        }
+    }
+}
+
+/// Check that `json` describes a valid module over the algebra `algebra_name`, so that the user
+/// can be told about a malformed module before resolving it.
+///
+/// Parsing a finite dimensional module already checks that the action satisfies the relations of
+/// the algebra. For bounded modules, we additionally check that the action is associative in all
+/// degrees. Unbounded modules are given by presentations, and are associative by construction.
+pub fn validate_module(json: &str, algebra_name: &str) -> ModuleValidation {
+    ModuleValidation::from_errors(match module_errors(json, algebra_name) {
+        Ok(errors) => errors,
+        Err(e) => vec![format!("{e:#}")],
+    })
+}
+
+fn module_errors(json: &str, algebra_name: &str) -> anyhow::Result<Vec<String>> {
+    let json: Value = serde_json::from_str(json)?;
+    let algebra_type: AlgebraType = algebra_name.parse()?;
+    let algebra = Arc::new(SteenrodAlgebra::from_json(&json, algebra_type, false)?);
+    let module = steenrod_module::from_json(algebra, &json)?;
+
+    let mut errors = Vec::new();
+    if let Some(max_degree) = module.max_degree() {
+        if let Err(e) = module.check_associativity(max_degree - module.min_degree(), max_degree) {
+            errors.push(e);
+        }
+    }
+    Ok(errors)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validate_module() {
+        let c2 = ext::utils::load_module_json("C2").unwrap().to_string();
+        let result = validate_module(&c2, "milnor");
+        assert!(result.valid, "{:?}", result.errors);
+        assert!(result.errors.is_empty());
+
+        // Sq^1 Sq^1 = 0, but here it sends x0 to x2
+        let invalid = r#"{
+            "type": "finite dimensional module",
+            "p": 2,
+            "gens": {"x0": 0, "x1": 1, "x2": 2},
+            "actions": ["Sq1 x0 = x1", "Sq1 x1 = x2"]
+        }"#;
+        let result = validate_module(invalid, "adem");
+        assert!(!result.valid);
+        assert_eq!(result.errors.len(), 1);
+
+        let result = validate_module("{", "milnor");
+        assert!(!result.valid);
+
+        let result = validate_module(&c2, "serre");
+        assert!(!result.valid);
+        assert_eq!(result.errors, ["Invalid algebra name: serre"]);
+    }
+}
@@ -1,6 +1,8 @@
 use crate::actions::*;
 use crate::managers::*;
-use js_sys::Function;
+use crate::resolution_wrapper::FlatRankTable;
+use crate::validation;
+use js_sys::{Function, Uint32Array};
 use wasm_bindgen::prelude::*;
 
 #[derive(Clone)]
@@ -43,6 +45,44 @@ impl Resolution {
     }
 }
 
+#[wasm_bindgen]
+impl Resolution {
+    /// The ranks of the resolution as a [`RankTable`], or `undefined` if the resolution has not
+    /// been constructed yet.
+    pub fn rank_table(&self) -> Option<RankTable> {
+        Some(RankTable {
+            table: self.r.resolution()?.flat_rank_table(),
+        })
+    }
+}
+
+/// The ranks of the resolution, so that JavaScript can render the chart without querying each
+/// bidegree separately. See [`FlatRankTable`] for the layout of `ranks`.
+#[wasm_bindgen]
+pub struct RankTable {
+    table: FlatRankTable,
+}
+
+#[wasm_bindgen]
+impl RankTable {
+    pub fn ranks(&self) -> Uint32Array {
+        Uint32Array::from(&self.table.ranks[..])
+    }
+
+    /// The maximum `s` in the table, or `undefined` if nothing has been computed.
+    pub fn max_s(&self) -> Option<u32> {
+        self.table.max_s
+    }
+
+    pub fn min_t(&self) -> i32 {
+        self.table.min_t
+    }
+
+    pub fn max_t(&self) -> i32 {
+        self.table.max_t
+    }
+}
+
 #[wasm_bindgen]
 pub struct Sseq {
     s: SseqManager,
@@ -65,3 +105,12 @@ impl Sseq {
         }
     }
 }
+
+/// Check that `json` describes a valid module over `algebra` before resolving it. This returns an
+/// object of the form `{valid: bool, errors: [...]}`; see [`validation::validate_module`].
+#[wasm_bindgen]
+pub fn validate_module(json: &str, algebra: &str) -> Result<JsValue, JsValue> {
+    let result = validation::validate_module(json, algebra);
+    let s = serde_json::to_string(&result).map_err(|e| JsValue::from(e.to_string()))?;
+    js_sys::JSON::parse(&s)
+}